@@ -10,7 +10,7 @@ import { ExplorationHistory } from '@/components/ExplorationHistory';
 import { WalletButton } from '@/components/WalletButton';
 import { DepositModal } from '@/components/DepositModal';
 import { useExploration } from '@/hooks/useExploration';
-import { BLOCK_IDS } from '@rig-it/sdk';
+import { BLOCK_IDS, NO_WINNING_RIG } from '@rig-it/sdk';
 
 export default function Home() {
   const [selectedBlock, setSelectedBlock] = useState<number>(BLOCK_IDS.SOL);
@@ -104,7 +104,7 @@ export default function Home() {
 
               <RigGrid
                 rigs={rigs}
-                winningRig={exploration?.winningRig ?? null}
+                winningRig={exploration && exploration.winningRig !== NO_WINNING_RIG ? exploration.winningRig : null}
                 onRigClick={handleRigClick}
                 disabled={phase !== 'active'}
               />
@@ -10,6 +10,7 @@ interface RigGridProps {
   onRigClick: (rigIndex: number) => void;
   disabled?: boolean;
   userDeposits?: Map<number, bigint>; // rigIndex -> deposit amount
+  rigCount?: number; // Per-block rig count
 }
 
 export const RigGrid: FC<RigGridProps> = ({
@@ -18,6 +19,7 @@ export const RigGrid: FC<RigGridProps> = ({
   onRigClick,
   disabled = false,
   userDeposits = new Map(),
+  rigCount = RIGS_PER_EXPLORATION,
 }) => {
   // Find max deposits for relative sizing
   const maxDeposits = useMemo(() => {
@@ -27,9 +29,9 @@ export const RigGrid: FC<RigGridProps> = ({
     );
   }, [rigs]);
 
-  // Generate 36 rig cells
+  // Generate one cell per rig
   const rigCells = useMemo(() => {
-    return Array.from({ length: RIGS_PER_EXPLORATION }, (_, i) => {
+    return Array.from({ length: rigCount }, (_, i) => {
       const rig = rigs[i];
       const deposits = Number(rig?.totalDeposits || 0);
       const tickets = Number(rig?.totalTickets || 0);
@@ -47,10 +49,16 @@ export const RigGrid: FC<RigGridProps> = ({
         depositCount: rig?.depositCount || 0,
       };
     });
-  }, [rigs, maxDeposits, winningRig, userDeposits]);
+  }, [rigs, rigCount, maxDeposits, winningRig, userDeposits]);
+
+  // Keep the grid roughly square for any rig count (6x6 for 36, 8x8 for 64)
+  const columns = Math.ceil(Math.sqrt(rigCount));
 
   return (
-    <div className="grid grid-cols-6 gap-2 md:gap-3">
+    <div
+      className="grid gap-2 md:gap-3"
+      style={{ gridTemplateColumns: `repeat(${columns}, minmax(0, 1fr))` }}
+    >
       {rigCells.map((rig) => (
         <motion.button
           key={rig.index}
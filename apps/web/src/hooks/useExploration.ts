@@ -41,30 +41,30 @@ export function useExploration(blockId: number): UseExplorationResult {
       const timing = calculateExplorationTiming(mockStartTs);
 
       const mockExploration: ExplorationState = {
+        version: 2,
+        bump: 0,
         blockId,
+        trackId: 0,
+        status: 1, // Active
+        rigCount: 36,
+        winningRig: 255, // Not settled
+        flags: 0,
         explorationIndex: BigInt(42) as any,
-        status: 'active' as any,
         startSlot: BigInt(0) as any,
         startTs: BigInt(mockStartTs) as any,
         activeEndTs: BigInt(timing.activeEndTs) as any,
         cooldownEndTs: BigInt(timing.cooldownEndTs) as any,
         totalDeposits: BigInt(45_500_000_000) as any, // 45.5 SOL
-        rigDeposits: new Array(36).fill(BigInt(0)) as any,
-        rigTickets: new Array(36).fill(BigInt(0)) as any,
         rolloverAmount: BigInt(5_000_000_000) as any, // 5 SOL rollover
         commitSlot: BigInt(0) as any,
-        commitHash: new Uint8Array(32),
         revealDeadlineSlot: BigInt(0) as any,
-        revealedRandom: null,
-        winningRig: null,
-        loserRefundsProcessed: false,
-        winnerDistributionProcessed: false,
-        buybackAllocated: false,
-        carryForwardDone: false,
         totalWinnerDeposits: BigInt(0) as any,
         totalLoserDeposits: BigInt(0) as any,
         remainingPool: BigInt(0) as any,
-        bump: 0,
+        commitHash: new Array(32).fill(0),
+        revealedRandom: new Array(32).fill(0),
+        entropyAccumulator: new Array(32).fill(0),
+        entropyContributions: 0,
       };
 
       // Generate some random rig data
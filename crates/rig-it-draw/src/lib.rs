@@ -0,0 +1,581 @@
+//! Draw math for Rig It explorations: commit hashes, random value derivation,
+//! SlotHashes lookup, ticket snapshots and winner selection.
+//!
+//! Shared by the on-chain program and the offline verifier, so it must not
+//! depend on Anchor.
+
+use solana_sha256_hasher::hash;
+
+/// Version of the draw algorithm recorded on every DrawRecord.
+/// v1: random value = hash(secret || entropy accumulator || slot hash), or the
+/// slot hash alone for timeout fallbacks; winner = first 16 bytes (LE) modulo
+/// total tickets, walked over cumulative rig tickets.
+/// v2: timeout fallbacks use hash(commit hash || entropy accumulator || slot hash)
+/// of a fallback slot fixed in advance.
+pub const DRAW_ALGORITHM_VERSION: u8 = 2;
+
+/// Commit scheme v1: hash(secret || target_slot)
+pub const COMMIT_SCHEME_V1: u8 = 1;
+/// Commit scheme v2: domain-separated and bound to the exploration identity
+pub const COMMIT_SCHEME_V2: u8 = 2;
+/// Scheme new commits are made with
+pub const CURRENT_COMMIT_SCHEME: u8 = COMMIT_SCHEME_V2;
+
+/// Domain separator prefixed to v2 commit preimages
+pub const COMMIT_DOMAIN_V2: &[u8] = b"rig-it:commit:v2";
+
+/// Identity of the exploration a commitment is made for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommitContext {
+    pub program_id: [u8; 32],
+    pub block_id: u8,
+    pub track_id: u8,
+    pub exploration_index: u64,
+}
+
+/// Generate commit hash from secret and target slot (scheme v1)
+pub fn generate_commit_hash(secret: &[u8; 32], target_slot: u64) -> [u8; 32] {
+    let mut data = Vec::with_capacity(40);
+    data.extend_from_slice(secret);
+    data.extend_from_slice(&target_slot.to_le_bytes());
+    hash(&data).to_bytes()
+}
+
+/// Generate commit hash bound to an exploration (scheme v2):
+/// hash(domain || program_id || block_id || track_id || exploration_index || target_slot || secret)
+pub fn generate_commit_hash_v2(
+    secret: &[u8; 32],
+    target_slot: u64,
+    context: &CommitContext,
+) -> [u8; 32] {
+    let mut data = Vec::with_capacity(COMMIT_DOMAIN_V2.len() + 32 + 2 + 8 + 8 + 32);
+    data.extend_from_slice(COMMIT_DOMAIN_V2);
+    data.extend_from_slice(&context.program_id);
+    data.push(context.block_id);
+    data.push(context.track_id);
+    data.extend_from_slice(&context.exploration_index.to_le_bytes());
+    data.extend_from_slice(&target_slot.to_le_bytes());
+    data.extend_from_slice(secret);
+    hash(&data).to_bytes()
+}
+
+/// Commit hash under `scheme`, None for unknown schemes
+pub fn compute_commit_hash(
+    scheme: u8,
+    secret: &[u8; 32],
+    target_slot: u64,
+    context: &CommitContext,
+) -> Option<[u8; 32]> {
+    match scheme {
+        COMMIT_SCHEME_V1 => Some(generate_commit_hash(secret, target_slot)),
+        COMMIT_SCHEME_V2 => Some(generate_commit_hash_v2(secret, target_slot, context)),
+        _ => None,
+    }
+}
+
+/// Verify reveal matches commit under the scheme it was made with
+pub fn verify_reveal(
+    scheme: u8,
+    secret: &[u8; 32],
+    target_slot: u64,
+    context: &CommitContext,
+    commit_hash: &[u8; 32],
+) -> bool {
+    compute_commit_hash(scheme, secret, target_slot, context)
+        .is_some_and(|computed| computed == *commit_hash)
+}
+
+/// Fold a depositor's entropy commitment into the exploration's accumulator.
+/// Chaining with the depositor key means a contribution can't cancel out an
+/// earlier one the way a plain XOR could.
+pub fn accumulate_entropy(
+    accumulator: &[u8; 32],
+    depositor: &[u8; 32],
+    commitment: &[u8; 32],
+) -> [u8; 32] {
+    let mut data = Vec::with_capacity(96);
+    data.extend_from_slice(accumulator);
+    data.extend_from_slice(depositor);
+    data.extend_from_slice(commitment);
+    hash(&data).to_bytes()
+}
+
+/// Generate final random value from operator secret, depositor entropy and slot hash
+pub fn generate_random_value(
+    secret: &[u8; 32],
+    entropy_accumulator: &[u8; 32],
+    slot_hash: &[u8; 32],
+) -> [u8; 32] {
+    let mut data = Vec::with_capacity(96);
+    data.extend_from_slice(secret);
+    data.extend_from_slice(entropy_accumulator);
+    data.extend_from_slice(slot_hash);
+    hash(&data).to_bytes()
+}
+
+/// Generate the timeout-fallback random value from the operator commitment,
+/// depositor entropy and the hash of the fallback slot. The commitment and
+/// entropy are frozen at commit and the slot is fixed before its hash exists,
+/// so whoever submits the fallback can't steer the draw.
+pub fn generate_fallback_random_value(
+    commit_hash: &[u8; 32],
+    entropy_accumulator: &[u8; 32],
+    slot_hash: &[u8; 32],
+) -> [u8; 32] {
+    let mut data = Vec::with_capacity(96);
+    data.extend_from_slice(commit_hash);
+    data.extend_from_slice(entropy_accumulator);
+    data.extend_from_slice(slot_hash);
+    hash(&data).to_bytes()
+}
+
+/// Size of one SlotHashes entry: slot (u64) + hash ([u8; 32])
+const SLOT_HASH_ENTRY_SIZE: usize = 8 + 32;
+
+/// Number of complete entries in a SlotHashes buffer.
+/// Format: length (8 bytes) + repeated (slot: 8 bytes, hash: 32 bytes)
+fn slot_hash_entry_count(slot_hashes_data: &[u8]) -> Option<usize> {
+    let len = u64::from_le_bytes(slot_hashes_data.get(0..8)?.try_into().ok()?);
+    let available = (slot_hashes_data.len() - 8) / SLOT_HASH_ENTRY_SIZE;
+    Some(usize::try_from(len).map_or(available, |len| len.min(available)))
+}
+
+/// Read the (slot, hash) entry at `index`; caller ensures it is in bounds
+fn slot_hash_entry(slot_hashes_data: &[u8], index: usize) -> Option<(u64, [u8; 32])> {
+    let offset = 8 + index * SLOT_HASH_ENTRY_SIZE;
+    let entry = slot_hashes_data.get(offset..offset + SLOT_HASH_ENTRY_SIZE)?;
+    let slot = u64::from_le_bytes(entry[0..8].try_into().ok()?);
+    let hash_bytes: [u8; 32] = entry[8..].try_into().ok()?;
+    Some((slot, hash_bytes))
+}
+
+/// Get slot hash from SlotHashes sysvar
+/// Note: SlotHashes only stores the last 512 slots, sorted by descending slot,
+/// so the entry is found by binary search over the complete entries.
+pub fn get_slot_hash(slot_hashes_data: &[u8], target_slot: u64) -> Option<[u8; 32]> {
+    let mut low = 0;
+    let mut high = slot_hash_entry_count(slot_hashes_data)?;
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let (slot, hash_bytes) = slot_hash_entry(slot_hashes_data, mid)?;
+        match slot.cmp(&target_slot) {
+            std::cmp::Ordering::Equal => return Some(hash_bytes),
+            // Descending order: larger slots come first
+            std::cmp::Ordering::Greater => low = mid + 1,
+            std::cmp::Ordering::Less => high = mid,
+        }
+    }
+
+    None
+}
+
+/// Hash of the per-rig ticket counts a winner is selected from
+pub fn hash_ticket_snapshot(rig_tickets: &[u128]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(rig_tickets.len() * 16);
+    for tickets in rig_tickets {
+        data.extend_from_slice(&tickets.to_le_bytes());
+    }
+    hash(&data).to_bytes()
+}
+
+/// Hash of the settlement inputs frozen at commit: per-rig deposits,
+/// per-rig tickets and the rollover amount
+pub fn hash_commit_snapshot(
+    rig_deposits: impl ExactSizeIterator<Item = u64>,
+    rig_tickets: impl ExactSizeIterator<Item = u128>,
+    rollover_amount: u64,
+) -> [u8; 32] {
+    let mut data = Vec::with_capacity(8 + rig_deposits.len() * 8 + rig_tickets.len() * 16 + 8);
+    data.extend_from_slice(&(rig_deposits.len() as u64).to_le_bytes());
+    for deposits in rig_deposits {
+        data.extend_from_slice(&deposits.to_le_bytes());
+    }
+    for tickets in rig_tickets {
+        data.extend_from_slice(&tickets.to_le_bytes());
+    }
+    data.extend_from_slice(&rollover_amount.to_le_bytes());
+    hash(&data).to_bytes()
+}
+
+/// Select winning rig based on random value and ticket distribution
+/// Returns rig index (< rig_tickets.len())
+pub fn select_winning_rig(random_value: &[u8; 32], rig_tickets: &[u128]) -> Option<u8> {
+    // Calculate total tickets
+    let total_tickets: u128 = rig_tickets.iter().sum();
+    
+    if total_tickets == 0 {
+        return None;
+    }
+    
+    // Convert random bytes to u128 (take first 16 bytes)
+    let random_u128 = u128::from_le_bytes(random_value[0..16].try_into().unwrap());
+    
+    // Get winning ticket number (modulo total)
+    let winning_ticket = random_u128 % total_tickets;
+    
+    // Find which rig contains this ticket
+    let mut cumulative: u128 = 0;
+    for (i, tickets) in rig_tickets.iter().enumerate() {
+        cumulative = cumulative.saturating_add(*tickets);
+        if winning_ticket < cumulative {
+            return Some(i as u8);
+        }
+    }
+    
+    // Fallback to last rig (should never happen if tickets > 0)
+    Some((rig_tickets.len() - 1) as u8)
+}
+
+/// Random value for a prize tier. Tier 0 uses the draw's random value as-is,
+/// so a single-winner draw is unchanged; later tiers hash in the tier number.
+pub fn derive_tier_random(random_value: &[u8; 32], tier: u8) -> [u8; 32] {
+    if tier == 0 {
+        return *random_value;
+    }
+    let mut data = Vec::with_capacity(32 + 4 + 1);
+    data.extend_from_slice(random_value);
+    data.extend_from_slice(b"tier");
+    data.push(tier);
+    hash(&data).to_bytes()
+}
+
+/// Draw up to `tiers` distinct rigs without replacement, one per prize tier.
+/// Stops early once every rig with tickets has been drawn.
+pub fn select_prize_rigs(random_value: &[u8; 32], rig_tickets: &[u128], tiers: usize) -> Vec<u8> {
+    let mut remaining = rig_tickets.to_vec();
+    let mut prize_rigs = Vec::with_capacity(tiers);
+    for tier in 0..tiers {
+        let tier_random = derive_tier_random(random_value, tier as u8);
+        match select_winning_rig(&tier_random, &remaining) {
+            Some(rig) => {
+                remaining[rig as usize] = 0;
+                prize_rigs.push(rig);
+            }
+            None => break,
+        }
+    }
+    prize_rigs
+}
+
+/// Draw `count` ticket positions in `[0, total_tickets)` for the consolation
+/// lottery, each from its own value derived from the draw's random value.
+/// Positions are drawn with replacement.
+pub fn select_consolation_tickets(
+    random_value: &[u8; 32],
+    total_tickets: u128,
+    count: u8,
+) -> Vec<u128> {
+    if total_tickets == 0 {
+        return Vec::new();
+    }
+    (0..count)
+        .map(|i| {
+            let mut data = Vec::with_capacity(32 + 11 + 1);
+            data.extend_from_slice(random_value);
+            data.extend_from_slice(b"consolation");
+            data.push(i);
+            let derived = hash(&data).to_bytes();
+            u128::from_le_bytes(derived[0..16].try_into().unwrap()) % total_tickets
+        })
+        .collect()
+}
+
+/// Random value for the exploration's buyback schedule, kept independent of
+/// the values used for the draw itself
+pub fn derive_buyback_random(random_value: &[u8; 32]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(32 + 7);
+    data.extend_from_slice(random_value);
+    data.extend_from_slice(b"buyback");
+    hash(&data).to_bytes()
+}
+
+/// Jackpot roll in `[0, 10000)`, kept independent of the draw itself. The
+/// jackpot hits when the roll is below the exploration's hit chance in bps.
+pub fn jackpot_roll(random_value: &[u8; 32]) -> u16 {
+    let mut data = Vec::with_capacity(32 + 7);
+    data.extend_from_slice(random_value);
+    data.extend_from_slice(b"jackpot");
+    let derived = hash(&data).to_bytes();
+    (u64::from_le_bytes(derived[0..8].try_into().unwrap()) % 10_000) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_is_sha256() {
+        // The off-chain verifier must hash exactly like the on-chain syscall
+        assert_eq!(
+            hash(b"").to_bytes()[..4],
+            [0xe3, 0xb0, 0xc4, 0x42]
+        );
+    }
+
+    #[test]
+    fn test_commit_reveal_cycle() {
+        let secret = [42u8; 32];
+        let target_slot = 12345u64;
+        
+        let context = test_context();
+
+        let commit_hash = generate_commit_hash_v2(&secret, target_slot, &context);
+        assert!(verify_reveal(COMMIT_SCHEME_V2, &secret, target_slot, &context, &commit_hash));
+        
+        // Wrong secret should fail
+        let wrong_secret = [43u8; 32];
+        assert!(!verify_reveal(COMMIT_SCHEME_V2, &wrong_secret, target_slot, &context, &commit_hash));
+        
+        // Wrong slot should fail
+        assert!(!verify_reveal(COMMIT_SCHEME_V2, &secret, target_slot + 1, &context, &commit_hash));
+
+        // Unknown scheme should fail
+        assert!(!verify_reveal(0, &secret, target_slot, &context, &commit_hash));
+    }
+
+    fn test_context() -> CommitContext {
+        CommitContext {
+            program_id: [0x11u8; 32],
+            block_id: 1,
+            track_id: 0,
+            exploration_index: 42,
+        }
+    }
+
+    #[test]
+    fn test_commit_bound_to_exploration_identity() {
+        let secret = [42u8; 32];
+        let target_slot = 12345u64;
+        let context = test_context();
+        let commit_hash = generate_commit_hash_v2(&secret, target_slot, &context);
+
+        // Replaying the commitment on another program, block, track or exploration fails
+        for other in [
+            CommitContext { program_id: [0x22u8; 32], ..context },
+            CommitContext { block_id: 2, ..context },
+            CommitContext { track_id: 1, ..context },
+            CommitContext { exploration_index: 43, ..context },
+        ] {
+            assert!(!verify_reveal(COMMIT_SCHEME_V2, &secret, target_slot, &other, &commit_hash));
+        }
+    }
+
+    #[test]
+    fn test_v1_commitments_remain_verifiable() {
+        let secret = [42u8; 32];
+        let target_slot = 12345u64;
+        let context = test_context();
+
+        let legacy = generate_commit_hash(&secret, target_slot);
+        assert!(verify_reveal(COMMIT_SCHEME_V1, &secret, target_slot, &context, &legacy));
+        assert!(!verify_reveal(COMMIT_SCHEME_V2, &secret, target_slot, &context, &legacy));
+    }
+
+    #[test]
+    fn test_entropy_accumulator_is_order_dependent() {
+        let alice = [0xa1u8; 32];
+        let bob = [0xb0u8; 32];
+        let a = [1u8; 32];
+        let b = [2u8; 32];
+
+        let ab = accumulate_entropy(&accumulate_entropy(&[0u8; 32], &alice, &a), &bob, &b);
+        let ba = accumulate_entropy(&accumulate_entropy(&[0u8; 32], &bob, &b), &alice, &a);
+        assert_ne!(ab, ba);
+
+        // Repeating a contribution does not cancel it out
+        let aa = accumulate_entropy(&accumulate_entropy(&[0u8; 32], &alice, &a), &alice, &a);
+        assert_ne!(aa, [0u8; 32]);
+    }
+
+    #[test]
+    fn test_random_value_mixes_all_sources() {
+        let secret = [1u8; 32];
+        let accumulator = [2u8; 32];
+        let slot_hash = [3u8; 32];
+        let base = generate_random_value(&secret, &accumulator, &slot_hash);
+
+        assert_ne!(base, generate_random_value(&[9u8; 32], &accumulator, &slot_hash));
+        assert_ne!(base, generate_random_value(&secret, &[9u8; 32], &slot_hash));
+        assert_ne!(base, generate_random_value(&secret, &accumulator, &[9u8; 32]));
+    }
+
+    #[test]
+    fn test_fallback_random_value_mixes_all_inputs() {
+        let commit_hash = [1u8; 32];
+        let accumulator = [2u8; 32];
+        let slot_hash = [3u8; 32];
+        let base = generate_fallback_random_value(&commit_hash, &accumulator, &slot_hash);
+
+        assert_ne!(base, slot_hash);
+        assert_ne!(base, generate_fallback_random_value(&[9u8; 32], &accumulator, &slot_hash));
+        assert_ne!(base, generate_fallback_random_value(&commit_hash, &[9u8; 32], &slot_hash));
+        assert_ne!(base, generate_fallback_random_value(&commit_hash, &accumulator, &[9u8; 32]));
+    }
+
+    fn slot_hashes_buffer(entries: &[(u64, [u8; 32])]) -> Vec<u8> {
+        let mut data = (entries.len() as u64).to_le_bytes().to_vec();
+        for (slot, hash) in entries {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(hash);
+        }
+        data
+    }
+
+    #[test]
+    fn test_expired_slot_hash_not_found() {
+        let data = slot_hashes_buffer(&[(1_000, [3u8; 32]), (999, [2u8; 32])]);
+
+        // Aged-out fallback slots must be re-committed, not replaced by a newer entry
+        assert_eq!(get_slot_hash(&data, 100), None);
+        assert_eq!(get_slot_hash(&slot_hashes_buffer(&[]), 1_000), None);
+        assert_eq!(get_slot_hash(&data[..40], 999), None);
+    }
+
+    #[test]
+    fn test_slot_hash_lookup() {
+        // Full 512-entry sysvar of consecutive slots
+        let entries: Vec<(u64, [u8; 32])> = (0..512u64)
+            .map(|i| (10_000 - i, [(i % 251) as u8; 32]))
+            .collect();
+        let data = slot_hashes_buffer(&entries);
+
+        for (slot, hash) in &entries {
+            assert_eq!(get_slot_hash(&data, *slot), Some(*hash));
+        }
+        // Newer than the newest entry, and expired
+        assert_eq!(get_slot_hash(&data, 10_001), None);
+        assert_eq!(get_slot_hash(&data, 10_000 - 512), None);
+    }
+
+    #[test]
+    fn test_slot_hash_lookup_with_skipped_slots() {
+        // Skipped slots leave gaps in the sysvar
+        let data = slot_hashes_buffer(&[
+            (120, [5u8; 32]),
+            (117, [4u8; 32]),
+            (116, [3u8; 32]),
+            (110, [2u8; 32]),
+            (101, [1u8; 32]),
+        ]);
+
+        assert_eq!(get_slot_hash(&data, 120), Some([5u8; 32]));
+        assert_eq!(get_slot_hash(&data, 116), Some([3u8; 32]));
+        assert_eq!(get_slot_hash(&data, 101), Some([1u8; 32]));
+        for skipped in [119, 118, 115, 111, 109, 102, 100] {
+            assert_eq!(get_slot_hash(&data, skipped), None);
+        }
+    }
+
+    #[test]
+    fn test_slot_hash_lookup_truncated_data() {
+        let data = slot_hashes_buffer(&[(30, [3u8; 32]), (20, [2u8; 32]), (10, [1u8; 32])]);
+
+        // Missing or partial length prefix
+        assert_eq!(get_slot_hash(&[], 30), None);
+        assert_eq!(get_slot_hash(&data[..7], 30), None);
+
+        // Last entry cut short: only complete entries are searched
+        let truncated = &data[..data.len() - 1];
+        assert_eq!(get_slot_hash(truncated, 30), Some([3u8; 32]));
+        assert_eq!(get_slot_hash(truncated, 10), None);
+
+        // Length prefix larger than the buffer
+        let mut overstated = data.clone();
+        overstated[0..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(get_slot_hash(&overstated, 10), Some([1u8; 32]));
+
+        // Length prefix smaller than the buffer: extra entries are ignored
+        let mut understated = data;
+        understated[0..8].copy_from_slice(&1u64.to_le_bytes());
+        assert_eq!(get_slot_hash(&understated, 30), Some([3u8; 32]));
+        assert_eq!(get_slot_hash(&understated, 20), None);
+    }
+
+    #[test]
+    fn test_ticket_snapshot_hash() {
+        let snapshot = hash_ticket_snapshot(&[100, 0, 50]);
+        assert_eq!(snapshot, hash_ticket_snapshot(&[100, 0, 50]));
+        assert_ne!(snapshot, hash_ticket_snapshot(&[100, 50, 0]));
+        assert_ne!(snapshot, hash_ticket_snapshot(&[100, 0, 50, 0]));
+    }
+
+    #[test]
+    fn test_commit_snapshot_hash() {
+        let hash_of = |deposits: [u64; 3], tickets: [u128; 3], rollover: u64| {
+            hash_commit_snapshot(deposits.into_iter(), tickets.into_iter(), rollover)
+        };
+        let snapshot = hash_of([10, 0, 5], [100, 0, 50], 7);
+        assert_eq!(snapshot, hash_of([10, 0, 5], [100, 0, 50], 7));
+        assert_ne!(snapshot, hash_of([10, 1, 5], [100, 0, 50], 7));
+        assert_ne!(snapshot, hash_of([10, 0, 5], [100, 1, 50], 7));
+        assert_ne!(snapshot, hash_of([10, 0, 5], [100, 0, 50], 8));
+    }
+
+    #[test]
+    fn test_winning_rig_selection() {
+        let mut rig_tickets = [0u128; 36];
+        rig_tickets[0] = 100;
+        rig_tickets[1] = 200;
+        rig_tickets[2] = 300;
+        // Total: 600
+        
+        // Random value that maps to ticket 50 should select rig 0
+        let mut random = [0u8; 32];
+        random[0] = 50; // Will give small number mod 600
+        let winner = select_winning_rig(&random, &rig_tickets);
+        assert!(winner.is_some());
+        
+        // All zeros should return None
+        let empty_tickets = [0u128; 36];
+        assert!(select_winning_rig(&random, &empty_tickets).is_none());
+    }
+
+    #[test]
+    fn test_winning_rig_selection_respects_rig_count() {
+        // 6-rig "quick" block: winner must stay within the arena
+        let rig_tickets = [10u128, 0, 0, 0, 0, 5];
+        for seed in 0u8..=255 {
+            let random = [seed; 32];
+            let winner = select_winning_rig(&random, &rig_tickets).unwrap();
+            assert!(winner == 0 || winner == 5);
+        }
+
+        // 64-rig "mega" block: the last rig is reachable
+        let mut mega_tickets = [0u128; 64];
+        mega_tickets[63] = 1;
+        assert_eq!(select_winning_rig(&[7u8; 32], &mega_tickets), Some(63));
+    }
+
+    #[test]
+    fn test_prize_rigs_drawn_without_replacement() {
+        let random_value = [0xabu8; 32];
+        let rig_tickets = [100u128, 0, 250, 50, 0, 600];
+
+        let prize_rigs = select_prize_rigs(&random_value, &rig_tickets, 3);
+        assert_eq!(prize_rigs.len(), 3);
+        assert_eq!(prize_rigs[0], select_winning_rig(&random_value, &rig_tickets).unwrap());
+        for (i, rig) in prize_rigs.iter().enumerate() {
+            assert!(rig_tickets[*rig as usize] > 0);
+            assert!(!prize_rigs[..i].contains(rig));
+        }
+    }
+
+    #[test]
+    fn test_prize_rigs_stop_when_tickets_run_out() {
+        let random_value = [0x5au8; 32];
+        assert_eq!(select_prize_rigs(&random_value, &[0, 40, 0], 3), vec![1]);
+        assert!(select_prize_rigs(&random_value, &[0, 0], 3).is_empty());
+        assert_ne!(derive_tier_random(&random_value, 1), derive_tier_random(&random_value, 2));
+    }
+
+    #[test]
+    fn test_consolation_tickets_in_range() {
+        let random_value = [0x42u8; 32];
+        let tickets = select_consolation_tickets(&random_value, 1_000, 5);
+        assert_eq!(tickets.len(), 5);
+        assert!(tickets.iter().all(|&t| t < 1_000));
+        assert_eq!(tickets, select_consolation_tickets(&random_value, 1_000, 5));
+        assert!(select_consolation_tickets(&random_value, 0, 5).is_empty());
+    }
+}
@@ -0,0 +1,16 @@
+//! Offline verifier for Rig It exploration draws.
+//!
+//! Re-derives an exploration's winner from an exported draw snapshot using the
+//! exact draw code the on-chain program runs (the `rig-it-draw` crate), and
+//! reports whether it matches the recorded `winning_rig`.
+//!
+//! The slot hash itself is taken as recorded; cross-check it against an RPC
+//! node or archive if the exporter is not trusted.
+
+pub use rig_it_draw as randomness;
+
+pub mod snapshot;
+pub mod verify;
+
+pub use snapshot::DrawSnapshot;
+pub use verify::{verify_draw, CheckResult, VerificationReport};
@@ -0,0 +1,60 @@
+//! rig-it-verify: re-derive an exploration draw offline.
+//!
+//! Usage:
+//!   rig-it-verify <snapshot.json>
+//!   rig-it-verify --accounts <draw_record.bin> <exploration.bin>   (feature `anchor`)
+//!
+//! Exits 0 if the recorded winner matches, 1 on a mismatch, 2 on bad input.
+
+use std::process::ExitCode;
+
+use rig_it_verifier::{verify_draw, DrawSnapshot};
+
+const USAGE: &str = "usage: rig-it-verify <snapshot.json>\n       \
+                     rig-it-verify --accounts <draw_record.bin> <exploration.bin>";
+
+fn load_snapshot(args: &[String]) -> Result<DrawSnapshot, String> {
+    match args {
+        [path] => {
+            let json = std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+            DrawSnapshot::from_json(&json).map_err(|e| format!("{path}: {e}"))
+        }
+        [flag, draw_record, exploration] if flag == "--accounts" => {
+            load_accounts(draw_record, exploration)
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+#[cfg(feature = "anchor")]
+fn load_accounts(draw_record: &str, exploration: &str) -> Result<DrawSnapshot, String> {
+    let record = std::fs::read(draw_record).map_err(|e| format!("{draw_record}: {e}"))?;
+    let state = std::fs::read(exploration).map_err(|e| format!("{exploration}: {e}"))?;
+    DrawSnapshot::from_account_bytes(&record, &state)
+}
+
+#[cfg(not(feature = "anchor"))]
+fn load_accounts(_draw_record: &str, _exploration: &str) -> Result<DrawSnapshot, String> {
+    Err("raw account input requires building with `--features anchor`".to_string())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let snapshot = match load_snapshot(&args) {
+        Ok(snapshot) => snapshot,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let report = verify_draw(&snapshot);
+    println!("{report}");
+
+    if report.is_valid() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    }
+}
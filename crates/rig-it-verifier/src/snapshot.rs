@@ -0,0 +1,207 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::randomness::CommitContext;
+
+/// Everything needed to re-derive an exploration's winner.
+/// Mirrors the on-chain `DrawRecord` plus the exploration's per-rig tickets.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DrawSnapshot {
+    /// Program that settled the draw
+    #[serde(with = "hex32")]
+    pub program_id: [u8; 32],
+    /// Parent block ID
+    pub block_id: u8,
+    /// Parent track ID
+    #[serde(default)]
+    pub track_id: u8,
+    /// Exploration index within the track
+    pub exploration_index: u64,
+    /// Version of the selection algorithm the draw was made with
+    pub algorithm_version: u8,
+    /// True if settled by the timeout fallback instead of an operator reveal
+    #[serde(default)]
+    pub used_fallback: bool,
+    /// Revealed operator secret (zero for fallback draws)
+    #[serde(with = "hex32")]
+    pub operator_secret: [u8; 32],
+    /// Operator commit hash
+    #[serde(with = "hex32")]
+    pub commit_hash: [u8; 32],
+    /// Commit scheme the commit hash was made with (v1 if absent)
+    #[serde(default = "default_commit_scheme")]
+    pub commit_scheme: u8,
+    /// Committed target slot
+    pub target_slot: u64,
+    /// Slot whose hash was used by the fallback (0 for operator reveals)
+    #[serde(default)]
+    pub fallback_slot: u64,
+    /// Slot hash mixed into the random value
+    #[serde(with = "hex32")]
+    pub slot_hash: [u8; 32],
+    /// Depositor entropy accumulator at commit
+    #[serde(with = "hex32")]
+    pub entropy_accumulator: [u8; 32],
+    /// Recorded final random value
+    #[serde(with = "hex32")]
+    pub random_value: [u8; 32],
+    /// Recorded hash of the per-rig ticket counts, if exported
+    #[serde(default, with = "hex32_opt")]
+    pub ticket_snapshot_hash: Option<[u8; 32]>,
+    /// Ticket count per rig (numbers or decimal strings)
+    #[serde(with = "ticket_counts")]
+    pub rig_tickets: Vec<u128>,
+    /// Recorded winning rig
+    pub winning_rig: u8,
+    /// Recorded rig per prize tier, first prize first (empty for single-winner draws)
+    #[serde(default)]
+    pub prize_rigs: Vec<u8>,
+}
+
+fn default_commit_scheme() -> u8 {
+    crate::randomness::COMMIT_SCHEME_V1
+}
+
+impl DrawSnapshot {
+    /// Identity the operator commitment is bound to
+    pub fn commit_context(&self) -> CommitContext {
+        CommitContext {
+            program_id: self.program_id,
+            block_id: self.block_id,
+            track_id: self.track_id,
+            exploration_index: self.exploration_index,
+        }
+    }
+
+    /// Parse a JSON snapshot
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Build a snapshot from raw `DrawRecord` and `ExplorationState` account data
+    /// (including the 8-byte discriminator), as returned by `getAccountInfo`
+    #[cfg(feature = "anchor")]
+    pub fn from_account_bytes(draw_record: &[u8], exploration: &[u8]) -> Result<Self, String> {
+        use anchor_lang::{AccountDeserialize, Discriminator};
+        use rig_it::state::{DrawRecord, ExplorationState, RigTotals};
+
+        let record = DrawRecord::try_deserialize(&mut &draw_record[..])
+            .map_err(|e| format!("invalid DrawRecord account: {e}"))?;
+
+        if exploration.len() < ExplorationState::LEN {
+            return Err("ExplorationState account data is too short".to_string());
+        }
+        if exploration[..8] != *ExplorationState::DISCRIMINATOR {
+            return Err("invalid ExplorationState discriminator".to_string());
+        }
+        let state: ExplorationState =
+            bytemuck::pod_read_unaligned(&exploration[8..ExplorationState::LEN]);
+        if exploration.len() < ExplorationState::space(state.rig_count) {
+            return Err("ExplorationState rig totals are truncated".to_string());
+        }
+        let rig_tickets = exploration[ExplorationState::LEN..ExplorationState::space(state.rig_count)]
+            .chunks_exact(std::mem::size_of::<RigTotals>())
+            .map(|rig| bytemuck::pod_read_unaligned::<RigTotals>(rig).tickets.get())
+            .collect();
+        if state.exploration_index != record.exploration_index ||
+            state.block_id != record.block_id ||
+            state.track_id != record.track_id
+        {
+            return Err("DrawRecord does not belong to this exploration".to_string());
+        }
+
+        Ok(Self {
+            program_id: rig_it::ID.to_bytes(),
+            block_id: record.block_id,
+            track_id: record.track_id,
+            exploration_index: record.exploration_index,
+            algorithm_version: record.algorithm_version,
+            used_fallback: record.used_fallback,
+            operator_secret: record.operator_secret,
+            commit_hash: record.commit_hash,
+            commit_scheme: record.commit_scheme,
+            target_slot: record.target_slot,
+            fallback_slot: record.fallback_slot,
+            slot_hash: record.slot_hash,
+            entropy_accumulator: record.entropy_accumulator,
+            random_value: record.random_value,
+            ticket_snapshot_hash: Some(record.ticket_snapshot_hash),
+            rig_tickets,
+            winning_rig: record.winning_rig,
+            prize_rigs: record
+                .prize_rigs
+                .iter()
+                .copied()
+                .take_while(|&rig| rig != rig_it::state::NO_WINNING_RIG)
+                .collect(),
+        })
+    }
+}
+
+mod hex32 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(bytes: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 32], D::Error> {
+        let text = String::deserialize(deserializer)?;
+        decode(&text).map_err(serde::de::Error::custom)
+    }
+
+    pub fn decode(text: &str) -> Result<[u8; 32], String> {
+        let bytes = hex::decode(text.trim_start_matches("0x")).map_err(|e| e.to_string())?;
+        bytes
+            .try_into()
+            .map_err(|bytes: Vec<u8>| format!("expected 32 bytes, got {}", bytes.len()))
+    }
+}
+
+mod hex32_opt {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        bytes: &Option<[u8; 32]>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match bytes {
+            Some(bytes) => serializer.serialize_some(&hex::encode(bytes)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<[u8; 32]>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|text| hex32::decode(&text).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+/// u128 ticket counts exceed what JSON numbers carry safely, so decimal
+/// strings are accepted (and written) alongside plain u64 numbers
+mod ticket_counts {
+    use super::*;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum TicketCount {
+        Number(u64),
+        Text(String),
+    }
+
+    pub fn serialize<S: Serializer>(tickets: &[u128], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(tickets.iter().map(|t| t.to_string()))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u128>, D::Error> {
+        Vec::<TicketCount>::deserialize(deserializer)?
+            .into_iter()
+            .map(|count| match count {
+                TicketCount::Number(n) => Ok(n as u128),
+                TicketCount::Text(text) => text.parse().map_err(serde::de::Error::custom),
+            })
+            .collect()
+    }
+}
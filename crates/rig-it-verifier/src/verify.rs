@@ -0,0 +1,278 @@
+use std::fmt;
+
+use crate::randomness::{
+    generate_fallback_random_value, generate_random_value, hash_ticket_snapshot, select_prize_rigs, select_winning_rig,
+    verify_reveal, DRAW_ALGORITHM_VERSION,
+};
+use crate::DrawSnapshot;
+
+/// Outcome of a single verification step
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub passed: bool,
+    pub detail: String,
+}
+
+/// Outcome of re-deriving a draw
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationReport {
+    pub checks: Vec<CheckResult>,
+    /// Winner recomputed from the snapshot inputs
+    pub recomputed_winning_rig: Option<u8>,
+}
+
+impl VerificationReport {
+    /// True if every check passed
+    pub fn is_valid(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+
+    fn check(&mut self, name: &'static str, passed: bool, detail: String) {
+        self.checks.push(CheckResult { name, passed, detail });
+    }
+}
+
+impl fmt::Display for VerificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            let status = if check.passed { "PASS" } else { "FAIL" };
+            writeln!(f, "[{status}] {}: {}", check.name, check.detail)?;
+        }
+        let verdict = if self.is_valid() { "VERIFIED" } else { "MISMATCH" };
+        write!(f, "{verdict}")
+    }
+}
+
+/// Re-derive the draw from its inputs and compare with the recorded results
+pub fn verify_draw(snapshot: &DrawSnapshot) -> VerificationReport {
+    let mut report = VerificationReport {
+        checks: Vec::new(),
+        recomputed_winning_rig: None,
+    };
+
+    let supported = (1..=DRAW_ALGORITHM_VERSION).contains(&snapshot.algorithm_version);
+    report.check(
+        "algorithm version",
+        supported,
+        format!(
+            "draw v{}, verifier supports v1-v{}",
+            snapshot.algorithm_version, DRAW_ALGORITHM_VERSION
+        ),
+    );
+    if !supported {
+        return report;
+    }
+
+    // Fallback draws mix the commitment and entropy into the fallback slot hash
+    // (the slot hash alone before v2); operator reveals must match the commit
+    let random_value = if snapshot.used_fallback {
+        report.check(
+            "operator reveal",
+            true,
+            format!("timeout fallback using slot {}", snapshot.fallback_slot),
+        );
+        if snapshot.algorithm_version == 1 {
+            snapshot.slot_hash
+        } else {
+            generate_fallback_random_value(
+                &snapshot.commit_hash,
+                &snapshot.entropy_accumulator,
+                &snapshot.slot_hash,
+            )
+        }
+    } else {
+        report.check(
+            "operator reveal",
+            verify_reveal(
+                snapshot.commit_scheme,
+                &snapshot.operator_secret,
+                snapshot.target_slot,
+                &snapshot.commit_context(),
+                &snapshot.commit_hash,
+            ),
+            format!(
+                "secret against v{} commit for target slot {}",
+                snapshot.commit_scheme, snapshot.target_slot
+            ),
+        );
+        generate_random_value(
+            &snapshot.operator_secret,
+            &snapshot.entropy_accumulator,
+            &snapshot.slot_hash,
+        )
+    };
+
+    report.check(
+        "random value",
+        random_value == snapshot.random_value,
+        format!("recomputed {}", hex::encode(random_value)),
+    );
+
+    if let Some(recorded) = snapshot.ticket_snapshot_hash {
+        let computed = hash_ticket_snapshot(&snapshot.rig_tickets);
+        report.check(
+            "ticket snapshot",
+            computed == recorded,
+            format!("{} rigs, hash {}", snapshot.rig_tickets.len(), hex::encode(computed)),
+        );
+    }
+
+    let winner = select_winning_rig(&random_value, &snapshot.rig_tickets);
+    report.recomputed_winning_rig = winner;
+    report.check(
+        "winning rig",
+        winner == Some(snapshot.winning_rig),
+        match winner {
+            Some(rig) => format!("recomputed {rig}, recorded {}", snapshot.winning_rig),
+            None => "no tickets to draw from".to_string(),
+        },
+    );
+
+    // Lower prize tiers are drawn without replacement from derived randomness
+    if snapshot.prize_rigs.len() > 1 {
+        let tiers = snapshot.prize_rigs.len();
+        let prize_rigs = select_prize_rigs(&random_value, &snapshot.rig_tickets, tiers);
+        report.check(
+            "prize tiers",
+            prize_rigs == snapshot.prize_rigs,
+            format!("recomputed {prize_rigs:?}, recorded {:?}", snapshot.prize_rigs),
+        );
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::randomness::{
+        generate_commit_hash, generate_commit_hash_v2, CommitContext, COMMIT_SCHEME_V1,
+        COMMIT_SCHEME_V2,
+    };
+
+    fn settled_snapshot() -> DrawSnapshot {
+        let operator_secret = [7u8; 32];
+        let target_slot = 5_000;
+        let slot_hash = [9u8; 32];
+        let entropy_accumulator = [3u8; 32];
+        let rig_tickets = vec![100, 0, 250, 50, 0, 600];
+        let random_value = generate_random_value(&operator_secret, &entropy_accumulator, &slot_hash);
+        let context = CommitContext {
+            program_id: [0x11u8; 32],
+            block_id: 0,
+            track_id: 1,
+            exploration_index: 9,
+        };
+
+        DrawSnapshot {
+            program_id: context.program_id,
+            block_id: context.block_id,
+            track_id: context.track_id,
+            exploration_index: context.exploration_index,
+            algorithm_version: DRAW_ALGORITHM_VERSION,
+            used_fallback: false,
+            operator_secret,
+            commit_hash: generate_commit_hash_v2(&operator_secret, target_slot, &context),
+            commit_scheme: COMMIT_SCHEME_V2,
+            target_slot,
+            fallback_slot: 0,
+            slot_hash,
+            entropy_accumulator,
+            random_value,
+            ticket_snapshot_hash: Some(hash_ticket_snapshot(&rig_tickets)),
+            winning_rig: select_winning_rig(&random_value, &rig_tickets).unwrap(),
+            prize_rigs: Vec::new(),
+            rig_tickets,
+        }
+    }
+
+    #[test]
+    fn test_valid_draw_verifies() {
+        let report = verify_draw(&settled_snapshot());
+        assert!(report.is_valid(), "{report}");
+    }
+
+    #[test]
+    fn test_tampered_winner_is_detected() {
+        let mut snapshot = settled_snapshot();
+        snapshot.winning_rig = (snapshot.winning_rig + 1) % 6;
+        let report = verify_draw(&snapshot);
+        assert!(!report.is_valid());
+        assert_ne!(report.recomputed_winning_rig, Some(snapshot.winning_rig));
+    }
+
+    #[test]
+    fn test_tampered_inputs_are_detected() {
+        let mut wrong_secret = settled_snapshot();
+        wrong_secret.operator_secret = [8u8; 32];
+        assert!(!verify_draw(&wrong_secret).is_valid());
+
+        let mut wrong_tickets = settled_snapshot();
+        wrong_tickets.rig_tickets[0] += 1;
+        assert!(!verify_draw(&wrong_tickets).is_valid());
+
+        let mut wrong_version = settled_snapshot();
+        wrong_version.algorithm_version = DRAW_ALGORITHM_VERSION + 1;
+        assert!(!verify_draw(&wrong_version).is_valid());
+    }
+
+    #[test]
+    fn test_commit_scheme_is_respected() {
+        // Commitment replayed from another exploration
+        let mut replayed = settled_snapshot();
+        replayed.exploration_index += 1;
+        assert!(!verify_draw(&replayed).is_valid());
+
+        // Legacy v1 commitments still verify
+        let mut legacy = settled_snapshot();
+        legacy.commit_scheme = COMMIT_SCHEME_V1;
+        legacy.commit_hash = generate_commit_hash(&legacy.operator_secret, legacy.target_slot);
+        assert!(verify_draw(&legacy).is_valid());
+    }
+
+    #[test]
+    fn test_prize_tiers_verify() {
+        let mut snapshot = settled_snapshot();
+        snapshot.prize_rigs = select_prize_rigs(&snapshot.random_value, &snapshot.rig_tickets, 3);
+        assert_eq!(snapshot.prize_rigs[0], snapshot.winning_rig);
+        assert!(verify_draw(&snapshot).is_valid());
+
+        snapshot.prize_rigs.swap(1, 2);
+        assert!(!verify_draw(&snapshot).is_valid());
+    }
+
+    #[test]
+    fn test_fallback_draw_verifies() {
+        let mut snapshot = settled_snapshot();
+        snapshot.used_fallback = true;
+        snapshot.operator_secret = [0u8; 32];
+        snapshot.fallback_slot = 5_150;
+        snapshot.random_value = generate_fallback_random_value(
+            &snapshot.commit_hash,
+            &snapshot.entropy_accumulator,
+            &snapshot.slot_hash,
+        );
+        snapshot.winning_rig = select_winning_rig(&snapshot.random_value, &snapshot.rig_tickets).unwrap();
+        assert!(verify_draw(&snapshot).is_valid());
+
+        // Bare slot hash is only accepted for v1 draws
+        let mut v1 = snapshot.clone();
+        v1.random_value = v1.slot_hash;
+        v1.winning_rig = select_winning_rig(&v1.slot_hash, &v1.rig_tickets).unwrap();
+        assert!(!verify_draw(&v1).is_valid());
+        v1.algorithm_version = 1;
+        assert!(verify_draw(&v1).is_valid());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let snapshot = settled_snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(DrawSnapshot::from_json(&json).unwrap(), snapshot);
+
+        // Ticket counts may be plain numbers
+        let numeric = json.replace("\"100\"", "100");
+        assert_eq!(DrawSnapshot::from_json(&numeric).unwrap(), snapshot);
+    }
+}
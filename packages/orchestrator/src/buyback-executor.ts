@@ -3,11 +3,24 @@ import { Logger } from 'pino';
 import { Config } from './config';
 import { executePrivacyCashTransfer, isPrivacyCashAvailable } from './privacy-cash-stub';
 
+interface BuybackBudget {
+  burnAmount: bigint;
+  lpAmount: bigint;
+}
+
+/** Catch-up period after the window closes (BUYBACK_CATCH_UP_SECS on-chain) */
+const BUYBACK_CATCH_UP_MS = 24 * 60 * 60 * 1000;
+
+/** One on-chain schedule tranche; executable from executeAt until expiresAt, once funded */
 interface ScheduledBuyback {
   explorationKey: string;
+  tranche: number;
+  bps: bigint;
   burnAmount: bigint;
   lpAmount: bigint;
   executeAt: number;
+  expiresAt: number;
+  funded: boolean;
   executed: boolean;
 }
 
@@ -16,6 +29,7 @@ export class BuybackExecutor {
   private operator: Keypair;
   private config: Config;
   private logger: Logger;
+  private budgets: Map<string, BuybackBudget> = new Map();
   private scheduledBuybacks: Map<string, ScheduledBuyback> = new Map();
   private checkInterval: NodeJS.Timeout | null = null;
 
@@ -35,56 +49,102 @@ export class BuybackExecutor {
   }
 
   /**
-   * Schedule a buyback with randomized delay (2-5 hours)
+   * Record an exploration's buyback budget (BuybackBudgetAllocated) and fund
+   * its scheduled tranches. The schedule is normally emitted first, at
+   * settlement; a budget seen before it is kept until it arrives.
    */
-  scheduleBuyback(data: {
+  recordBudget(data: {
     explorationKey: PublicKey | string;
     burnAmount: bigint | number;
     lpAmount: bigint | number;
   }): void {
     const key = data.explorationKey.toString();
-    
-    if (this.scheduledBuybacks.has(key)) {
-      this.logger.debug({ key }, 'Buyback already scheduled');
-      return;
-    }
-
-    // Random delay between min and max hours
-    const minMs = this.config.buybackMinDelayHours * 60 * 60 * 1000;
-    const maxMs = this.config.buybackMaxDelayHours * 60 * 60 * 1000;
-    const delay = minMs + Math.random() * (maxMs - minMs);
-    const executeAt = Date.now() + delay;
-
-    const buyback: ScheduledBuyback = {
-      explorationKey: key,
+    this.budgets.set(key, {
       burnAmount: BigInt(data.burnAmount.toString()),
       lpAmount: BigInt(data.lpAmount.toString()),
-      executeAt,
-      executed: false,
-    };
+    });
+    this.fundTranches(key);
+  }
 
-    this.scheduledBuybacks.set(key, buyback);
+  /**
+   * Queue the tranches of an on-chain buyback schedule (BuybackScheduled).
+   * The window is derived at settlement from the exploration's random value,
+   * so execution times are fixed on-chain rather than picked here.
+   */
+  scheduleBuyback(data: {
+    explorationKey: PublicKey | string;
+    windowEndTs: bigint | number;
+    trancheStartTs: (bigint | number)[];
+    trancheBps: number[];
+  }): void {
+    const key = data.explorationKey.toString();
+    const expiresAt = Number(data.windowEndTs) * 1000 + BUYBACK_CATCH_UP_MS;
 
-    this.logger.info(
-      {
+    data.trancheStartTs.forEach((startTs, tranche) => {
+      const trancheKey = `${key}:${tranche}`;
+      if (this.scheduledBuybacks.has(trancheKey)) {
+        this.logger.debug({ trancheKey }, 'Buyback tranche already scheduled');
+        return;
+      }
+
+      const buyback: ScheduledBuyback = {
         explorationKey: key,
-        burnAmount: buyback.burnAmount.toString(),
-        lpAmount: buyback.lpAmount.toString(),
-        executeAt: new Date(executeAt).toISOString(),
-        delayHours: (delay / (60 * 60 * 1000)).toFixed(2),
-      },
-      'Buyback scheduled'
-    );
+        tranche,
+        bps: BigInt(data.trancheBps[tranche]),
+        burnAmount: 0n,
+        lpAmount: 0n,
+        executeAt: Number(startTs) * 1000,
+        expiresAt,
+        funded: false,
+        executed: false,
+      };
+      this.scheduledBuybacks.set(trancheKey, buyback);
+
+      this.logger.info(
+        {
+          explorationKey: key,
+          tranche,
+          executeAt: new Date(buyback.executeAt).toISOString(),
+        },
+        'Buyback tranche scheduled'
+      );
+    });
+    this.fundTranches(key);
+  }
+
+  /** Size an exploration's tranches once both its schedule and budget are known */
+  private fundTranches(key: string): void {
+    const budget = this.budgets.get(key);
+    if (!budget) return;
+
+    let funded = false;
+    for (const buyback of this.scheduledBuybacks.values()) {
+      if (buyback.explorationKey !== key || buyback.funded) continue;
+      buyback.burnAmount = (budget.burnAmount * buyback.bps) / 10000n;
+      buyback.lpAmount = (budget.lpAmount * buyback.bps) / 10000n;
+      buyback.funded = true;
+      funded = true;
+    }
+    if (funded) this.budgets.delete(key);
   }
 
   private async checkAndExecuteBuybacks(): Promise<void> {
     const now = Date.now();
 
     for (const [key, buyback] of this.scheduledBuybacks.entries()) {
-      if (!buyback.executed && buyback.executeAt <= now) {
-        await this.executeBuyback(buyback);
-        buyback.executed = true;
+      // Missed tranches catch up until the on-chain grace period ends
+      if (buyback.executed || !buyback.funded || buyback.executeAt > now) continue;
+      if (buyback.expiresAt < now) {
+        this.logger.warn(
+          { explorationKey: buyback.explorationKey, tranche: buyback.tranche },
+          'Buyback tranche expired before it could execute'
+        );
+        this.scheduledBuybacks.delete(key);
+        continue;
       }
+
+      buyback.executed = true;
+      await this.executeBuyback(buyback);
     }
 
     // Clean up old executed buybacks (older than 24 hours)
@@ -100,6 +160,7 @@ export class BuybackExecutor {
     this.logger.info(
       {
         explorationKey: buyback.explorationKey,
+        tranche: buyback.tranche,
         burnAmount: buyback.burnAmount.toString(),
         lpAmount: buyback.lpAmount.toString(),
       },
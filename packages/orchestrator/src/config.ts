@@ -10,10 +10,6 @@ export interface Config {
   // Operator
   operatorPrivateKey: string;
   
-  // Buyback settings
-  buybackMinDelayHours: number;
-  buybackMaxDelayHours: number;
-  
   // Privacy Cash (stub)
   privacyCashEnabled: boolean;
   privacyCashApiUrl: string;
@@ -43,8 +39,6 @@ export function loadConfig(): Config {
       process.env.RIG_TOKEN_MINT || 'RiGTokEnMintXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX'
     ),
     operatorPrivateKey: process.env.OPERATOR_PRIVATE_KEY || '[]',
-    buybackMinDelayHours: parseInt(process.env.BUYBACK_MIN_DELAY_HOURS || '2'),
-    buybackMaxDelayHours: parseInt(process.env.BUYBACK_MAX_DELAY_HOURS || '5'),
     privacyCashEnabled: process.env.PRIVACY_CASH_ENABLED === 'true',
     privacyCashApiUrl: process.env.PRIVACY_CASH_API_URL || '',
     blocks: [
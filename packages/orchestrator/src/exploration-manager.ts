@@ -19,10 +19,12 @@ import {
   getBlockVaultPDA,
   getBlockVaultAuthorityPDA,
   getTreasuryVaultPDA,
+  buildCommitPreimage,
 } from '@rig-it/sdk';
 
 interface ExplorationInfo {
   blockId: number;
+  trackId: number;
   explorationIndex: bigint;
   status: string;
   activeEndTs: number;
@@ -131,12 +133,12 @@ export class ExplorationManager {
     }
   }
 
-  async finalizeExploration(blockId: number, explorationIndex: bigint): Promise<string> {
-    this.logger.info({ blockId, explorationIndex: explorationIndex.toString() }, 'Finalizing exploration');
+  async finalizeExploration(blockId: number, trackId: number, explorationIndex: bigint): Promise<string> {
+    this.logger.info({ blockId, trackId, explorationIndex: explorationIndex.toString() }, 'Finalizing exploration');
 
     try {
       // Phase 1: Commit randomness
-      const commitResult = await this.commitRandomness(blockId, explorationIndex);
+      const commitResult = await this.commitRandomness(blockId, trackId, explorationIndex);
       
       if (commitResult === 'rolled_over') {
         this.logger.info({ blockId, explorationIndex: explorationIndex.toString() }, 'Exploration rolled over (threshold not met)');
@@ -148,7 +150,7 @@ export class ExplorationManager {
       await this.waitForSlot(targetSlot);
 
       // Phase 2: Reveal randomness
-      const revealResult = await this.revealRandomness(blockId, explorationIndex);
+      const revealResult = await this.revealRandomness(blockId, trackId, explorationIndex);
       
       // Phase 3: Allocate buyback budget
       await this.allocateBuybackBudget(blockId, explorationIndex);
@@ -160,7 +162,7 @@ export class ExplorationManager {
     }
   }
 
-  private async commitRandomness(blockId: number, explorationIndex: bigint): Promise<string> {
+  private async commitRandomness(blockId: number, trackId: number, explorationIndex: bigint): Promise<string> {
     // Generate secret for commit-reveal
     const secret = crypto.randomBytes(32);
     
@@ -169,10 +171,10 @@ export class ExplorationManager {
     const targetSlot = currentSlot + 20; // ~8 seconds in the future
     
     // Generate commit hash
-    const commitHash = this.generateCommitHash(secret, targetSlot);
+    const commitHash = this.generateCommitHash(secret, targetSlot, blockId, trackId, explorationIndex);
     
     // Store secret for later reveal
-    const key = `${blockId}-${explorationIndex}`;
+    const key = `${blockId}-${trackId}-${explorationIndex}`;
     this.pendingSecrets.set(key, secret);
 
     this.logger.info(
@@ -186,8 +188,8 @@ export class ExplorationManager {
     return 'committed';
   }
 
-  private async revealRandomness(blockId: number, explorationIndex: bigint): Promise<string> {
-    const key = `${blockId}-${explorationIndex}`;
+  private async revealRandomness(blockId: number, trackId: number, explorationIndex: bigint): Promise<string> {
+    const key = `${blockId}-${trackId}-${explorationIndex}`;
     const secret = this.pendingSecrets.get(key);
     
     if (!secret) {
@@ -218,11 +220,20 @@ export class ExplorationManager {
     // Simplified - real implementation needs proper instruction building
   }
 
-  private generateCommitHash(secret: Buffer, targetSlot: number): Buffer {
-    const data = Buffer.concat([
-      secret,
-      Buffer.from(new BN(targetSlot).toArray('le', 8)),
-    ]);
+  private generateCommitHash(
+    secret: Buffer,
+    targetSlot: number,
+    blockId: number,
+    trackId: number,
+    explorationIndex: bigint
+  ): Buffer {
+    // Bound to this exploration so the commitment can't be replayed elsewhere
+    const data = buildCommitPreimage(secret, targetSlot, {
+      blockId,
+      trackId,
+      explorationIndex,
+      programId: this.programId,
+    });
     return crypto.createHash('sha256').update(data).digest();
   }
 
@@ -251,8 +262,11 @@ export class ExplorationManager {
 
   private parseExplorationState(data: Buffer, blockId: number, explorationIndex: bigint): ExplorationInfo {
     // Simplified parsing - real implementation needs proper deserialization
+    // Skip discriminator (8) + version (1) + bump (1) + block_id (1)
+    const trackIdOffset = 8 + 1 + 1 + 1;
     return {
       blockId,
+      trackId: data.readUInt8(trackIdOffset),
       explorationIndex,
       status: 'active', // Parse from data
       activeEndTs: 0, // Parse from data
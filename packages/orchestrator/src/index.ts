@@ -71,6 +71,9 @@ async function main() {
         scheduler.onExplorationRolledOver(event.data);
         break;
       case 'BuybackBudgetAllocated':
+        buybackExecutor.recordBudget(event.data);
+        break;
+      case 'BuybackScheduled':
         buybackExecutor.scheduleBuyback(event.data);
         break;
     }
@@ -7,6 +7,7 @@ import { BuybackExecutor } from './buyback-executor';
 
 interface ScheduledTask {
   blockId: number;
+  trackId: number;
   explorationIndex: bigint;
   type: 'finalize' | 'start_next';
   executeAt: number;
@@ -82,6 +83,7 @@ export class Scheduler {
       if (exploration.status === 'active' && now >= exploration.activeEndTs) {
         this.scheduleTask({
           blockId,
+          trackId: exploration.trackId,
           explorationIndex: exploration.explorationIndex,
           type: 'finalize',
           executeAt: Date.now(),
@@ -93,7 +95,7 @@ export class Scheduler {
   }
 
   scheduleTask(task: ScheduledTask): void {
-    const key = `${task.blockId}-${task.explorationIndex}-${task.type}`;
+    const key = `${task.blockId}-${task.trackId}-${task.explorationIndex}-${task.type}`;
     
     if (this.tasks.has(key)) {
       this.logger.debug({ key }, 'Task already scheduled');
@@ -124,6 +126,7 @@ export class Scheduler {
         case 'finalize':
           await this.explorationManager.finalizeExploration(
             task.blockId,
+            task.trackId,
             task.explorationIndex
           );
           break;
@@ -155,6 +158,7 @@ export class Scheduler {
     // Schedule finalization after active phase
     this.scheduleTask({
       blockId: data.blockId,
+      trackId: data.trackId,
       explorationIndex: BigInt(data.explorationIndex.toString()),
       type: 'finalize',
       executeAt: activeEndMs + 5000, // 5 second buffer
@@ -173,6 +177,7 @@ export class Scheduler {
     // In practice, you might want some buffer time
     this.scheduleTask({
       blockId,
+      trackId: data.trackId,
       explorationIndex: BigInt(data.explorationIndex.toString()) + 1n,
       type: 'start_next',
       executeAt: Date.now() + 2400 * 1000 + 5000, // cooldown + buffer
@@ -188,6 +193,7 @@ export class Scheduler {
     // Similar to settled - schedule next exploration
     this.scheduleTask({
       blockId: data.blockId,
+      trackId: data.trackId,
       explorationIndex: BigInt(data.explorationIndex.toString()) + 1n,
       type: 'start_next',
       executeAt: Date.now() + 2400 * 1000 + 5000,
@@ -5,6 +5,7 @@ import {
   Keypair,
   Transaction,
   TransactionInstruction,
+  AccountMeta,
   SystemProgram,
   SYSVAR_CLOCK_PUBKEY,
   SYSVAR_SLOT_HASHES_PUBKEY,
@@ -14,7 +15,10 @@ import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from '@solana/spl-token';
 import {
   ProtocolConfig,
   BlockState,
+  TrackState,
   ExplorationState,
+  RigTotals,
+  OperatorBond,
   RigState,
   DepositReceipt,
   InitProtocolArgs,
@@ -23,6 +27,10 @@ import {
   CommitRandomnessArgs,
   RevealRandomnessArgs,
   SetParamsArgs,
+  SetBlockParamsArgs,
+  ExecuteBuybackBurnArgs,
+  ExecuteBuybackLpArgs,
+  WithdrawTreasuryArgs,
   ExplorationStatus,
   RIGS_PER_EXPLORATION,
 } from './types';
@@ -31,15 +39,27 @@ import {
   PROGRAM_ID,
   getProtocolConfigPDA,
   getBlockStatePDA,
+  getTrackStatePDA,
   getExplorationStatePDA,
   getRigStatePDA,
   getDepositReceiptPDA,
   getBlockVaultPDA,
   getBlockVaultAuthorityPDA,
   getTreasuryVaultPDA,
+  getTreasuryAuthorityPDA,
+  getRigBuybackVaultPDA,
+  getLpLockPDA,
+  getLpLockVaultPDA,
+  getBuybackSchedulePDA,
+  getJackpotVaultPDA,
+  getOperatorBondPDA,
+  getUserStatsPDA,
+  getReferrerPDA,
+  getReferralCodePDA,
+  getReferralVolumePDA,
 } from './pdas';
 
-import { calculateEffectiveTickets } from './utils/tickets';
+import { calculateEffectiveTickets, decodeRigTotals } from './utils/tickets';
 
 // IDL will be generated by Anchor build - placeholder for type
 type RigItIDL = Idl;
@@ -91,8 +111,32 @@ export class RigItClient {
     }
   }
 
-  async getExplorationState(blockId: number, explorationIndex: BN): Promise<ExplorationState | null> {
-    const [pda] = getExplorationStatePDA(blockId, explorationIndex, this.programId);
+  async getTrackState(blockId: number, trackId: number = 0): Promise<TrackState | null> {
+    const [pda] = getTrackStatePDA(blockId, trackId, this.programId);
+    try {
+      const account = await this.program.account.trackState.fetch(pda);
+      return account as unknown as TrackState;
+    } catch {
+      return null;
+    }
+  }
+
+  async getOperatorBond(blockId: number): Promise<OperatorBond | null> {
+    const [pda] = getOperatorBondPDA(blockId, this.programId);
+    try {
+      const account = await this.program.account.operatorBond.fetch(pda);
+      return account as unknown as OperatorBond;
+    } catch {
+      return null;
+    }
+  }
+
+  async getExplorationState(
+    blockId: number,
+    explorationIndex: BN,
+    trackId: number = 0
+  ): Promise<ExplorationState | null> {
+    const [pda] = getExplorationStatePDA(blockId, explorationIndex, this.programId, trackId);
     try {
       const account = await this.program.account.explorationState.fetch(pda);
       return account as unknown as ExplorationState;
@@ -101,14 +145,27 @@ export class RigItClient {
     }
   }
 
-  async getCurrentExploration(blockId: number): Promise<ExplorationState | null> {
-    const block = await this.getBlockState(blockId);
-    if (!block) return null;
+  async getCurrentExploration(blockId: number, trackId: number = 0): Promise<ExplorationState | null> {
+    const track = await this.getTrackState(blockId, trackId);
+    if (!track) return null;
     
-    const currentIndex = block.currentExplorationIndex.subn(1);
+    const currentIndex = track.currentExplorationIndex.subn(1);
     if (currentIndex.ltn(0)) return null;
     
-    return this.getExplorationState(blockId, currentIndex);
+    return this.getExplorationState(blockId, currentIndex, trackId);
+  }
+
+  /** Per-rig deposit and ticket totals of an exploration */
+  async getRigTotals(
+    blockId: number,
+    explorationIndex: BN,
+    trackId: number = 0
+  ): Promise<RigTotals[] | null> {
+    const [pda] = getExplorationStatePDA(blockId, explorationIndex, this.programId, trackId);
+    const info = await this.provider.connection.getAccountInfo(pda);
+    if (!info) return null;
+    // rigCount is the sixth header byte, after the 8-byte discriminator
+    return decodeRigTotals(info.data, info.data[8 + 5]);
   }
 
   async getRigState(explorationKey: PublicKey, rigIndex: number): Promise<RigState | null> {
@@ -135,9 +192,12 @@ export class RigItClient {
     }
   }
 
-  async getAllRigsForExploration(explorationKey: PublicKey): Promise<(RigState | null)[]> {
+  async getAllRigsForExploration(
+    explorationKey: PublicKey,
+    rigCount: number = RIGS_PER_EXPLORATION
+  ): Promise<(RigState | null)[]> {
     const rigs: (RigState | null)[] = [];
-    for (let i = 0; i < RIGS_PER_EXPLORATION; i++) {
+    for (let i = 0; i < rigCount; i++) {
       rigs.push(await this.getRigState(explorationKey, i));
     }
     return rigs;
@@ -203,27 +263,104 @@ export class RigItClient {
     return tx;
   }
 
-  async startExploration(blockId: number): Promise<string> {
+  async initTreasuries(blockId: number, assetMint: PublicKey): Promise<string> {
+    const [protocolConfig] = getProtocolConfigPDA(this.programId);
+    const [blockState] = getBlockStatePDA(blockId, this.programId);
+
+    const tx = await this.program.methods
+      .initTreasuries()
+      .accounts({
+        protocolConfig,
+        blockState,
+        treasuryBuybackBurn: getTreasuryVaultPDA('buyback_burn', blockId, this.programId)[0],
+        treasuryBuybackLp: getTreasuryVaultPDA('buyback_lp', blockId, this.programId)[0],
+        treasuryTeamOps: getTreasuryVaultPDA('team_ops', blockId, this.programId)[0],
+        treasuryEcosystem: getTreasuryVaultPDA('ecosystem', blockId, this.programId)[0],
+        treasuryAuthority: getTreasuryAuthorityPDA(blockId, this.programId)[0],
+        assetMint,
+        admin: this.provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async initJackpot(blockId: number, assetMint: PublicKey): Promise<string> {
     const [protocolConfig] = getProtocolConfigPDA(this.programId);
     const [blockState] = getBlockStatePDA(blockId, this.programId);
+
+    const tx = await this.program.methods
+      .initJackpot()
+      .accounts({
+        protocolConfig,
+        blockState,
+        jackpotVault: getJackpotVaultPDA(blockId, this.programId)[0],
+        blockVaultAuthority: getBlockVaultAuthorityPDA(blockId, this.programId)[0],
+        assetMint,
+        admin: this.provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async initFeeVault(blockId: number, assetMint: PublicKey): Promise<string> {
+    const [protocolConfig] = getProtocolConfigPDA(this.programId);
+    const [blockState] = getBlockStatePDA(blockId, this.programId);
+
+    const tx = await this.program.methods
+      .initFeeVault()
+      .accounts({
+        protocolConfig,
+        blockState,
+        treasuryDepositFees: getTreasuryVaultPDA('deposit_fees', blockId, this.programId)[0],
+        treasuryAuthority: getTreasuryAuthorityPDA(blockId, this.programId)[0],
+        assetMint,
+        admin: this.provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async startExploration(blockId: number, trackId: number = 0): Promise<string> {
+    const [protocolConfig] = getProtocolConfigPDA(this.programId);
+    const [blockState] = getBlockStatePDA(blockId, this.programId);
+    const [trackState] = getTrackStatePDA(blockId, trackId, this.programId);
     
-    const block = await this.getBlockState(blockId);
-    if (!block) throw new Error('Block not found');
+    const track = await this.getTrackState(blockId, trackId);
+    if (!track) throw new Error('Track not found');
     
     const [explorationState] = getExplorationStatePDA(
       blockId,
-      block.currentExplorationIndex,
-      this.programId
+      track.currentExplorationIndex,
+      this.programId,
+      trackId
     );
+    const previousExploration = track.currentExplorationIndex.isZero()
+      ? null
+      : getExplorationStatePDA(
+          blockId,
+          track.currentExplorationIndex.subn(1),
+          this.programId,
+          trackId
+        )[0];
     
     const tx = await this.program.methods
       .startExploration()
       .accounts({
         protocolConfig,
         blockState,
+        trackState,
         explorationState,
-        previousExploration: null, // Simplified - add logic for rollover
-        operator: this.provider.wallet.publicKey,
+        previousExploration,
+        caller: this.provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
         clock: SYSVAR_CLOCK_PUBKEY,
       })
@@ -259,6 +396,13 @@ export class RigItClient {
     );
     
     const config = await this.getProtocolConfig();
+    const referrer = args.referrer ? getReferrerPDA(args.referrer, this.programId)[0] : null;
+    const referrerStats = args.referrer
+      ? getUserStatsPDA(args.referrer, this.programId)[0]
+      : null;
+    const referralVolume = args.referrer
+      ? getReferralVolumePDA(explorationState, args.referrer, this.programId)[0]
+      : null;
     
     const tx = await this.program.methods
       .depositToRig(args)
@@ -271,8 +415,14 @@ export class RigItClient {
         user: this.provider.wallet.publicKey,
         userTokenAccount,
         blockVault,
+        treasuryDepositFees: block.depositFeeBps > 0
+          ? getTreasuryVaultPDA('deposit_fees', blockId, this.programId)[0]
+          : null,
         userRigTokenAccount: userRigTokenAccount || null,
         rigTokenMint: config?.rigTokenMint || null,
+        referrer,
+        referrerStats,
+        referralVolume,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         clock: SYSVAR_CLOCK_PUBKEY,
@@ -297,6 +447,7 @@ export class RigItClient {
         protocolConfig,
         blockState,
         explorationState,
+        operatorBond: await this.getOperatorBondKey(blockId),
         operator: this.provider.wallet.publicKey,
         clock: SYSVAR_CLOCK_PUBKEY,
       })
@@ -320,8 +471,14 @@ export class RigItClient {
         protocolConfig,
         blockState,
         explorationState,
+        operatorBond: await this.getOperatorBondKey(blockId),
+        blockVault: getBlockVaultPDA(blockId, this.programId)[0],
+        blockVaultAuthority: getBlockVaultAuthorityPDA(blockId, this.programId)[0],
+        jackpotVault: await this.getJackpotVaultKey(blockId),
+        buybackSchedule: getBuybackSchedulePDA(explorationState, this.programId)[0],
         operator: this.provider.wallet.publicKey,
         slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        tokenProgram: TOKEN_PROGRAM_ID,
         clock: SYSVAR_CLOCK_PUBKEY,
       })
       .rpc();
@@ -329,6 +486,24 @@ export class RigItClient {
     return tx;
   }
 
+  /** Operator bond account to pass to commit/reveal, or null if none was posted */
+  private async getOperatorBondKey(blockId: number): Promise<PublicKey | null> {
+    const [pda] = getOperatorBondPDA(blockId, this.programId);
+    return (await this.getOperatorBond(blockId)) ? pda : null;
+  }
+
+  /** Jackpot vault to pass to reveal, or null if the block has none */
+  private async getJackpotVaultKey(blockId: number): Promise<PublicKey | null> {
+    const [pda] = getJackpotVaultPDA(blockId, this.programId);
+    return (await this.provider.connection.getAccountInfo(pda)) ? pda : null;
+  }
+
+  /** Deposit-fee treasury to pass to set_block_params, or null if the block has none */
+  private async getFeeVaultKey(blockId: number): Promise<PublicKey | null> {
+    const [pda] = getTreasuryVaultPDA('deposit_fees', blockId, this.programId);
+    return (await this.provider.connection.getAccountInfo(pda)) ? pda : null;
+  }
+
   async refundLoser(depositReceiptKey: PublicKey): Promise<string> {
     const receipt = await this.program.account.depositReceipt.fetch(depositReceiptKey);
     const [blockVault] = getBlockVaultPDA(0, this.programId); // Need to derive from exploration
@@ -401,6 +576,191 @@ export class RigItClient {
     return tx;
   }
 
+  /** Register the wallet as a referrer; `code` is 1-16 chars of [a-z0-9_-] */
+  async registerReferrer(code: string): Promise<string> {
+    const owner = this.provider.wallet.publicKey;
+    const codeBytes = Buffer.alloc(16);
+    codeBytes.write(code, 'utf8');
+    const [protocolConfig] = getProtocolConfigPDA(this.programId);
+    const [referrer] = getReferrerPDA(owner, this.programId);
+    const [referralCode] = getReferralCodePDA(codeBytes, this.programId);
+    const [userStats] = getUserStatsPDA(owner, this.programId);
+
+    const tx = await this.program.methods
+      .registerReferrer({ code: Array.from(codeBytes) })
+      .accounts({
+        protocolConfig,
+        referrer,
+        referralCode,
+        userStats,
+        owner,
+        systemProgram: SystemProgram.programId,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /** Claim the wallet's referral reward for the volume it referred into an exploration */
+  async claimReferralReward(explorationKey: PublicKey): Promise<string> {
+    const owner = this.provider.wallet.publicKey;
+    const exploration = await this.program.account.explorationState.fetch(explorationKey);
+    const blockId = (exploration as any).blockId;
+
+    const block = await this.getBlockState(blockId);
+    if (!block) throw new Error('Block not found');
+
+    const [trackState] = getTrackStatePDA(blockId, (exploration as any).trackId, this.programId);
+    const [referrer] = getReferrerPDA(owner, this.programId);
+    const [referrerStats] = getUserStatsPDA(owner, this.programId);
+    const [referralVolume] = getReferralVolumePDA(explorationKey, owner, this.programId);
+    const [blockVault] = getBlockVaultPDA(blockId, this.programId);
+    const [blockVaultAuthority] = getBlockVaultAuthorityPDA(blockId, this.programId);
+    const ownerTokenAccount = await getAssociatedTokenAddress(block.assetMint, owner);
+
+    const tx = await this.program.methods
+      .claimReferralReward()
+      .accounts({
+        explorationState: explorationKey,
+        trackState,
+        referrer,
+        referrerStats,
+        referralVolume,
+        owner,
+        ownerTokenAccount,
+        blockVault,
+        blockVaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  async setBlockParams(blockId: number, args: SetBlockParamsArgs): Promise<string> {
+    const [protocolConfig] = getProtocolConfigPDA(this.programId);
+    const [blockState] = getBlockStatePDA(blockId, this.programId);
+
+    const tx = await this.program.methods
+      .setBlockParams(args)
+      .accounts({
+        protocolConfig,
+        blockState,
+        jackpotVault: await this.getJackpotVaultKey(blockId),
+        treasuryDepositFees: await this.getFeeVaultKey(blockId),
+        admin: this.provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /** Withdraw from a block's team-ops, ecosystem or deposit-fee treasury into `destination` (admin only) */
+  async withdrawTreasury(
+    blockId: number,
+    args: WithdrawTreasuryArgs,
+    destination: PublicKey
+  ): Promise<string> {
+    const [protocolConfig] = getProtocolConfigPDA(this.programId);
+    const [blockState] = getBlockStatePDA(blockId, this.programId);
+    const bucket =
+      'teamOps' in args.bucket ? 'team_ops' : 'ecosystem' in args.bucket ? 'ecosystem' : 'deposit_fees';
+
+    const tx = await this.program.methods
+      .withdrawTreasury(args)
+      .accounts({
+        protocolConfig,
+        blockState,
+        treasury: getTreasuryVaultPDA(bucket, blockId, this.programId)[0],
+        treasuryAuthority: getTreasuryAuthorityPDA(blockId, this.programId)[0],
+        destination,
+        admin: this.provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Swap buyback-burn treasury funds for $RIG through the whitelisted AMM and
+   * burn the proceeds. `poolAccounts` are the adapter's pool-specific accounts.
+   * Valid from the tranche's scheduled start time until 24h after the window closes.
+   */
+  async executeBuybackBurn(
+    blockId: number,
+    explorationKey: PublicKey,
+    args: ExecuteBuybackBurnArgs,
+    poolAccounts: AccountMeta[]
+  ): Promise<string> {
+    const [protocolConfig] = getProtocolConfigPDA(this.programId);
+    const config = await this.getProtocolConfig();
+    if (!config) throw new Error('Protocol not initialized');
+
+    const tx = await this.program.methods
+      .executeBuybackBurn(args)
+      .accounts({
+        protocolConfig,
+        blockState: getBlockStatePDA(blockId, this.programId)[0],
+        buybackSchedule: getBuybackSchedulePDA(explorationKey, this.programId)[0],
+        treasuryBuybackBurn: getTreasuryVaultPDA('buyback_burn', blockId, this.programId)[0],
+        treasuryAuthority: getTreasuryAuthorityPDA(blockId, this.programId)[0],
+        rigBuybackVault: getRigBuybackVaultPDA(blockId, this.programId)[0],
+        rigTokenMint: config.rigTokenMint,
+        ammProgram: config.ammProgram,
+        operator: this.provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .remainingAccounts(poolAccounts)
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Swap half of a schedule tranche's LP budget for $RIG, add liquidity with
+   * the other half and lock the LP tokens permanently. `poolAccounts` holds
+   * the swap's pool accounts followed by add-liquidity's.
+   */
+  async executeBuybackLp(
+    blockId: number,
+    explorationKey: PublicKey,
+    args: ExecuteBuybackLpArgs,
+    lpMint: PublicKey,
+    poolAccounts: AccountMeta[]
+  ): Promise<string> {
+    const [protocolConfig] = getProtocolConfigPDA(this.programId);
+    const config = await this.getProtocolConfig();
+    if (!config) throw new Error('Protocol not initialized');
+
+    const tx = await this.program.methods
+      .executeBuybackLp(args)
+      .accounts({
+        protocolConfig,
+        blockState: getBlockStatePDA(blockId, this.programId)[0],
+        buybackSchedule: getBuybackSchedulePDA(explorationKey, this.programId)[0],
+        treasuryBuybackLp: getTreasuryVaultPDA('buyback_lp', blockId, this.programId)[0],
+        treasuryAuthority: getTreasuryAuthorityPDA(blockId, this.programId)[0],
+        rigBuybackVault: getRigBuybackVaultPDA(blockId, this.programId)[0],
+        rigTokenMint: config.rigTokenMint,
+        lpLock: getLpLockPDA(blockId, this.programId)[0],
+        lpLockVault: getLpLockVaultPDA(blockId, this.programId)[0],
+        lpMint,
+        ammProgram: config.ammProgram,
+        operator: this.provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        clock: SYSVAR_CLOCK_PUBKEY,
+      })
+      .remainingAccounts(poolAccounts)
+      .rpc();
+
+    return tx;
+  }
+
   async emergencyPause(pause: boolean): Promise<string> {
     const [protocolConfig] = getProtocolConfigPDA(this.programId);
     
@@ -4,3 +4,4 @@ export * from './pdas';
 export * from './instructions';
 export * from './utils/tickets';
 export * from './utils/timing';
+export * from './utils/commit';
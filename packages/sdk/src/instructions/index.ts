@@ -16,6 +16,7 @@ import {
   getBlockVaultAuthorityPDA,
   PROGRAM_ID,
 } from '../pdas';
+import { RIGS_PER_EXPLORATION } from '../types';
 
 // For MVP, instruction builders would be implemented here
 // In practice, you'd use the Anchor-generated instruction builders
@@ -64,10 +65,11 @@ export function deriveDepositAccounts(
 
 export function deriveAllRigPDAs(
   explorationKey: PublicKey,
+  rigCount: number = RIGS_PER_EXPLORATION,
   programId: PublicKey = PROGRAM_ID
 ): PublicKey[] {
   const rigs: PublicKey[] = [];
-  for (let i = 0; i < 36; i++) {
+  for (let i = 0; i < rigCount; i++) {
     const [rigPda] = getRigStatePDA(explorationKey, i, programId);
     rigs.push(rigPda);
   }
@@ -7,13 +7,26 @@ export const PROGRAM_ID = new PublicKey('RiG1tPRogRamXXXXXXXXXXXXXXXXXXXXXXXXXXX
 export const SEEDS = {
   PROTOCOL: Buffer.from('protocol'),
   BLOCK: Buffer.from('block'),
+  TRACK: Buffer.from('track'),
   EXPLORATION: Buffer.from('exploration'),
   RIG: Buffer.from('rig'),
   DEPOSIT: Buffer.from('deposit'),
   BLOCK_VAULT: Buffer.from('block_vault'),
   BLOCK_VAULT_AUTHORITY: Buffer.from('block_vault_authority'),
   TREASURY: Buffer.from('treasury'),
+  TREASURY_AUTHORITY: Buffer.from('treasury_authority'),
+  RIG_BUYBACK: Buffer.from('rig_buyback'),
+  LP_LOCK: Buffer.from('lp_lock'),
+  LP_LOCK_VAULT: Buffer.from('lp_lock_vault'),
+  BUYBACK_SCHEDULE: Buffer.from('buyback_schedule'),
+  JACKPOT_VAULT: Buffer.from('jackpot_vault'),
   USER_STATS: Buffer.from('user_stats'),
+  REFERRER: Buffer.from('referrer'),
+  REFERRAL_CODE: Buffer.from('referral_code'),
+  REFERRAL_VOLUME: Buffer.from('referral_volume'),
+  OPERATOR_BOND: Buffer.from('operator_bond'),
+  OPERATOR_BOND_VAULT: Buffer.from('operator_bond_vault'),
+  DRAW_RECORD: Buffer.from('draw_record'),
 };
 
 export function getProtocolConfigPDA(programId: PublicKey = PROGRAM_ID): [PublicKey, number] {
@@ -33,17 +46,29 @@ export function getBlockStatePDA(
   );
 }
 
+export function getTrackStatePDA(
+  blockId: number,
+  trackId: number = 0,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.TRACK, Buffer.from([blockId]), Buffer.from([trackId])],
+    programId
+  );
+}
+
 export function getExplorationStatePDA(
   blockId: number,
   explorationIndex: BN | number,
-  programId: PublicKey = PROGRAM_ID
+  programId: PublicKey = PROGRAM_ID,
+  trackId: number = 0
 ): [PublicKey, number] {
   const indexBuffer = Buffer.alloc(8);
   const idx = typeof explorationIndex === 'number' ? new BN(explorationIndex) : explorationIndex;
   indexBuffer.writeBigUInt64LE(BigInt(idx.toString()));
   
   return PublicKey.findProgramAddressSync(
-    [SEEDS.EXPLORATION, Buffer.from([blockId]), indexBuffer],
+    [SEEDS.EXPLORATION, Buffer.from([blockId]), Buffer.from([trackId]), indexBuffer],
     programId
   );
 }
@@ -95,12 +120,114 @@ export function getBlockVaultAuthorityPDA(
   );
 }
 
+export function getOperatorBondPDA(
+  blockId: number,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.OPERATOR_BOND, Buffer.from([blockId])],
+    programId
+  );
+}
+
+export function getOperatorBondVaultPDA(
+  blockId: number,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.OPERATOR_BOND_VAULT, Buffer.from([blockId])],
+    programId
+  );
+}
+
+export function getDrawRecordPDA(
+  explorationState: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.DRAW_RECORD, explorationState.toBuffer()],
+    programId
+  );
+}
+
+export type TreasuryBucket =
+  | 'buyback_burn'
+  | 'buyback_lp'
+  | 'team_ops'
+  | 'ecosystem'
+  | 'deposit_fees';
+
+/** Per-block treasury vault for a bucket, holding the block's asset */
 export function getTreasuryVaultPDA(
-  treasuryType: 'buyback_burn' | 'buyback_lp' | 'team_ops' | 'ecosystem',
+  treasuryType: TreasuryBucket,
+  blockId: number,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.TREASURY, Buffer.from(treasuryType), Buffer.from([blockId])],
+    programId
+  );
+}
+
+export function getTreasuryAuthorityPDA(
+  blockId: number,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.TREASURY_AUTHORITY, Buffer.from([blockId])],
+    programId
+  );
+}
+
+/** Per-block $RIG account that receives buybacks before they are burned */
+export function getRigBuybackVaultPDA(
+  blockId: number,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.RIG_BUYBACK, Buffer.from([blockId])],
+    programId
+  );
+}
+
+export function getLpLockPDA(
+  blockId: number,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.LP_LOCK, Buffer.from([blockId])],
+    programId
+  );
+}
+
+/** LP vault owned by the lock PDA; nothing can withdraw from it */
+export function getLpLockVaultPDA(
+  blockId: number,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.LP_LOCK_VAULT, Buffer.from([blockId])],
+    programId
+  );
+}
+
+export function getBuybackSchedulePDA(
+  explorationKey: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.BUYBACK_SCHEDULE, explorationKey.toBuffer()],
+    programId
+  );
+}
+
+/** Per-block jackpot vault, owned by the block vault authority */
+export function getJackpotVaultPDA(
+  blockId: number,
   programId: PublicKey = PROGRAM_ID
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [SEEDS.TREASURY, Buffer.from(treasuryType)],
+    [SEEDS.JACKPOT_VAULT, Buffer.from([blockId])],
     programId
   );
 }
@@ -114,3 +241,36 @@ export function getUserStatsPDA(
     programId
   );
 }
+
+export function getReferrerPDA(
+  ownerKey: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.REFERRER, ownerKey.toBuffer()],
+    programId
+  );
+}
+
+/** Reserves a referral code; `code` is the zero-padded 16-byte code */
+export function getReferralCodePDA(
+  code: Uint8Array,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.REFERRAL_CODE, Buffer.from(code)],
+    programId
+  );
+}
+
+/** Volume a referrer brought into one exploration */
+export function getReferralVolumePDA(
+  explorationKey: PublicKey,
+  referrerKey: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEEDS.REFERRAL_VOLUME, explorationKey.toBuffer(), referrerKey.toBuffer()],
+    programId
+  );
+}
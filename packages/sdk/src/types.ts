@@ -18,7 +18,13 @@ export interface ProtocolConfig {
   cooldownDurationSecs: number;
   antiSnipeWindowSecs: number;
   commitRevealTimeoutSlots: BN;
+  /** Bond locked per commit, in whole units of the block asset (0 = disabled) */
+  operatorBondAmount: BN;
   bump: number;
+  /** Whitelisted AMM adapter for treasury buybacks (default key = disabled) */
+  ammProgram: PublicKey;
+  /** Share of each exploration's team-ops allocation paid to referrers, in bps */
+  referralBps: number;
 }
 
 export interface BlockState {
@@ -26,11 +32,59 @@ export interface BlockState {
   assetMint: PublicKey;
   assetDecimals: number;
   minThreshold: BN;
-  currentExplorationIndex: BN;
+  rigCount: number;
+  trackCount: number;
   paused: boolean;
   totalVolume: BN;
   totalExplorationsCompleted: BN;
   bump: number;
+  /** Winner pool split across 1st/2nd/3rd drawn rigs in bps ([0, 0, 0] = single winner) */
+  prizeTierBps: number[];
+  /** Share of the ecosystem allocation paid as consolation bonuses (bps, 0 = off) */
+  consolationBps: number;
+  consolationWinners: number;
+  /** Where rollover goes at settlement, snapshotted by each exploration at start */
+  rolloverPolicy: RolloverPolicy;
+  /** Slice of each exploration's R moved to the jackpot vault (bps, 0 = off) */
+  jackpotBps: number;
+  /** Chance per exploration that the whole jackpot goes to the winning rig (bps) */
+  jackpotHitBps: number;
+  jackpotHits: BN;
+  totalJackpotPaid: BN;
+  /** Fee taken from each deposit into the deposit-fee treasury (bps, 0 = off) */
+  depositFeeBps: number;
+  /** $RIG balance from which holders get the fee discount (0 = no discount) */
+  feeDiscountRigBalance: BN;
+  /** Share of the fee waived for qualifying holders (bps, 10000 = exempt) */
+  feeDiscountBps: number;
+  /** Share of a losing deposit refunded in bps (0 = default 5000) */
+  loserRefundBps: number;
+}
+
+/** Anchor encoding of the on-chain RolloverPolicy enum */
+export type RolloverPolicy =
+  | { allBuckets: Record<string, never> }
+  | { winnerPool: Record<string, never> }
+  | { jackpot: Record<string, never> };
+
+/** Rollover policy byte of a zero-copy ExplorationState, in enum order */
+export const ROLLOVER_POLICY_BY_CODE = ['allBuckets', 'winnerPool', 'jackpot'] as const;
+
+export interface TrackState {
+  blockId: number;
+  trackId: number;
+  currentExplorationIndex: BN;
+  scheduleAnchorTs: BN;
+  scheduleAnchorIndex: BN;
+  activeDurationSecs: number;
+  cooldownDurationSecs: number;
+  antiSnipeWindowSecs: number;
+  outstandingLiabilities: BN;
+  totalVolume: BN;
+  totalExplorationsCompleted: BN;
+  /** Deposit fees collected on this track */
+  totalFeesCollected: BN;
+  bump: number;
 }
 
 export enum ExplorationStatus {
@@ -41,30 +95,167 @@ export enum ExplorationStatus {
   RolledOver = 'rolledOver',
 }
 
+/** On-chain status byte of a zero-copy ExplorationState, in enum order */
+export const EXPLORATION_STATUS_BY_CODE: ExplorationStatus[] = [
+  ExplorationStatus.Pending,
+  ExplorationStatus.Active,
+  ExplorationStatus.Finalizing,
+  ExplorationStatus.Settled,
+  ExplorationStatus.RolledOver,
+];
+
+/** `winningRig` value while an exploration is unsettled */
+export const NO_WINNING_RIG = 255;
+
+/** Bits of ExplorationState.flags */
+export const ExplorationFlags = {
+  RandomRevealed: 1 << 0,
+  LoserRefundsProcessed: 1 << 1,
+  WinnerDistributionProcessed: 1 << 2,
+  BuybackAllocated: 1 << 3,
+  CarryForwardDone: 1 << 4,
+  DustSwept: 1 << 5,
+} as const;
+
+/** Byte length of the fixed part of an exploration account (discriminator + fields) */
+export const EXPLORATION_FIXED_LEN = 352;
+
+/** Deposit and ticket totals of one rig */
+export interface RigTotals {
+  deposits: BN;
+  tickets: BN;
+}
+
+/**
+ * Raw zero-copy ExplorationState account. The account data continues with
+ * `rigCount` rig totals (u64 deposits + u128 tickets, little-endian) after
+ * `EXPLORATION_FIXED_LEN` bytes; read them with `decodeRigTotals`.
+ */
 export interface ExplorationState {
+  version: number;
+  bump: number;
   blockId: number;
+  trackId: number;
+  status: number;
+  rigCount: number;
+  winningRig: number;
+  flags: number;
   explorationIndex: BN;
-  status: ExplorationStatus;
   startSlot: BN;
   startTs: BN;
   activeEndTs: BN;
   cooldownEndTs: BN;
   totalDeposits: BN;
-  rigDeposits: BN[];
-  rigTickets: BN[];
   rolloverAmount: BN;
   commitSlot: BN;
-  commitHash: Uint8Array;
   revealDeadlineSlot: BN;
-  revealedRandom: Uint8Array | null;
-  winningRig: number | null;
-  loserRefundsProcessed: boolean;
-  winnerDistributionProcessed: boolean;
-  buybackAllocated: boolean;
-  carryForwardDone: boolean;
+  /** Slot whose hash seeds the timeout fallback (0 = the reveal deadline) */
+  fallbackSlot: BN;
   totalWinnerDeposits: BN;
   totalLoserDeposits: BN;
   remainingPool: BN;
+  commitHash: number[];
+  revealedRandom: number[];
+  entropyAccumulator: number[];
+  /** Hash of rig deposits, tickets and rollover taken at commit */
+  commitSnapshotHash: number[];
+  operatorBondAtStake: BN;
+  operatorSlashAmount: BN;
+  consolationPool: BN;
+  /** Paid out of the vault for this exploration (claims, refunds, buckets, dust) */
+  totalPaid: BN;
+  /** Rounding dust swept to the ecosystem treasury */
+  dustSwept: BN;
+  /** Slice of R moved to the block jackpot at settlement */
+  jackpotContribution: BN;
+  /** Jackpot paid on top of the first prize (0 unless the jackpot hit) */
+  jackpotWon: BN;
+  /** Deposits made through a referrer, counted in the draw */
+  referredVolume: BN;
+  /** Team-ops slice kept in the vault for referrer rewards */
+  referralPool: BN;
+  /** Referral rewards claimed out of `referralPool` */
+  referralPaid: BN;
+  entropyContributions: number;
+  receiptCount: number;
+  receiptsClosed: number;
+  /** Anti-snipe window before `activeEndTs`, snapshotted from the track at start */
+  antiSnipeWindowSecs: number;
+  /** Commit scheme of `commitHash` (0 before commit) */
+  commitScheme: number;
+  /** Rig drawn per prize tier, NO_WINNING_RIG for undrawn tiers */
+  prizeRigs: number[];
+  /** Winner pool split per prize tier in bps, snapshotted at start */
+  prizeTierBps: number[];
+  consolationWinners: number;
+  /** RolloverPolicy byte snapshotted at start (see ROLLOVER_POLICY_BY_CODE) */
+  rolloverPolicy: number;
+  /** Jackpot slice and hit chance in bps, snapshotted at start */
+  jackpotBps: number;
+  jackpotHitBps: number;
+  /** Loser refund share in bps, snapshotted at start */
+  loserRefundBps: number;
+}
+
+export interface OperatorBond {
+  blockId: number;
+  operator: PublicKey;
+  amount: BN;
+  lockedAmount: BN;
+  totalSlashed: BN;
+  bump: number;
+}
+
+/** Permanent per-block lock of LP tokens bought with the buyback-LP bucket */
+export interface LpLock {
+  blockId: number;
+  lpMint: PublicKey;
+  vault: PublicKey;
+  totalLpLocked: BN;
+  totalAssetPaired: BN;
+  totalRigPaired: BN;
+  lockCount: number;
+  bump: number;
+}
+
+/** Buyback window and tranches committed at allocation from the revealed random value */
+export interface BuybackSchedule {
+  exploration: PublicKey;
+  blockId: number;
+  burnBudget: BN;
+  lpBudget: BN;
+  windowStartTs: BN;
+  windowEndTs: BN;
+  /** Each tranche may execute from its start until the window closes */
+  trancheStartTs: BN[];
+  trancheBps: number[];
+  /** Bitmasks of executed tranches */
+  burnExecuted: number;
+  lpExecuted: number;
+  bump: number;
+}
+
+/** Audit record of every input to an exploration's draw */
+export interface DrawRecord {
+  exploration: PublicKey;
+  blockId: number;
+  trackId: number;
+  explorationIndex: BN;
+  algorithmVersion: number;
+  usedFallback: boolean;
+  operatorSecret: number[];
+  commitHash: number[];
+  commitScheme: number;
+  targetSlot: BN;
+  fallbackSlot: BN;
+  slotHash: number[];
+  entropyAccumulator: number[];
+  randomValue: number[];
+  ticketSnapshotHash: number[];
+  rigCount: number;
+  winningRig: number;
+  prizeRigs: number[];
+  drawnAt: BN;
   bump: number;
 }
 
@@ -90,6 +281,18 @@ export interface DepositReceipt {
   refundClaimed: boolean;
   winningsClaimed: boolean;
   bump: number;
+  /** Position of the first ticket within the rig (consolation draw) */
+  ticketOffset: BN;
+  /** Owner of the referrer the deposit was made through */
+  referrer: PublicKey | null;
+}
+
+export interface Referrer {
+  owner: PublicKey;
+  /** Referral code, zero-padded to 16 bytes */
+  code: number[];
+  registeredAt: BN;
+  bump: number;
 }
 
 // === Instruction Args ===
@@ -102,12 +305,37 @@ export interface InitProtocolArgs {
 export interface InitBlockArgs {
   blockId: number;
   minThreshold: BN;
+  rigCount: number | null;
+  /** Prize tier split in bps, e.g. [7000, 2000, 1000] (defaults to a single winner) */
+  prizeTierBps: number[] | null;
+}
+
+export interface SetBlockParamsArgs {
+  minThreshold: BN | null;
+  prizeTierBps: number[] | null;
+  consolationBps: number | null;
+  consolationWinners: number | null;
+  rolloverPolicy: RolloverPolicy | null;
+  /** Slice of R funding the block jackpot in bps (max 1000) */
+  jackpotBps: number | null;
+  /** Chance per exploration that the jackpot hits, in bps */
+  jackpotHitBps: number | null;
+  /** Fee taken from each deposit in bps (max 500) */
+  depositFeeBps: number | null;
+  feeDiscountRigBalance: BN | null;
+  feeDiscountBps: number | null;
+  /** Share of a losing deposit refunded in bps (1-10000) */
+  loserRefundBps: number | null;
 }
 
 export interface DepositToRigArgs {
   rigIndex: number;
   amount: BN;
   depositNonce: BN;
+  /** Optional 32-byte entropy commitment mixed into the draw */
+  entropyCommitment: number[] | null;
+  /** Owner of a registered referrer the deposit is made through */
+  referrer: PublicKey | null;
 }
 
 export interface CommitRandomnessArgs {
@@ -124,8 +352,38 @@ export interface SetParamsArgs {
   cooldownDurationSecs?: number;
   antiSnipeWindowSecs?: number;
   commitRevealTimeoutSlots?: BN;
+  operatorBondAmount?: BN;
   newOperator?: PublicKey;
   newEmergencyAdmin?: PublicKey;
+  ammProgram?: PublicKey;
+  referralBps?: number;
+}
+
+export interface ExecuteBuybackLpArgs {
+  /** Schedule tranche; its share of the LP budget is half swapped, half paired */
+  tranche: number;
+  minRigOut: BN;
+  minLpOut: BN;
+  /** Leading pool accounts that belong to the swap; the rest go to add-liquidity */
+  swapAccountCount: number;
+}
+
+/** Anchor encoding of the on-chain WithdrawableBucket enum */
+export type WithdrawableBucket =
+  | { teamOps: Record<string, never> }
+  | { ecosystem: Record<string, never> }
+  | { depositFees: Record<string, never> };
+
+export interface WithdrawTreasuryArgs {
+  bucket: WithdrawableBucket;
+  amount: BN;
+}
+
+export interface ExecuteBuybackBurnArgs {
+  /** Schedule tranche; sells its share of the burn budget */
+  tranche: number;
+  /** Minimum $RIG the swap must return */
+  minAmountOut: BN;
 }
 
 // === Event Types ===
@@ -150,6 +408,9 @@ export interface DepositMadeEvent {
   effectiveTickets: BN;
   isAntiSniped: boolean;
   depositReceipt: PublicKey;
+  referrer: PublicKey | null;
+  /** Deposit fee taken out of the transferred amount; `amount` entered the draw */
+  fee: BN;
 }
 
 export interface ExplorationSettledEvent {
@@ -161,10 +422,49 @@ export interface ExplorationSettledEvent {
   winnerDeposits: BN;
   loserDeposits: BN;
   remainingPool: BN;
+  /** Rollover carried into the exploration, not included in winnerDeposits */
+  rolloverAmount: BN;
+  /** RolloverPolicy byte the rollover was settled under */
+  rolloverPolicy: number;
+  rolloverToJackpot: BN;
+  /** Share of each losing deposit refunded; the rest of L stays in R */
+  loserRefundBps: number;
+}
+
+export interface JackpotFundedEvent {
+  blockId: number;
+  explorationKey: PublicKey;
+  rolloverAmount: BN;
+  poolContribution: BN;
+  jackpotBalance: BN;
+}
+
+export interface JackpotHitEvent {
+  blockId: number;
+  explorationKey: PublicKey;
+  winningRig: number;
+  amount: BN;
+}
+
+export interface ReferrerRegisteredEvent {
+  owner: PublicKey;
+  referrer: PublicKey;
+  code: number[];
+}
+
+export interface ReferralRewardClaimedEvent {
+  referrer: PublicKey;
+  explorationKey: PublicKey;
+  referredAmount: BN;
+  reward: BN;
 }
 
 // === Constants ===
 
+/** Maximum referral code length; codes use lowercase letters, digits, '-' and '_' */
+export const REFERRAL_CODE_LEN = 16;
+
+/** Default rig count; each block configures its own (2-64) */
 export const RIGS_PER_EXPLORATION = 36;
 
 export const BLOCK_IDS = {
@@ -0,0 +1,47 @@
+/**
+ * Commit-reveal helpers
+ * Must match utils::randomness on-chain
+ */
+
+import { PublicKey } from '@solana/web3.js';
+import BN from 'bn.js';
+
+import { PROGRAM_ID } from '../pdas';
+
+export const COMMIT_SCHEME_V1 = 1;
+export const COMMIT_SCHEME_V2 = 2;
+export const COMMIT_DOMAIN_V2 = Buffer.from('rig-it:commit:v2');
+
+/** Exploration identity a v2 commitment is bound to */
+export interface CommitContext {
+  blockId: number;
+  trackId: number;
+  explorationIndex: BN | bigint | number;
+  programId?: PublicKey;
+}
+
+/**
+ * Preimage of a v2 commit hash:
+ * domain || program_id || block_id || track_id || exploration_index (LE) || target_slot (LE) || secret
+ *
+ * Hash it with SHA-256 to get the `commitHash` passed to `commit_randomness`.
+ */
+export function buildCommitPreimage(
+  secret: Uint8Array,
+  targetSlot: BN | bigint | number,
+  context: CommitContext
+): Buffer {
+  const indexBuffer = Buffer.alloc(8);
+  indexBuffer.writeBigUInt64LE(BigInt(context.explorationIndex.toString()));
+  const slotBuffer = Buffer.alloc(8);
+  slotBuffer.writeBigUInt64LE(BigInt(targetSlot.toString()));
+
+  return Buffer.concat([
+    COMMIT_DOMAIN_V2,
+    (context.programId ?? PROGRAM_ID).toBuffer(),
+    Buffer.from([context.blockId, context.trackId]),
+    indexBuffer,
+    slotBuffer,
+    Buffer.from(secret),
+  ]);
+}
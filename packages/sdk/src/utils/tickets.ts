@@ -3,6 +3,10 @@
  * Must match on-chain logic exactly
  */
 
+import BN from 'bn.js';
+
+import { EXPLORATION_FIXED_LEN, RigTotals } from '../types';
+
 const SCALE = 1_000_000n;
 const RIG_MULTIPLIER_BASE = 1_000_000_000n; // 1 RIG with 9 decimals
 const MAX_MULTIPLIER_BONUS = 2_000_000n;
@@ -95,10 +99,14 @@ export function calculateWinnerShare(
 }
 
 /**
- * Calculate loser refund (always 50% of deposit)
+ * Calculate loser refund
+ * @param loserRefundBps The exploration's loser refund share (default 5000 = 50%)
  */
-export function calculateLoserRefund(depositAmount: number | bigint): bigint {
-  return BigInt(depositAmount) / 2n;
+export function calculateLoserRefund(
+  depositAmount: number | bigint,
+  loserRefundBps: number = 5000
+): bigint {
+  return (BigInt(depositAmount) * BigInt(loserRefundBps)) / 10000n;
 }
 
 /**
@@ -123,3 +131,18 @@ export function estimateTicketWeight(
     effectiveTickets,
   };
 }
+
+/**
+ * Decode the per-rig totals stored after the fixed part of an exploration account
+ */
+export function decodeRigTotals(data: Buffer, rigCount: number): RigTotals[] {
+  const rigs: RigTotals[] = [];
+  for (let i = 0; i < rigCount; i++) {
+    const offset = EXPLORATION_FIXED_LEN + i * 24;
+    rigs.push({
+      deposits: new BN(data.subarray(offset, offset + 8), 'le'),
+      tickets: new BN(data.subarray(offset + 8, offset + 24), 'le'),
+    });
+  }
+  return rigs;
+}
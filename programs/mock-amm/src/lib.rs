@@ -0,0 +1,387 @@
+//! Minimal constant-product AMM implementing the Rig It adapter interface.
+//!
+//! For local testing only. `swap` takes the adapter's fixed accounts (source,
+//! destination, authority, token program) followed by the pool and its two
+//! vaults; `add_liquidity` takes (source a, source b, LP destination,
+//! authority, token program) followed by the pool, its vaults and LP mint.
+
+#![allow(deprecated)]
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+declare_id!("EWQUAXkNswPUc5L5WkaR72cbmJMLVvfm24W8ZAXxhRu");
+
+#[program]
+pub mod mock_amm {
+    use super::*;
+
+    /// Create a pool for a mint pair
+    pub fn initialize_pool(ctx: Context<InitializePool>, fee_bps: u16) -> Result<()> {
+        require!(fee_bps < 10000, MockAmmError::InvalidFee);
+
+        let pool = &mut ctx.accounts.pool;
+        pool.mint_a = ctx.accounts.mint_a.key();
+        pool.mint_b = ctx.accounts.mint_b.key();
+        pool.vault_a = ctx.accounts.vault_a.key();
+        pool.vault_b = ctx.accounts.vault_b.key();
+        pool.lp_mint = ctx.accounts.lp_mint.key();
+        pool.fee_bps = fee_bps;
+        pool.bump = ctx.bumps.pool;
+
+        msg!("Pool initialized: {} / {}", pool.mint_a, pool.mint_b);
+        Ok(())
+    }
+
+    /// Adapter add-liquidity: deposit up to the offered amounts at the pool
+    /// ratio and mint LP tokens. Sources may be given in either mint order.
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidity>,
+        amount_a: u64,
+        amount_b: u64,
+        min_lp_out: u64,
+    ) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let (source_for_a, source_for_b, max_a, max_b) =
+            if ctx.accounts.source_a.mint == pool.mint_a {
+                (&ctx.accounts.source_a, &ctx.accounts.source_b, amount_a, amount_b)
+            } else {
+                (&ctx.accounts.source_b, &ctx.accounts.source_a, amount_b, amount_a)
+            };
+        require_keys_eq!(source_for_a.mint, pool.mint_a, MockAmmError::MintMismatch);
+        require_keys_eq!(source_for_b.mint, pool.mint_b, MockAmmError::MintMismatch);
+
+        let (take_a, take_b, lp_out) = quote_liquidity(
+            ctx.accounts.vault_a.amount,
+            ctx.accounts.vault_b.amount,
+            ctx.accounts.lp_mint.supply,
+            max_a,
+            max_b,
+        )
+        .ok_or(MockAmmError::InsufficientLiquidity)?;
+        require!(lp_out >= min_lp_out, MockAmmError::SlippageExceeded);
+
+        for (from, to, amount) in [
+            (source_for_a, &ctx.accounts.vault_a, take_a),
+            (source_for_b, &ctx.accounts.vault_b, take_b),
+        ] {
+            let cpi_accounts = Transfer {
+                from: from.to_account_info(),
+                to: to.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            };
+            let cpi_ctx =
+                CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+            token::transfer(cpi_ctx, amount)?;
+        }
+
+        let seeds = &[
+            Pool::SEED,
+            pool.mint_a.as_ref(),
+            pool.mint_b.as_ref(),
+            &[pool.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.lp_mint.to_account_info(),
+            to: ctx.accounts.lp_destination.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::mint_to(cpi_ctx, lp_out)?;
+
+        msg!("Liquidity added: {} / {} for {} LP", take_a, take_b, lp_out);
+        Ok(())
+    }
+
+    /// Adapter swap: sell `amount_in` of the source mint for the other side
+    pub fn swap(ctx: Context<Swap>, amount_in: u64, min_amount_out: u64) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let source_mint = ctx.accounts.user_source.mint;
+        let (vault_in, vault_out) = if source_mint == pool.mint_a {
+            (&ctx.accounts.vault_a, &ctx.accounts.vault_b)
+        } else if source_mint == pool.mint_b {
+            (&ctx.accounts.vault_b, &ctx.accounts.vault_a)
+        } else {
+            return err!(MockAmmError::MintMismatch);
+        };
+        require_keys_eq!(
+            ctx.accounts.user_destination.mint,
+            vault_out.mint,
+            MockAmmError::MintMismatch
+        );
+
+        let amount_out = quote_swap(vault_in.amount, vault_out.amount, amount_in, pool.fee_bps)
+            .ok_or(MockAmmError::InsufficientLiquidity)?;
+        require!(amount_out >= min_amount_out, MockAmmError::SlippageExceeded);
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.user_source.to_account_info(),
+            to: vault_in.to_account_info(),
+            authority: ctx.accounts.user_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, amount_in)?;
+
+        let seeds = &[
+            Pool::SEED,
+            pool.mint_a.as_ref(),
+            pool.mint_b.as_ref(),
+            &[pool.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        let cpi_accounts = Transfer {
+            from: vault_out.to_account_info(),
+            to: ctx.accounts.user_destination.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, amount_out)?;
+
+        msg!("Swapped {} for {}", amount_in, amount_out);
+        Ok(())
+    }
+}
+
+/// Constant-product output for `amount_in` after the fee, or None if the
+/// pool cannot pay anything out
+pub fn quote_swap(reserve_in: u64, reserve_out: u64, amount_in: u64, fee_bps: u16) -> Option<u64> {
+    let amount_in_after_fee = (amount_in as u128) * (10000 - fee_bps as u128) / 10000;
+    let numerator = amount_in_after_fee * reserve_out as u128;
+    let denominator = (reserve_in as u128).checked_add(amount_in_after_fee)?;
+    if denominator == 0 {
+        return None;
+    }
+    let amount_out = (numerator / denominator) as u64;
+    (amount_out > 0).then_some(amount_out)
+}
+
+/// Amounts taken from each side and LP minted for a deposit of up to
+/// `max_a` / `max_b`. The first deposit mints sqrt(a * b); later deposits
+/// mint pro-rata to the scarcer side and take the other side rounded up.
+pub fn quote_liquidity(
+    reserve_a: u64,
+    reserve_b: u64,
+    lp_supply: u64,
+    max_a: u64,
+    max_b: u64,
+) -> Option<(u64, u64, u64)> {
+    let (take_a, take_b, lp_out) = if lp_supply == 0 || reserve_a == 0 || reserve_b == 0 {
+        (max_a, max_b, isqrt(max_a as u128 * max_b as u128) as u64)
+    } else {
+        let supply = lp_supply as u128;
+        let lp_out = core::cmp::min(
+            max_a as u128 * supply / reserve_a as u128,
+            max_b as u128 * supply / reserve_b as u128,
+        );
+        let take_a = (lp_out * reserve_a as u128).div_ceil(supply);
+        let take_b = (lp_out * reserve_b as u128).div_ceil(supply);
+        (take_a as u64, take_b as u64, u64::try_from(lp_out).ok()?)
+    };
+    (lp_out > 0).then_some((take_a, take_b, lp_out))
+}
+
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+#[account]
+#[derive(Default)]
+pub struct Pool {
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+    pub lp_mint: Pubkey,
+    pub fee_bps: u16,
+    pub bump: u8,
+}
+
+impl Pool {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint_a
+        32 + // mint_b
+        32 + // vault_a
+        32 + // vault_b
+        32 + // lp_mint
+        2 +  // fee_bps
+        1;   // bump
+
+    pub const SEED: &'static [u8] = b"pool";
+    pub const VAULT_SEED: &'static [u8] = b"pool_vault";
+    pub const LP_MINT_SEED: &'static [u8] = b"lp_mint";
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = Pool::LEN,
+        seeds = [Pool::SEED, mint_a.key().as_ref(), mint_b.key().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, Pool>,
+
+    pub mint_a: Account<'info, Mint>,
+    pub mint_b: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint_a,
+        token::authority = pool,
+        seeds = [Pool::VAULT_SEED, pool.key().as_ref(), mint_a.key().as_ref()],
+        bump
+    )]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint_b,
+        token::authority = pool,
+        seeds = [Pool::VAULT_SEED, pool.key().as_ref(), mint_b.key().as_ref()],
+        bump
+    )]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 9,
+        mint::authority = pool,
+        seeds = [Pool::LP_MINT_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Account order follows the Rig It adapter interface
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(mut)]
+    pub source_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub source_b: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub lp_destination: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    #[account(has_one = vault_a, has_one = vault_b, has_one = lp_mint)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_b: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+}
+
+/// Account order follows the Rig It adapter interface
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    #[account(mut)]
+    pub user_source: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user_destination: Account<'info, TokenAccount>,
+
+    pub user_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    #[account(has_one = vault_a, has_one = vault_b)]
+    pub pool: Account<'info, Pool>,
+
+    #[account(mut)]
+    pub vault_a: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub vault_b: Account<'info, TokenAccount>,
+}
+
+#[error_code]
+pub enum MockAmmError {
+    #[msg("Fee must be below 10000 bps")]
+    InvalidFee,
+
+    #[msg("Token accounts do not match the pool's mints")]
+    MintMismatch,
+
+    #[msg("Insufficient liquidity")]
+    InsufficientLiquidity,
+
+    #[msg("Slippage exceeded")]
+    SlippageExceeded,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_swap_constant_product() {
+        // 1000 in against 100k/100k with no fee: 100_000 * 1000 / 101_000
+        assert_eq!(quote_swap(100_000, 100_000, 1_000, 0), Some(990));
+        // 30 bps fee applied to the input
+        assert_eq!(quote_swap(100_000, 100_000, 1_000, 30), Some(987));
+        // Output never drains the pool
+        assert!(quote_swap(100, 100, u64::MAX / 2, 0).unwrap() < 100);
+    }
+
+    #[test]
+    fn test_quote_liquidity() {
+        // First deposit mints sqrt(a * b)
+        assert_eq!(quote_liquidity(0, 0, 0, 400, 100), Some((400, 100, 200)));
+        // Later deposits follow the pool ratio; the excess side is left over
+        assert_eq!(
+            quote_liquidity(400, 100, 200, 1_000, 100),
+            Some((400, 100, 200))
+        );
+        // Rounding favours the pool
+        let (take_a, take_b, lp) = quote_liquidity(1_000, 333, 577, 10, 10).unwrap();
+        assert_eq!(lp, 5);
+        assert_eq!((take_a, take_b), (9, 3));
+        assert_eq!(quote_liquidity(1_000, 1_000, 1_000, 0, 10), None);
+    }
+
+    #[test]
+    fn test_quote_swap_empty_pool() {
+        assert_eq!(quote_swap(0, 0, 1_000, 0), None);
+        assert_eq!(quote_swap(100_000, 100_000, 0, 0), None);
+        assert_eq!(quote_swap(100_000, 100_000, 1, 30), None);
+    }
+}
@@ -20,7 +20,7 @@ pub enum RigItError {
     #[msg("Exploration already finalized")]
     ExplorationAlreadyFinalized,
 
-    #[msg("Invalid rig index (must be 0-35)")]
+    #[msg("Invalid rig index (must be below the exploration's rig count)")]
     InvalidRigIndex,
 
     #[msg("Deposit amount too small")]
@@ -103,4 +103,120 @@ pub enum RigItError {
 
     #[msg("Slot hash not available")]
     SlotHashNotAvailable,
+    #[msg("Scheduled exploration window has not opened yet")]
+    ExplorationNotScheduled,
+
+    #[msg("Invalid schedule - active duration must be non-zero and exceed the anti-snipe window")]
+    InvalidSchedule,
+
+    #[msg("Previous exploration account required")]
+    PreviousExplorationRequired,
+
+    #[msg("Previous exploration does not belong to this block sequence")]
+    InvalidPreviousExploration,
+
+    #[msg("Invalid track ID")]
+    InvalidTrackId,
+
+    #[msg("Invalid rig count (must be 2-64)")]
+    InvalidRigCount,
+
+    #[msg("Operator bond is insufficient")]
+    InsufficientOperatorBond,
+
+    #[msg("Operator bond account required")]
+    OperatorBondRequired,
+
+    #[msg("Rig deposits or tickets changed after randomness commit")]
+    TicketSnapshotMismatch,
+
+    #[msg("Invalid prize tiers - must sum to 10000 bps with a non-zero first prize and no gaps")]
+    InvalidPrizeTiers,
+
+    #[msg("Invalid consolation config - share must be at most 10000 bps and at most 16 draws")]
+    InvalidConsolationConfig,
+
+    #[msg("AMM program is not the whitelisted adapter")]
+    AmmNotWhitelisted,
+
+    #[msg("Swap returned less than the minimum amount out")]
+    SlippageExceeded,
+
+    #[msg("Swap amount must be non-zero")]
+    InvalidSwapAmount,
+
+    #[msg("Insufficient treasury balance")]
+    InsufficientTreasuryBalance,
+
+    #[msg("Adapter account split exceeds the accounts provided")]
+    InvalidAdapterAccounts,
+
+    #[msg("LP mint does not match the block's LP lock")]
+    LpMintMismatch,
+
+    #[msg("Invalid buyback tranche")]
+    InvalidBuybackTranche,
+
+    #[msg("Buyback tranche already executed")]
+    BuybackTrancheExecuted,
+
+    #[msg("Buyback tranche has not opened yet")]
+    OutsideBuybackWindow,
+
+    #[msg("Dust already swept")]
+    DustAlreadySwept,
+
+    #[msg("Receipts or bucket allocation still outstanding")]
+    PayoutsOutstanding,
+
+    #[msg("Jackpot vault required by the block's jackpot or rollover settings")]
+    JackpotVaultRequired,
+
+    #[msg("Invalid jackpot configuration")]
+    InvalidJackpotConfig,
+
+    #[msg("Invalid referral configuration")]
+    InvalidReferralConfig,
+
+    #[msg("Invalid referral code")]
+    InvalidReferralCode,
+
+    #[msg("Referrer account does not match the deposit's referrer")]
+    ReferrerMismatch,
+
+    #[msg("Cannot refer your own deposits")]
+    SelfReferral,
+
+    #[msg("Deposit was not made through this referrer")]
+    NotReferred,
+
+    #[msg("Referral reward already claimed")]
+    ReferralAlreadyClaimed,
+
+    #[msg("Referral rewards are available once the buyback budget is allocated")]
+    ReferralRewardsNotReady,
+
+    #[msg("Invalid deposit fee configuration")]
+    InvalidDepositFeeConfig,
+
+    #[msg("Fee vault required while the block charges a deposit fee")]
+    FeeVaultRequired,
+
+    #[msg("Loser refund must be between 1 and 10000 bps")]
+    InvalidLoserRefund,
+
+    #[msg("Fallback slot hash is still available")]
+    FallbackSlotHashAvailable,
+
+    #[msg("Adapter took more than the amount offered")]
+    AdapterOverspent,
+
+    #[msg("Buyback tranche expired after the catch-up period")]
+    BuybackTrancheExpired,
+
+    #[msg("Adapter swapped less than the amount offered")]
+    AdapterPartialFill,
+
+    #[msg("Adapter pool accounts may not include vaults of the treasury authority")]
+    TreasuryAccountNotAllowed,
 }
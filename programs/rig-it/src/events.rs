@@ -3,8 +3,10 @@ use anchor_lang::prelude::*;
 #[event]
 pub struct ExplorationStarted {
     pub block_id: u8,
+    pub track_id: u8,
     pub exploration_index: u64,
     pub exploration_key: Pubkey,
+    pub scheduled_start_ts: i64,
     pub start_ts: i64,
     pub active_end_ts: i64,
     pub cooldown_end_ts: i64,
@@ -15,6 +17,7 @@ pub struct ExplorationStarted {
 pub struct DepositMade {
     pub user: Pubkey,
     pub block_id: u8,
+    pub track_id: u8,
     pub exploration_index: u64,
     pub exploration_key: Pubkey,
     pub rig_index: u8,
@@ -22,6 +25,10 @@ pub struct DepositMade {
     pub effective_tickets: u128,
     pub is_anti_sniped: bool,
     pub deposit_receipt: Pubkey,
+    pub referrer: Option<Pubkey>,
+    /// Deposit fee taken out of the transferred amount; `amount` is the rest,
+    /// which entered the draw
+    pub fee: u64,
 }
 
 #[event]
@@ -30,23 +37,39 @@ pub struct RandomnessCommitted {
     pub commit_slot: u64,
     pub reveal_deadline_slot: u64,
     pub commit_hash: [u8; 32],
+    pub commit_scheme: u8,
+    pub commit_snapshot_hash: [u8; 32],
+    pub entropy_accumulator: [u8; 32],
+    pub entropy_contributions: u32,
 }
 
 #[event]
 pub struct ExplorationSettled {
     pub block_id: u8,
+    pub track_id: u8,
     pub exploration_index: u64,
     pub exploration_key: Pubkey,
     pub winning_rig: u8,
+    /// Rig drawn for each prize tier (NO_WINNING_RIG for undrawn tiers)
+    pub prize_rigs: [u8; 3],
     pub total_pool: u64,
     pub winner_deposits: u64,
     pub loser_deposits: u64,
     pub remaining_pool: u64,
+    /// Rollover carried into this exploration, reported apart from W
+    pub rollover_amount: u64,
+    /// RolloverPolicy the rollover was settled under
+    pub rollover_policy: u8,
+    /// Part of the rollover moved to the block's jackpot vault
+    pub rollover_to_jackpot: u64,
+    /// Share of each losing deposit refunded; the rest of L stays in R
+    pub loser_refund_bps: u16,
 }
 
 #[event]
 pub struct ExplorationRolledOver {
     pub block_id: u8,
+    pub track_id: u8,
     pub exploration_index: u64,
     pub exploration_key: Pubkey,
     pub total_deposits: u64,
@@ -60,6 +83,18 @@ pub struct LoserRefunded {
     pub deposit_receipt: Pubkey,
     pub original_amount: u64,
     pub refund_amount: u64,
+    /// Consolation bonus included in `refund_amount`
+    pub consolation_bonus: u64,
+    /// Share of the deposit refunded, in bps
+    pub loser_refund_bps: u16,
+}
+
+#[event]
+pub struct ConsolationFunded {
+    pub exploration_key: Pubkey,
+    pub consolation_pool: u64,
+    pub consolation_winners: u8,
+    pub losing_tickets: u128,
 }
 
 #[event]
@@ -68,6 +103,8 @@ pub struct WinningsClaimed {
     pub exploration_key: Pubkey,
     pub deposit_receipt: Pubkey,
     pub effective_tickets: u128,
+    /// Prize tier won (0 = first prize)
+    pub prize_tier: u8,
     pub winning_amount: u64,
 }
 
@@ -79,6 +116,76 @@ pub struct BuybackBudgetAllocated {
     pub lp_amount: u64,
     pub team_amount: u64,
     pub ecosystem_amount: u64,
+    /// Ecosystem allocation kept in the vault for consolation bonuses
+    pub consolation_pool: u64,
+    /// Team-ops allocation kept in the vault for referrer rewards
+    pub referral_pool: u64,
+}
+
+#[event]
+pub struct ReferrerRegistered {
+    pub owner: Pubkey,
+    pub referrer: Pubkey,
+    pub code: [u8; 16],
+}
+
+#[event]
+pub struct ReferralRewardClaimed {
+    pub referrer: Pubkey,
+    pub exploration_key: Pubkey,
+    pub referred_amount: u64,
+    pub reward: u64,
+}
+
+#[event]
+pub struct BuybackScheduled {
+    pub exploration_key: Pubkey,
+    pub block_id: u8,
+    pub window_start_ts: i64,
+    pub window_end_ts: i64,
+    pub tranche_start_ts: [i64; 4],
+    pub tranche_bps: [u16; 4],
+}
+
+#[event]
+pub struct BuybackBurned {
+    pub block_id: u8,
+    pub exploration_key: Pubkey,
+    pub tranche: u8,
+    pub amm_program: Pubkey,
+    /// Block asset sold from the buyback-burn treasury
+    pub amount_in: u64,
+    /// $RIG received and burned
+    pub amount_out: u64,
+    pub treasury_remaining: u64,
+}
+
+#[event]
+pub struct BuybackLpLocked {
+    pub block_id: u8,
+    pub exploration_key: Pubkey,
+    pub tranche: u8,
+    pub amm_program: Pubkey,
+    /// Block asset swapped for $RIG
+    pub swap_amount_in: u64,
+    pub rig_bought: u64,
+    /// Amounts the pool took on each side
+    pub asset_paired: u64,
+    pub rig_paired: u64,
+    /// LP tokens minted into the lock
+    pub lp_locked: u64,
+    pub total_lp_locked: u64,
+}
+
+#[event]
+pub struct DustSwept {
+    pub exploration_key: Pubkey,
+    pub block_id: u8,
+    /// Deposits, rollover and slashed bond held for the exploration
+    pub total_entitled: u64,
+    /// Claims, refunds and buckets paid before the sweep
+    pub total_paid: u64,
+    pub dust: u64,
 }
 
 #[event]
@@ -110,9 +217,139 @@ pub struct ParamsUpdated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct TrackInitialized {
+    pub block_id: u8,
+    pub track_id: u8,
+    pub schedule_anchor_ts: i64,
+    pub active_duration_secs: u32,
+    pub cooldown_duration_secs: u32,
+    pub anti_snipe_window_secs: u32,
+}
+
+#[event]
+pub struct BlockParamsUpdated {
+    pub block_id: u8,
+    pub min_threshold: u64,
+    pub prize_tier_bps: [u16; 3],
+    pub consolation_bps: u16,
+    pub consolation_winners: u8,
+    pub rollover_policy: u8,
+    pub jackpot_bps: u16,
+    pub jackpot_hit_bps: u16,
+    pub deposit_fee_bps: u16,
+    pub fee_discount_rig_balance: u64,
+    pub fee_discount_bps: u16,
+    pub loser_refund_bps: u16,
+}
+
+#[event]
+pub struct TreasuriesInitialized {
+    pub block_id: u8,
+    pub asset_mint: Pubkey,
+    pub treasury_authority: Pubkey,
+    pub buyback_burn: Pubkey,
+    pub buyback_lp: Pubkey,
+    pub team_ops: Pubkey,
+    pub ecosystem: Pubkey,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub block_id: u8,
+    /// WithdrawableBucket withdrawn from
+    pub bucket: u8,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub treasury_remaining: u64,
+}
+
+#[event]
+pub struct JackpotInitialized {
+    pub block_id: u8,
+    pub asset_mint: Pubkey,
+    pub jackpot_vault: Pubkey,
+}
+
+#[event]
+pub struct FeeVaultInitialized {
+    pub block_id: u8,
+    pub asset_mint: Pubkey,
+    pub fee_vault: Pubkey,
+}
+
+#[event]
+pub struct JackpotFunded {
+    pub block_id: u8,
+    pub exploration_key: Pubkey,
+    /// Rollover moved in under the jackpot rollover policy
+    pub rollover_amount: u64,
+    /// Jackpot slice of the exploration's R
+    pub pool_contribution: u64,
+    /// Jackpot vault balance afterwards
+    pub jackpot_balance: u64,
+}
+
+#[event]
+pub struct JackpotHit {
+    pub block_id: u8,
+    pub exploration_key: Pubkey,
+    pub winning_rig: u8,
+    /// Whole jackpot, paid on top of the winning rig's prize
+    pub amount: u64,
+}
+
+#[event]
+pub struct TrackScheduleUpdated {
+    pub block_id: u8,
+    pub track_id: u8,
+    pub schedule_anchor_ts: i64,
+    pub schedule_anchor_index: u64,
+    pub active_duration_secs: u32,
+    pub cooldown_duration_secs: u32,
+    pub anti_snipe_window_secs: u32,
+}
+
 #[event]
 pub struct RandomnessTimeoutFallback {
     pub exploration_key: Pubkey,
+    /// Reveal deadline slot
+    pub deadline_slot: u64,
+    /// Slot whose hash was used (the deadline unless re-committed)
     pub fallback_slot: u64,
     pub winning_rig: u8,
 }
+
+#[event]
+pub struct FallbackSlotRecommitted {
+    pub exploration_key: Pubkey,
+    /// Fallback slot whose hash aged out of SlotHashes
+    pub previous_slot: u64,
+    /// New fallback slot
+    pub fallback_slot: u64,
+}
+
+#[event]
+pub struct OperatorBondPosted {
+    pub block_id: u8,
+    pub operator: Pubkey,
+    pub amount: u64,
+    pub total_bond: u64,
+}
+
+#[event]
+pub struct OperatorBondWithdrawn {
+    pub block_id: u8,
+    pub operator: Pubkey,
+    pub amount: u64,
+    pub total_bond: u64,
+}
+
+#[event]
+pub struct OperatorSlashed {
+    pub block_id: u8,
+    pub exploration_key: Pubkey,
+    pub operator: Pubkey,
+    pub amount: u64,
+    pub remaining_bond: u64,
+}
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
-use crate::state::{ExplorationState, ExplorationStatus, ProtocolConfig};
+use crate::state::{
+    exploration_flags, treasury_buckets, BuybackSchedule, ExplorationState, ExplorationStatus,
+    ProtocolConfig, TrackState, TREASURY_SEED,
+};
 use crate::errors::RigItError;
 use crate::events::BuybackBudgetAllocated;
 
@@ -15,65 +18,83 @@ pub struct AllocateBuybackBudget<'info> {
 
     #[account(
         mut,
-        constraint = exploration_state.status == ExplorationStatus::Settled @ RigItError::ExplorationNotSettled,
-        constraint = !exploration_state.buyback_allocated @ RigItError::BuybackAlreadyAllocated,
+        constraint = exploration_state.load()?.status() == ExplorationStatus::Settled @ RigItError::ExplorationNotSettled,
+        constraint = !exploration_state.load()?.has_flag(exploration_flags::BUYBACK_ALLOCATED) @ RigItError::BuybackAlreadyAllocated,
     )]
-    pub exploration_state: Box<Account<'info, ExplorationState>>,
+    pub exploration_state: AccountLoader<'info, ExplorationState>,
 
     #[account(
         mut,
-        seeds = [b"block_vault", &[exploration_state.block_id]],
+        seeds = [TrackState::SEED, &[exploration_state.load()?.block_id], &[exploration_state.load()?.track_id]],
+        bump = track_state.bump,
+    )]
+    pub track_state: Box<Account<'info, TrackState>>,
+
+    #[account(
+        mut,
+        seeds = [b"block_vault", &[exploration_state.load()?.block_id]],
         bump,
     )]
     pub block_vault: Box<Account<'info, TokenAccount>>,
 
     /// CHECK: PDA authority for vault
     #[account(
-        seeds = [b"block_vault_authority", &[exploration_state.block_id]],
+        seeds = [b"block_vault_authority", &[exploration_state.load()?.block_id]],
         bump
     )]
     pub block_vault_authority: UncheckedAccount<'info>,
 
-    // Treasury vaults
+    // Treasury vaults for this block's asset
     #[account(
         mut,
-        seeds = [b"treasury", b"buyback_burn"],
+        seeds = [TREASURY_SEED, treasury_buckets::BUYBACK_BURN, &[exploration_state.load()?.block_id]],
         bump
     )]
     pub treasury_buyback_burn: Box<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
-        seeds = [b"treasury", b"buyback_lp"],
+        seeds = [TREASURY_SEED, treasury_buckets::BUYBACK_LP, &[exploration_state.load()?.block_id]],
         bump
     )]
     pub treasury_buyback_lp: Box<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
-        seeds = [b"treasury", b"team_ops"],
+        seeds = [TREASURY_SEED, treasury_buckets::TEAM_OPS, &[exploration_state.load()?.block_id]],
         bump
     )]
     pub treasury_team_ops: Box<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
-        seeds = [b"treasury", b"ecosystem"],
+        seeds = [TREASURY_SEED, treasury_buckets::ECOSYSTEM, &[exploration_state.load()?.block_id]],
         bump
     )]
     pub treasury_ecosystem: Box<Account<'info, TokenAccount>>,
 
+    /// Buyback schedule derived at settlement; receives the budgets
     #[account(
+        mut,
+        seeds = [BuybackSchedule::SEED, exploration_state.key().as_ref()],
+        bump = buyback_schedule.bump,
+    )]
+    pub buyback_schedule: Box<Account<'info, BuybackSchedule>>,
+
+    #[account(
+        mut,
         constraint = operator.key() == protocol_config.operator @ RigItError::Unauthorized
     )]
     pub operator: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<AllocateBuybackBudget>) -> Result<()> {
     let config = &ctx.accounts.protocol_config;
-    let exploration = &mut ctx.accounts.exploration_state;
+    let exploration_key = ctx.accounts.exploration_state.key();
+    let mut exploration = ctx.accounts.exploration_state.load_mut()?;
     let r = exploration.remaining_pool as u128;
 
     // Calculate allocations from R
@@ -94,11 +115,18 @@ pub fn handler(ctx: Context<AllocateBuybackBudget>) -> Result<()> {
         .checked_mul(config.team_ops_bps as u128)
         .ok_or(RigItError::ArithmeticOverflow)?
         / 10000;
+
+    // Referrer rewards are carved from team ops and stay in the vault until claimed
+    let referral_pool = exploration.fund_referrals(team_amount as u64, config.referral_bps)?;
+    let team_amount = team_amount - referral_pool as u128;
     
-    let ecosystem_amount = r
+    // The consolation pool stays in the vault for losers' refunds
+    let ecosystem_amount = (r
         .checked_mul(config.ecosystem_bps as u128)
         .ok_or(RigItError::ArithmeticOverflow)?
-        / 10000;
+        / 10000)
+        .checked_sub(exploration.consolation_pool as u128)
+        .ok_or(RigItError::ArithmeticOverflow)?;
 
     let block_id = exploration.block_id;
     let seeds = &[
@@ -168,16 +196,32 @@ pub fn handler(ctx: Context<AllocateBuybackBudget>) -> Result<()> {
         token::transfer(cpi_ctx, ecosystem_amount as u64)?;
     }
 
+    // Allocated buckets leave the vault
+    let allocated = burn_amount + lp_amount + team_amount + ecosystem_amount;
+    let track = &mut ctx.accounts.track_state;
+    track.outstanding_liabilities = track
+        .outstanding_liabilities
+        .checked_sub(allocated as u64)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+
     // Mark as allocated
-    exploration.buyback_allocated = true;
+    exploration.record_payout(allocated as u64)?;
+    exploration.set_flag(exploration_flags::BUYBACK_ALLOCATED);
+
+    // Budgets are spent in the tranches scheduled at settlement
+    let schedule = &mut ctx.accounts.buyback_schedule;
+    schedule.burn_budget = burn_amount as u64;
+    schedule.lp_budget = lp_amount as u64;
 
     emit!(BuybackBudgetAllocated {
-        exploration_key: exploration.key(),
+        exploration_key,
         remaining_pool: exploration.remaining_pool,
         burn_amount: burn_amount as u64,
         lp_amount: lp_amount as u64,
         team_amount: team_amount as u64,
         ecosystem_amount: ecosystem_amount as u64,
+        consolation_pool: exploration.consolation_pool,
+        referral_pool,
     });
 
     msg!(
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{ExplorationState, ExplorationStatus, ProtocolConfig};
+use crate::state::{exploration_flags, ExplorationState, ExplorationStatus, ProtocolConfig};
 use crate::errors::RigItError;
 use crate::events::FundsCarriedForward;
 
@@ -15,19 +15,20 @@ pub struct CarryForward<'info> {
     /// The rolled-over exploration (source of funds)
     #[account(
         mut,
-        constraint = rolled_exploration.status == ExplorationStatus::RolledOver @ RigItError::InvalidExplorationStatus,
-        constraint = !rolled_exploration.carry_forward_done @ RigItError::CarryForwardAlreadyDone,
+        constraint = rolled_exploration.load()?.status() == ExplorationStatus::RolledOver @ RigItError::InvalidExplorationStatus,
+        constraint = !rolled_exploration.load()?.has_flag(exploration_flags::CARRY_FORWARD_DONE) @ RigItError::CarryForwardAlreadyDone,
     )]
-    pub rolled_exploration: Box<Account<'info, ExplorationState>>,
+    pub rolled_exploration: AccountLoader<'info, ExplorationState>,
 
     /// The next exploration to receive the funds
     #[account(
         mut,
-        constraint = next_exploration.block_id == rolled_exploration.block_id,
-        constraint = next_exploration.exploration_index == rolled_exploration.exploration_index + 1,
-        constraint = next_exploration.status == ExplorationStatus::Active @ RigItError::ExplorationNotActive,
+        constraint = next_exploration.load()?.block_id == rolled_exploration.load()?.block_id,
+        constraint = next_exploration.load()?.track_id == rolled_exploration.load()?.track_id,
+        constraint = next_exploration.load()?.exploration_index == rolled_exploration.load()?.exploration_index + 1,
+        constraint = next_exploration.load()?.status() == ExplorationStatus::Active @ RigItError::ExplorationNotActive,
     )]
-    pub next_exploration: Box<Account<'info, ExplorationState>>,
+    pub next_exploration: AccountLoader<'info, ExplorationState>,
 
     #[account(
         constraint = operator.key() == protocol_config.operator @ RigItError::Unauthorized
@@ -36,8 +37,8 @@ pub struct CarryForward<'info> {
 }
 
 pub fn handler(ctx: Context<CarryForward>) -> Result<()> {
-    let rolled = &mut ctx.accounts.rolled_exploration;
-    let next = &mut ctx.accounts.next_exploration;
+    let mut rolled = ctx.accounts.rolled_exploration.load_mut()?;
+    let mut next = ctx.accounts.next_exploration.load_mut()?;
 
     // Amount to carry forward is the total deposits from rolled exploration
     let carry_amount = rolled.total_deposits;
@@ -49,11 +50,11 @@ pub fn handler(ctx: Context<CarryForward>) -> Result<()> {
         .ok_or(RigItError::ArithmeticOverflow)?;
 
     // Mark as done
-    rolled.carry_forward_done = true;
+    rolled.set_flag(exploration_flags::CARRY_FORWARD_DONE);
 
     emit!(FundsCarriedForward {
-        from_exploration: rolled.key(),
-        to_exploration: next.key(),
+        from_exploration: ctx.accounts.rolled_exploration.key(),
+        to_exploration: ctx.accounts.next_exploration.key(),
         amount: carry_amount,
     });
 
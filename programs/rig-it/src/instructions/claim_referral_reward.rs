@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::{
+    exploration_flags, ExplorationState, ExplorationStatus, ReferralVolume, Referrer, TrackState,
+    UserStats,
+};
+use crate::errors::RigItError;
+use crate::events::ReferralRewardClaimed;
+
+#[derive(Accounts)]
+pub struct ClaimReferralReward<'info> {
+    #[account(
+        mut,
+        constraint = exploration_state.load()?.status() == ExplorationStatus::Settled @ RigItError::ExplorationNotSettled,
+        constraint = exploration_state.load()?.has_flag(exploration_flags::BUYBACK_ALLOCATED) @ RigItError::ReferralRewardsNotReady,
+    )]
+    pub exploration_state: AccountLoader<'info, ExplorationState>,
+
+    #[account(
+        mut,
+        seeds = [TrackState::SEED, &[exploration_state.load()?.block_id], &[exploration_state.load()?.track_id]],
+        bump = track_state.bump,
+    )]
+    pub track_state: Box<Account<'info, TrackState>>,
+
+    #[account(
+        seeds = [Referrer::SEED, owner.key().as_ref()],
+        bump = referrer.bump,
+    )]
+    pub referrer: Account<'info, Referrer>,
+
+    #[account(
+        mut,
+        seeds = [UserStats::SEED, owner.key().as_ref()],
+        bump = referrer_stats.bump,
+    )]
+    pub referrer_stats: Box<Account<'info, UserStats>>,
+
+    /// Volume the owner referred into this exploration
+    #[account(
+        mut,
+        seeds = [ReferralVolume::SEED, exploration_state.key().as_ref(), owner.key().as_ref()],
+        bump = referral_volume.bump,
+        constraint = referral_volume.volume > 0 @ RigItError::NotReferred,
+        constraint = !referral_volume.claimed @ RigItError::ReferralAlreadyClaimed,
+    )]
+    pub referral_volume: Box<Account<'info, ReferralVolume>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key(),
+    )]
+    pub owner_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"block_vault", &[exploration_state.load()?.block_id]],
+        bump,
+    )]
+    pub block_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA authority for vault
+    #[account(
+        seeds = [b"block_vault_authority", &[exploration_state.load()?.block_id]],
+        bump
+    )]
+    pub block_vault_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimReferralReward>) -> Result<()> {
+    let exploration_key = ctx.accounts.exploration_state.key();
+    let mut exploration = ctx.accounts.exploration_state.load_mut()?;
+    let volume = &mut ctx.accounts.referral_volume;
+
+    // Referral pool pro-rata to the referrer's share of referred volume
+    let reward = exploration.referral_reward(volume.volume);
+
+    if reward > 0 {
+        let block_id = exploration.block_id;
+        let seeds = &[
+            b"block_vault_authority".as_ref(),
+            &[block_id],
+            &[ctx.bumps.block_vault_authority],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.block_vault.to_account_info(),
+            to: ctx.accounts.owner_token_account.to_account_info(),
+            authority: ctx.accounts.block_vault_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, reward)?;
+
+        // Reward leaves the vault
+        let track = &mut ctx.accounts.track_state;
+        track.outstanding_liabilities = track
+            .outstanding_liabilities
+            .checked_sub(reward)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        exploration.record_referral_payout(reward)?;
+    }
+
+    volume.claimed = true;
+
+    let stats = &mut ctx.accounts.referrer_stats;
+    stats.referral_rewards = stats
+        .referral_rewards
+        .checked_add(reward as u128)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+
+    emit!(ReferralRewardClaimed {
+        referrer: ctx.accounts.owner.key(),
+        exploration_key,
+        referred_amount: volume.volume,
+        reward,
+    });
+
+    msg!(
+        "Referrer {} claimed {} for {} referred volume",
+        ctx.accounts.owner.key(),
+        reward,
+        volume.volume
+    );
+
+    Ok(())
+}
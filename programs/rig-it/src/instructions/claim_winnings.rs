@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
-use crate::state::{DepositReceipt, ExplorationState, ExplorationStatus, ProtocolConfig, RigState};
+use crate::state::{
+    DepositReceipt, ExplorationState, ExplorationStatus, ProtocolConfig, RigState, TrackState,
+};
 use crate::errors::RigItError;
 use crate::events::WinningsClaimed;
 
@@ -14,9 +16,17 @@ pub struct ClaimWinnings<'info> {
     pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(
-        constraint = exploration_state.status == ExplorationStatus::Settled @ RigItError::ExplorationNotSettled,
+        mut,
+        constraint = exploration_state.load()?.status() == ExplorationStatus::Settled @ RigItError::ExplorationNotSettled,
+    )]
+    pub exploration_state: AccountLoader<'info, ExplorationState>,
+
+    #[account(
+        mut,
+        seeds = [TrackState::SEED, &[exploration_state.load()?.block_id], &[exploration_state.load()?.track_id]],
+        bump = track_state.bump,
     )]
-    pub exploration_state: Box<Account<'info, ExplorationState>>,
+    pub track_state: Account<'info, TrackState>,
 
     #[account(
         constraint = rig_state.exploration == exploration_state.key(),
@@ -43,14 +53,14 @@ pub struct ClaimWinnings<'info> {
 
     #[account(
         mut,
-        seeds = [b"block_vault", &[exploration_state.block_id]],
+        seeds = [b"block_vault", &[exploration_state.load()?.block_id]],
         bump,
     )]
     pub block_vault: Account<'info, TokenAccount>,
 
     /// CHECK: PDA authority for vault
     #[account(
-        seeds = [b"block_vault_authority", &[exploration_state.block_id]],
+        seeds = [b"block_vault_authority", &[exploration_state.load()?.block_id]],
         bump
     )]
     pub block_vault_authority: UncheckedAccount<'info>,
@@ -60,27 +70,33 @@ pub struct ClaimWinnings<'info> {
 
 pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
     let config = &ctx.accounts.protocol_config;
-    let exploration = &ctx.accounts.exploration_state;
+    let exploration_key = ctx.accounts.exploration_state.key();
+    let (mut exploration, rigs) =
+        ExplorationState::load_with_rigs_mut(&ctx.accounts.exploration_state)?;
     let rig = &ctx.accounts.rig_state;
     let receipt = &mut ctx.accounts.deposit_receipt;
 
-    // Verify this rig WON
-    let winning_rig = exploration.winning_rig.ok_or(RigItError::ExplorationNotSettled)?;
-    require!(rig.rig_index == winning_rig, RigItError::NotAWinner);
+    // Verify this rig won a prize tier
+    require!(exploration.winning_rig().is_some(), RigItError::ExplorationNotSettled);
+    let tier = exploration
+        .prize_tier(rig.rig_index)
+        .ok_or(RigItError::NotAWinner)?;
 
     // Calculate winner's share
-    // Winner pool = 50% of R
-    // User's share = winner_pool * (user_tickets / winning_rig_total_tickets)
-    let r = exploration.remaining_pool;
-    let winner_pool = (r as u128)
-        .checked_mul(config.winner_share_bps as u128)
-        .ok_or(RigItError::ArithmeticOverflow)?
-        / 10000;
-
-    let winning_rig_tickets = exploration.rig_tickets[winning_rig as usize];
+    // Winner pool = 50% of R + any slashed operator bond (+ rollover under the
+    // winner-pool policy)
+    // Tier pool = winner_pool * tier_bps (+ the jackpot for the first prize if it hit)
+    // User's share = tier_pool * (user_tickets / rig_total_tickets)
+    let tier_pool = exploration.tier_pool(tier, config.winner_share_bps)?;
+
+    let winning_rig_tickets = rigs
+        .get(rig.rig_index as usize)
+        .ok_or(RigItError::InvalidRigIndex)?
+        .tickets
+        .get();
     require!(winning_rig_tickets > 0, RigItError::ArithmeticOverflow);
 
-    let user_share = winner_pool
+    let user_share = tier_pool
         .checked_mul(receipt.effective_tickets)
         .ok_or(RigItError::ArithmeticOverflow)?
         / winning_rig_tickets;
@@ -105,21 +121,34 @@ pub fn handler(ctx: Context<ClaimWinnings>) -> Result<()> {
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
     token::transfer(cpi_ctx, payout_amount)?;
 
+    // Payout leaves the vault
+    let track = &mut ctx.accounts.track_state;
+    track.outstanding_liabilities = track
+        .outstanding_liabilities
+        .checked_sub(payout_amount)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+
+    // Paid and entitled amounts are reconciled when dust is swept
+    exploration.record_payout(payout_amount)?;
+    exploration.close_receipt()?;
+
     // Mark as claimed
     receipt.winnings_claimed = true;
 
     emit!(WinningsClaimed {
         user: receipt.user,
-        exploration_key: exploration.key(),
+        exploration_key,
         deposit_receipt: receipt.key(),
         effective_tickets: receipt.effective_tickets,
+        prize_tier: tier as u8,
         winning_amount: payout_amount,
     });
 
     msg!(
-        "Winner {} claimed {} (tickets: {}/{})",
+        "Winner {} claimed {} for prize tier {} (tickets: {}/{})",
         receipt.user,
         payout_amount,
+        tier + 1,
         receipt.effective_tickets,
         winning_rig_tickets
     );
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{BlockState, ExplorationState, ExplorationStatus, ProtocolConfig};
+use crate::state::{BlockState, ExplorationState, ExplorationStatus, OperatorBond, ProtocolConfig};
 use crate::errors::RigItError;
 use crate::events::{ExplorationRolledOver, RandomnessCommitted};
+use crate::utils::CURRENT_COMMIT_SCHEME;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CommitRandomnessArgs {
@@ -28,13 +29,23 @@ pub struct CommitRandomness<'info> {
         mut,
         seeds = [
             ExplorationState::SEED,
-            &[exploration_state.block_id],
-            &exploration_state.exploration_index.to_le_bytes()
+            &[block_state.block_id],
+            &[exploration_state.load()?.track_id],
+            &exploration_state.load()?.exploration_index.to_le_bytes()
         ],
-        bump = exploration_state.bump,
-        constraint = exploration_state.status == ExplorationStatus::Active @ RigItError::InvalidExplorationStatus,
+        bump = exploration_state.load()?.bump,
+        constraint = exploration_state.load()?.status() == ExplorationStatus::Active @ RigItError::InvalidExplorationStatus,
     )]
-    pub exploration_state: Box<Account<'info, ExplorationState>>,
+    pub exploration_state: AccountLoader<'info, ExplorationState>,
+
+    /// Operator bond for the block, required when a bond is configured
+    #[account(
+        mut,
+        seeds = [OperatorBond::SEED, &[block_state.block_id]],
+        bump = operator_bond.bump,
+        constraint = operator_bond.operator == protocol_config.operator @ RigItError::Unauthorized,
+    )]
+    pub operator_bond: Option<Account<'info, OperatorBond>>,
 
     #[account(
         constraint = operator.key() == protocol_config.operator @ RigItError::Unauthorized
@@ -47,7 +58,9 @@ pub struct CommitRandomness<'info> {
 pub fn handler(ctx: Context<CommitRandomness>, args: CommitRandomnessArgs) -> Result<()> {
     let config = &ctx.accounts.protocol_config;
     let block = &ctx.accounts.block_state;
-    let exploration = &mut ctx.accounts.exploration_state;
+    let exploration_key = ctx.accounts.exploration_state.key();
+    let (mut exploration, rigs) =
+        ExplorationState::load_with_rigs_mut(&ctx.accounts.exploration_state)?;
     let clock = &ctx.accounts.clock;
 
     // Verify active phase has ended
@@ -61,12 +74,13 @@ pub fn handler(ctx: Context<CommitRandomness>, args: CommitRandomnessArgs) -> Re
 
     if total_pool < block.min_threshold {
         // Threshold not met - rollover
-        exploration.status = ExplorationStatus::RolledOver;
+        exploration.set_status(ExplorationStatus::RolledOver);
 
         emit!(ExplorationRolledOver {
             block_id: exploration.block_id,
+            track_id: exploration.track_id,
             exploration_index: exploration.exploration_index,
-            exploration_key: exploration.key(),
+            exploration_key,
             total_deposits: exploration.total_deposits,
             reason: "Threshold not met".to_string(),
         });
@@ -88,17 +102,42 @@ pub fn handler(ctx: Context<CommitRandomness>, args: CommitRandomnessArgs) -> Re
         RigItError::CommitSlotNotFuture
     );
 
+    // Lock operator bond so a missed reveal can be slashed
+    let stake = config
+        .required_operator_bond(block.asset_decimals)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+    if stake > 0 {
+        let bond = ctx
+            .accounts
+            .operator_bond
+            .as_mut()
+            .ok_or(RigItError::OperatorBondRequired)?;
+        require!(bond.available() >= stake, RigItError::InsufficientOperatorBond);
+        bond.locked_amount = bond
+            .locked_amount
+            .checked_add(stake)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+    }
+    exploration.operator_bond_at_stake = stake;
+
     // Set commit state
     exploration.commit_slot = args.target_slot;
     exploration.commit_hash = args.commit_hash;
+    exploration.commit_scheme = CURRENT_COMMIT_SCHEME;
+    // Freeze the ticket distribution the draw will be made from
+    exploration.commit_snapshot_hash = exploration.settlement_snapshot_hash(&rigs);
     exploration.reveal_deadline_slot = args.target_slot + config.commit_reveal_timeout_slots;
-    exploration.status = ExplorationStatus::Finalizing;
+    exploration.set_status(ExplorationStatus::Finalizing);
 
     emit!(RandomnessCommitted {
-        exploration_key: exploration.key(),
+        exploration_key,
         commit_slot: exploration.commit_slot,
         reveal_deadline_slot: exploration.reveal_deadline_slot,
         commit_hash: exploration.commit_hash,
+        commit_scheme: exploration.commit_scheme,
+        commit_snapshot_hash: exploration.commit_snapshot_hash,
+        entropy_accumulator: exploration.entropy_accumulator,
+        entropy_contributions: exploration.entropy_contributions,
     });
 
     msg!(
@@ -2,8 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::state::{
-    BlockState, DepositReceipt, ExplorationState, ExplorationStatus, 
-    ProtocolConfig, RigState, RIGS_PER_EXPLORATION,
+    treasury_buckets, BlockState, DepositReceipt, ExplorationState, ExplorationStatus,
+    ProtocolConfig, ReferralVolume, Referrer, RigState, TrackState, UserStats, TREASURY_SEED,
 };
 use crate::errors::RigItError;
 use crate::events::{AntiSnipedDepositClaimed, DepositMade};
@@ -14,6 +14,10 @@ pub struct DepositToRigArgs {
     pub rig_index: u8,
     pub amount: u64,
     pub deposit_nonce: u64,
+    /// Optional 32-byte entropy commitment mixed into the exploration's randomness
+    pub entropy_commitment: Option<[u8; 32]>,
+    /// Optional referrer (owner of a registered `Referrer`) the deposit is made through
+    pub referrer: Option<Pubkey>,
 }
 
 #[derive(Accounts)]
@@ -33,17 +37,25 @@ pub struct DepositToRig<'info> {
     )]
     pub block_state: Box<Account<'info, BlockState>>,
 
+    #[account(
+        mut,
+        seeds = [TrackState::SEED, &[block_state.block_id], &[exploration_state.load()?.track_id]],
+        bump = track_state.bump,
+    )]
+    pub track_state: Box<Account<'info, TrackState>>,
+
     #[account(
         mut,
         seeds = [
             ExplorationState::SEED,
-            &[exploration_state.block_id],
-            &exploration_state.exploration_index.to_le_bytes()
+            &[block_state.block_id],
+            &[exploration_state.load()?.track_id],
+            &exploration_state.load()?.exploration_index.to_le_bytes()
         ],
-        bump = exploration_state.bump,
-        constraint = exploration_state.status == ExplorationStatus::Active @ RigItError::ExplorationNotActive,
+        bump = exploration_state.load()?.bump,
+        constraint = exploration_state.load()?.status() == ExplorationStatus::Active @ RigItError::ExplorationNotActive,
     )]
-    pub exploration_state: Box<Account<'info, ExplorationState>>,
+    pub exploration_state: AccountLoader<'info, ExplorationState>,
 
     #[account(
         init_if_needed,
@@ -86,34 +98,83 @@ pub struct DepositToRig<'info> {
     )]
     pub block_vault: Box<Account<'info, TokenAccount>>,
 
+    /// Deposit-fee treasury, required while the block charges a fee
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, treasury_buckets::DEPOSIT_FEES, &[block_state.block_id]],
+        bump,
+    )]
+    pub treasury_deposit_fees: Option<Box<Account<'info, TokenAccount>>>,
+
     /// Optional: User's $RIG token account for multiplier calculation
     pub user_rig_token_account: Option<Box<Account<'info, TokenAccount>>>,
 
     /// $RIG token mint (for validation)
     pub rig_token_mint: Option<Account<'info, Mint>>,
 
+    /// Required if `args.referrer` is set
+    #[account(
+        constraint = referrer.owner != user.key() @ RigItError::SelfReferral,
+    )]
+    pub referrer: Option<Box<Account<'info, Referrer>>>,
+
+    /// Referrer's stats, required if `args.referrer` is set
+    #[account(mut)]
+    pub referrer_stats: Option<Box<Account<'info, UserStats>>>,
+
+    /// Referrer's volume in this exploration, required if `args.referrer` is set
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ReferralVolume::LEN,
+        seeds = [
+            ReferralVolume::SEED,
+            exploration_state.key().as_ref(),
+            args.referrer.unwrap_or_default().as_ref()
+        ],
+        bump
+    )]
+    pub referral_volume: Option<Box<Account<'info, ReferralVolume>>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
 }
 
 pub fn handler(ctx: Context<DepositToRig>, args: DepositToRigArgs) -> Result<()> {
+    let track = &mut ctx.accounts.track_state;
+    let exploration_key = ctx.accounts.exploration_state.key();
+    let (mut exploration, mut rigs) =
+        ExplorationState::load_with_rigs_mut(&ctx.accounts.exploration_state)?;
+
     // Validate inputs
     require!(
-        args.rig_index < RIGS_PER_EXPLORATION as u8,
+        args.rig_index < exploration.rig_count,
         RigItError::InvalidRigIndex
     );
-    require!(args.amount >= MIN_DEPOSIT_AMOUNT, RigItError::DepositTooSmall);
+    // Deposit fee, discounted for holders of the depositor's own $RIG
+    let holder_balance = ctx
+        .accounts
+        .user_rig_token_account
+        .as_ref()
+        .filter(|acc| {
+            acc.owner == ctx.accounts.user.key() &&
+                acc.mint == ctx.accounts.protocol_config.rig_token_mint
+        })
+        .map(|acc| acc.amount)
+        .unwrap_or(0);
+    let fee = ctx.accounts.block_state.deposit_fee(args.amount, holder_balance);
+    // Only the rest enters the draw, so refunds and payouts are unaffected by the fee
+    let amount = args.amount - fee;
+    require!(amount >= MIN_DEPOSIT_AMOUNT, RigItError::DepositTooSmall);
 
-    let config = &ctx.accounts.protocol_config;
-    let exploration = &mut ctx.accounts.exploration_state;
     let rig = &mut ctx.accounts.rig_state;
     let receipt = &mut ctx.accounts.deposit_receipt;
     let clock = &ctx.accounts.clock;
     let current_ts = clock.unix_timestamp;
 
     // Check if in anti-snipe window
-    let is_anti_sniped = exploration.is_anti_snipe_window(current_ts, config.anti_snipe_window_secs);
+    let is_anti_sniped = exploration.is_anti_snipe_window(current_ts);
 
     // Get $RIG balance for multiplier
     let rig_balance = ctx
@@ -124,11 +185,11 @@ pub fn handler(ctx: Context<DepositToRig>, args: DepositToRigArgs) -> Result<()>
         .unwrap_or(0);
 
     // Calculate effective tickets
-    let effective_tickets = calculate_effective_tickets(args.amount, rig_balance);
+    let effective_tickets = calculate_effective_tickets(amount, rig_balance);
 
     // Initialize rig state if new
     if rig.exploration == Pubkey::default() {
-        rig.exploration = exploration.key();
+        rig.exploration = exploration_key;
         rig.rig_index = args.rig_index;
         rig.bump = ctx.bumps.rig_state;
     }
@@ -137,8 +198,8 @@ pub fn handler(ctx: Context<DepositToRig>, args: DepositToRigArgs) -> Result<()>
     receipt.user = ctx.accounts.user.key();
     receipt.rig = rig.key();
     receipt.rig_index = args.rig_index;
-    receipt.exploration = exploration.key();
-    receipt.amount = args.amount;
+    receipt.exploration = exploration_key;
+    receipt.amount = amount;
     receipt.effective_tickets = effective_tickets;
     receipt.deposited_at = current_ts;
     receipt.deposit_nonce = args.deposit_nonce;
@@ -147,6 +208,33 @@ pub fn handler(ctx: Context<DepositToRig>, args: DepositToRigArgs) -> Result<()>
     receipt.refund_claimed = false;
     receipt.winnings_claimed = false;
     receipt.bump = ctx.bumps.deposit_receipt;
+    receipt.ticket_offset = 0;
+    receipt.referrer = None;
+
+    if let Some(referrer_key) = args.referrer {
+        let referrer = ctx
+            .accounts
+            .referrer
+            .as_ref()
+            .ok_or(RigItError::ReferrerMismatch)?;
+        require_keys_eq!(referrer.owner, referrer_key, RigItError::ReferrerMismatch);
+        let stats = ctx
+            .accounts
+            .referrer_stats
+            .as_mut()
+            .ok_or(RigItError::ReferrerMismatch)?;
+        require_keys_eq!(stats.user, referrer_key, RigItError::ReferrerMismatch);
+
+        stats.referred_volume = stats
+            .referred_volume
+            .checked_add(amount as u128)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        stats.referred_deposits = stats
+            .referred_deposits
+            .checked_add(1)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        receipt.referrer = Some(referrer_key);
+    }
 
     // Transfer tokens to vault
     let cpi_accounts = Transfer {
@@ -156,14 +244,45 @@ pub fn handler(ctx: Context<DepositToRig>, args: DepositToRigArgs) -> Result<()>
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_ctx, args.amount)?;
+    token::transfer(cpi_ctx, amount)?;
+
+    if fee > 0 {
+        let fee_vault = ctx
+            .accounts
+            .treasury_deposit_fees
+            .as_ref()
+            .ok_or(RigItError::FeeVaultRequired)?;
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.user_token_account.to_account_info(),
+            to: fee_vault.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, fee)?;
+
+        track.total_fees_collected = track
+            .total_fees_collected
+            .checked_add(fee)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+    }
+
+    // The vault now holds these funds on behalf of this track
+    track.outstanding_liabilities = track
+        .outstanding_liabilities
+        .checked_add(amount)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+
+    // Depositor entropy is accepted until the operator commits
+    if let Some(commitment) = args.entropy_commitment {
+        exploration.contribute_entropy(&receipt.user, &commitment)?;
+    }
 
     // Update state only if NOT anti-sniped
     // Anti-sniped deposits are held but don't affect current exploration
     if !is_anti_sniped {
         rig.total_deposits = rig
             .total_deposits
-            .checked_add(args.amount)
+            .checked_add(amount)
             .ok_or(RigItError::ArithmeticOverflow)?;
         rig.total_tickets = rig
             .total_tickets
@@ -174,36 +293,46 @@ pub fn handler(ctx: Context<DepositToRig>, args: DepositToRigArgs) -> Result<()>
             .checked_add(1)
             .ok_or(RigItError::ArithmeticOverflow)?;
 
-        exploration.total_deposits = exploration
-            .total_deposits
-            .checked_add(args.amount)
-            .ok_or(RigItError::ArithmeticOverflow)?;
-        exploration.rig_deposits[args.rig_index as usize] = exploration.rig_deposits
-            [args.rig_index as usize]
-            .checked_add(args.amount)
-            .ok_or(RigItError::ArithmeticOverflow)?;
-        exploration.rig_tickets[args.rig_index as usize] = exploration.rig_tickets
-            [args.rig_index as usize]
-            .checked_add(effective_tickets)
-            .ok_or(RigItError::ArithmeticOverflow)?;
+        receipt.ticket_offset =
+            exploration.add_to_rig(&mut rigs, args.rig_index, amount, effective_tickets)?;
+        if let Some(referrer_key) = receipt.referrer {
+            let volume = ctx
+                .accounts
+                .referral_volume
+                .as_mut()
+                .ok_or(RigItError::ReferrerMismatch)?;
+            if volume.exploration == Pubkey::default() {
+                volume.exploration = exploration_key;
+                volume.referrer = referrer_key;
+                volume.bump = ctx.bumps.referral_volume.ok_or(RigItError::ReferrerMismatch)?;
+            }
+            volume.volume = volume
+                .volume
+                .checked_add(amount)
+                .ok_or(RigItError::ArithmeticOverflow)?;
+            exploration.add_referred(amount)?;
+        }
     }
 
     // Emit event
     emit!(DepositMade {
         user: receipt.user,
         block_id: exploration.block_id,
+        track_id: exploration.track_id,
         exploration_index: exploration.exploration_index,
-        exploration_key: exploration.key(),
+        exploration_key,
         rig_index: args.rig_index,
-        amount: args.amount,
+        amount,
         effective_tickets,
         is_anti_sniped,
         deposit_receipt: receipt.key(),
+        referrer: receipt.referrer,
+        fee,
     });
 
     msg!(
         "Deposit of {} to rig {} (anti-sniped: {})",
-        args.amount,
+        amount,
         args.rig_index,
         is_anti_sniped
     );
@@ -223,11 +352,21 @@ pub struct ClaimAntiSnipedDeposit<'info> {
     )]
     pub original_receipt: Box<Account<'info, DepositReceipt>>,
 
+    /// Exploration the anti-sniped deposit was made into
+    #[account(
+        constraint = original_exploration.key() == original_receipt.exploration,
+    )]
+    pub original_exploration: AccountLoader<'info, ExplorationState>,
+
+    /// A later exploration on the same track, so the funds stay in its liabilities
     #[account(
         mut,
-        constraint = next_exploration.status == ExplorationStatus::Active @ RigItError::ExplorationNotActive,
+        constraint = next_exploration.load()?.status() == ExplorationStatus::Active @ RigItError::ExplorationNotActive,
+        constraint = next_exploration.load()?.block_id == original_exploration.load()?.block_id @ RigItError::InvalidTrackId,
+        constraint = next_exploration.load()?.track_id == original_exploration.load()?.track_id @ RigItError::InvalidTrackId,
+        constraint = next_exploration.load()?.exploration_index > original_exploration.load()?.exploration_index @ RigItError::InvalidExplorationStatus,
     )]
-    pub next_exploration: Box<Account<'info, ExplorationState>>,
+    pub next_exploration: AccountLoader<'info, ExplorationState>,
 
     #[account(
         init_if_needed,
@@ -256,6 +395,21 @@ pub struct ClaimAntiSnipedDeposit<'info> {
     )]
     pub new_receipt: Box<Account<'info, DepositReceipt>>,
 
+    /// Referrer's volume in the next exploration, required if the deposit
+    /// was made through a referrer
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ReferralVolume::LEN,
+        seeds = [
+            ReferralVolume::SEED,
+            next_exploration.key().as_ref(),
+            original_receipt.referrer.unwrap_or_default().as_ref()
+        ],
+        bump
+    )]
+    pub referral_volume: Option<Box<Account<'info, ReferralVolume>>>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
@@ -265,7 +419,9 @@ pub struct ClaimAntiSnipedDeposit<'info> {
 
 pub fn claim_anti_sniped_handler(ctx: Context<ClaimAntiSnipedDeposit>) -> Result<()> {
     let original = &mut ctx.accounts.original_receipt;
-    let next_exp = &mut ctx.accounts.next_exploration;
+    let next_exp_key = ctx.accounts.next_exploration.key();
+    let (mut next_exp, mut next_rigs) =
+        ExplorationState::load_with_rigs_mut(&ctx.accounts.next_exploration)?;
     let next_rig = &mut ctx.accounts.next_rig_state;
     let new_receipt = &mut ctx.accounts.new_receipt;
     let clock = &ctx.accounts.clock;
@@ -275,7 +431,7 @@ pub fn claim_anti_sniped_handler(ctx: Context<ClaimAntiSnipedDeposit>) -> Result
 
     // Initialize new rig state if needed
     if next_rig.exploration == Pubkey::default() {
-        next_rig.exploration = next_exp.key();
+        next_rig.exploration = next_exp_key;
         next_rig.rig_index = rig_index;
         next_rig.bump = ctx.bumps.next_rig_state;
     }
@@ -284,7 +440,7 @@ pub fn claim_anti_sniped_handler(ctx: Context<ClaimAntiSnipedDeposit>) -> Result
     new_receipt.user = original.user;
     new_receipt.rig = next_rig.key();
     new_receipt.rig_index = rig_index;
-    new_receipt.exploration = next_exp.key();
+    new_receipt.exploration = next_exp_key;
     new_receipt.amount = original.amount;
     new_receipt.effective_tickets = original.effective_tickets;
     new_receipt.deposited_at = clock.unix_timestamp;
@@ -293,6 +449,7 @@ pub fn claim_anti_sniped_handler(ctx: Context<ClaimAntiSnipedDeposit>) -> Result
     new_receipt.rolled_to_exploration = None;
     new_receipt.refund_claimed = false;
     new_receipt.winnings_claimed = false;
+    new_receipt.referrer = original.referrer;
     new_receipt.bump = ctx.bumps.new_receipt;
 
     // Update next exploration and rig totals
@@ -309,26 +466,35 @@ pub fn claim_anti_sniped_handler(ctx: Context<ClaimAntiSnipedDeposit>) -> Result
         .checked_add(1)
         .ok_or(RigItError::ArithmeticOverflow)?;
 
-    next_exp.total_deposits = next_exp
-        .total_deposits
-        .checked_add(original.amount)
-        .ok_or(RigItError::ArithmeticOverflow)?;
-    next_exp.rig_deposits[rig_index as usize] = next_exp.rig_deposits[rig_index as usize]
-        .checked_add(original.amount)
-        .ok_or(RigItError::ArithmeticOverflow)?;
-    next_exp.rig_tickets[rig_index as usize] = next_exp.rig_tickets[rig_index as usize]
-        .checked_add(original.effective_tickets)
-        .ok_or(RigItError::ArithmeticOverflow)?;
+    new_receipt.ticket_offset =
+        next_exp.add_to_rig(&mut next_rigs, rig_index, original.amount, original.effective_tickets)?;
+    if let Some(referrer_key) = original.referrer {
+        let volume = ctx
+            .accounts
+            .referral_volume
+            .as_mut()
+            .ok_or(RigItError::ReferrerMismatch)?;
+        if volume.exploration == Pubkey::default() {
+            volume.exploration = next_exp_key;
+            volume.referrer = referrer_key;
+            volume.bump = ctx.bumps.referral_volume.ok_or(RigItError::ReferrerMismatch)?;
+        }
+        volume.volume = volume
+            .volume
+            .checked_add(original.amount)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        next_exp.add_referred(original.amount)?;
+    }
 
     // Mark original as rolled
-    original.rolled_to_exploration = Some(next_exp.key());
+    original.rolled_to_exploration = Some(next_exp_key);
 
     emit!(AntiSnipedDepositClaimed {
         original_deposit: original.key(),
         new_deposit: new_receipt.key(),
         user: original.user,
         amount: original.amount,
-        new_exploration: next_exp.key(),
+        new_exploration: next_exp_key,
     });
 
     Ok(())
@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::state::{
+    treasury_buckets, BlockState, BuybackSchedule, ProtocolConfig, RIG_BUYBACK_SEED, TREASURY_AUTHORITY_SEED,
+    TREASURY_SEED,
+};
+use crate::errors::RigItError;
+use crate::events::BuybackBurned;
+use crate::utils::{adapter_spent, check_pool_accounts, swap_instruction, AdapterSwapAccounts};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExecuteBuybackBurnArgs {
+    /// Schedule tranche to execute; sells that tranche's share of the burn budget
+    pub tranche: u8,
+    /// Minimum $RIG the swap must return
+    pub min_amount_out: u64,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteBuybackBurn<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ RigItError::ProtocolPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [BlockState::SEED, &[block_state.block_id]],
+        bump = block_state.bump,
+    )]
+    pub block_state: Account<'info, BlockState>,
+
+    #[account(
+        mut,
+        seeds = [BuybackSchedule::SEED, buyback_schedule.exploration.as_ref()],
+        bump = buyback_schedule.bump,
+        constraint = buyback_schedule.block_id == block_state.block_id @ RigItError::InvalidBuybackTranche,
+    )]
+    pub buyback_schedule: Box<Account<'info, BuybackSchedule>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, treasury_buckets::BUYBACK_BURN, &[block_state.block_id]],
+        bump
+    )]
+    pub treasury_buyback_burn: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA authority for the block's treasury vaults
+    #[account(
+        seeds = [TREASURY_AUTHORITY_SEED, &[block_state.block_id]],
+        bump
+    )]
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    /// Receives the bought $RIG until it is burned in the same instruction
+    #[account(
+        init_if_needed,
+        payer = operator,
+        token::mint = rig_token_mint,
+        token::authority = treasury_authority,
+        seeds = [RIG_BUYBACK_SEED, &[block_state.block_id]],
+        bump
+    )]
+    pub rig_buyback_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = protocol_config.rig_token_mint,
+    )]
+    pub rig_token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Must be the whitelisted AMM adapter
+    #[account(
+        executable,
+        constraint = protocol_config.amm_program != Pubkey::default() @ RigItError::AmmNotWhitelisted,
+        address = protocol_config.amm_program @ RigItError::AmmNotWhitelisted,
+    )]
+    pub amm_program: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = operator.key() == protocol_config.operator @ RigItError::Unauthorized
+    )]
+    pub operator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+    // Pool-specific adapter accounts follow in remaining_accounts
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteBuybackBurn<'info>>,
+    args: ExecuteBuybackBurnArgs,
+) -> Result<()> {
+    let schedule = &mut ctx.accounts.buyback_schedule;
+    let clock = &ctx.accounts.clock;
+    schedule.check_tranche(args.tranche, schedule.burn_executed, clock.unix_timestamp)?;
+    let amount_in = schedule.tranche_amount(schedule.burn_budget, args.tranche);
+    schedule.burn_executed |= 1 << args.tranche;
+    let exploration_key = schedule.exploration;
+
+    require!(amount_in > 0, RigItError::InvalidSwapAmount);
+    require!(
+        ctx.accounts.treasury_buyback_burn.amount >= amount_in,
+        RigItError::InsufficientTreasuryBalance
+    );
+    check_pool_accounts(ctx.remaining_accounts, &ctx.accounts.treasury_authority.key())?;
+
+    let block_id = ctx.accounts.block_state.block_id;
+    let seeds = &[
+        TREASURY_AUTHORITY_SEED,
+        &[block_id],
+        &[ctx.bumps.treasury_authority],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Swap treasury funds for $RIG through the adapter
+    let treasury_before = ctx.accounts.treasury_buyback_burn.amount;
+    let rig_before = ctx.accounts.rig_buyback_vault.amount;
+    let ix = swap_instruction(
+        ctx.accounts.amm_program.key(),
+        &AdapterSwapAccounts {
+            source: ctx.accounts.treasury_buyback_burn.key(),
+            destination: ctx.accounts.rig_buyback_vault.key(),
+            authority: ctx.accounts.treasury_authority.key(),
+            token_program: ctx.accounts.token_program.key(),
+        },
+        ctx.remaining_accounts,
+        amount_in,
+        args.min_amount_out,
+    );
+    let mut account_infos = vec![
+        ctx.accounts.treasury_buyback_burn.to_account_info(),
+        ctx.accounts.rig_buyback_vault.to_account_info(),
+        ctx.accounts.treasury_authority.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    ];
+    account_infos.extend(ctx.remaining_accounts.iter().cloned());
+    account_infos.push(ctx.accounts.amm_program.to_account_info());
+    invoke_signed(&ix, &account_infos, signer_seeds)?;
+
+    // Measure what actually arrived rather than trusting the adapter
+    ctx.accounts.treasury_buyback_burn.reload()?;
+    ctx.accounts.rig_buyback_vault.reload()?;
+    // A partial fill would strand the rest of the tranche once it is marked executed
+    let amount_spent = adapter_spent(
+        treasury_before,
+        ctx.accounts.treasury_buyback_burn.amount,
+        amount_in,
+    )?;
+    require!(amount_spent == amount_in, RigItError::AdapterPartialFill);
+    let amount_out = ctx
+        .accounts
+        .rig_buyback_vault
+        .amount
+        .checked_sub(rig_before)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+    require!(
+        amount_out >= args.min_amount_out,
+        RigItError::SlippageExceeded
+    );
+
+    // Burn everything bought
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.rig_token_mint.to_account_info(),
+        from: ctx.accounts.rig_buyback_vault.to_account_info(),
+        authority: ctx.accounts.treasury_authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::burn(cpi_ctx, amount_out)?;
+
+    emit!(BuybackBurned {
+        block_id,
+        exploration_key,
+        tranche: args.tranche,
+        amm_program: ctx.accounts.amm_program.key(),
+        amount_in: amount_spent,
+        amount_out,
+        treasury_remaining: ctx.accounts.treasury_buyback_burn.amount,
+    });
+
+    msg!(
+        "Buyback burn for block {} tranche {}: {} in, {} $RIG burned",
+        block_id,
+        args.tranche,
+        amount_spent,
+        amount_out
+    );
+
+    Ok(())
+}
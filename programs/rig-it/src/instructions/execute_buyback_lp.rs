@@ -0,0 +1,298 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::{
+    treasury_buckets, BlockState, BuybackSchedule, LpLock, ProtocolConfig, RIG_BUYBACK_SEED,
+    TREASURY_AUTHORITY_SEED, TREASURY_SEED,
+};
+use crate::errors::RigItError;
+use crate::events::BuybackLpLocked;
+use crate::utils::{
+    adapter_spent, add_liquidity_instruction, check_pool_accounts, swap_instruction,
+    AdapterLiquidityAccounts, AdapterSwapAccounts,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExecuteBuybackLpArgs {
+    /// Schedule tranche to execute; takes that tranche's share of the LP
+    /// budget, swaps half for $RIG and pairs the rest with it
+    pub tranche: u8,
+    /// Minimum $RIG the swap half must return
+    pub min_rig_out: u64,
+    /// Minimum LP tokens the add-liquidity must mint into the lock
+    pub min_lp_out: u64,
+    /// Number of leading remaining accounts passed to the swap; the rest go
+    /// to add-liquidity
+    pub swap_account_count: u8,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteBuybackLp<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ RigItError::ProtocolPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [BlockState::SEED, &[block_state.block_id]],
+        bump = block_state.bump,
+    )]
+    pub block_state: Account<'info, BlockState>,
+
+    #[account(
+        mut,
+        seeds = [BuybackSchedule::SEED, buyback_schedule.exploration.as_ref()],
+        bump = buyback_schedule.bump,
+        constraint = buyback_schedule.block_id == block_state.block_id @ RigItError::InvalidBuybackTranche,
+    )]
+    pub buyback_schedule: Box<Account<'info, BuybackSchedule>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, treasury_buckets::BUYBACK_LP, &[block_state.block_id]],
+        bump
+    )]
+    pub treasury_buyback_lp: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA authority for the block's treasury vaults
+    #[account(
+        seeds = [TREASURY_AUTHORITY_SEED, &[block_state.block_id]],
+        bump
+    )]
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    /// Receives the bought $RIG; any $RIG the pool ratio leaves unpaired is
+    /// paired on the next execution
+    #[account(
+        init_if_needed,
+        payer = operator,
+        token::mint = rig_token_mint,
+        token::authority = treasury_authority,
+        seeds = [RIG_BUYBACK_SEED, &[block_state.block_id]],
+        bump
+    )]
+    pub rig_buyback_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = protocol_config.rig_token_mint)]
+    pub rig_token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = LpLock::LEN,
+        seeds = [LpLock::SEED, &[block_state.block_id]],
+        bump
+    )]
+    pub lp_lock: Box<Account<'info, LpLock>>,
+
+    /// Owned by the lock PDA, which never signs: LP tokens cannot leave
+    #[account(
+        init_if_needed,
+        payer = operator,
+        token::mint = lp_mint,
+        token::authority = lp_lock,
+        seeds = [LpLock::VAULT_SEED, &[block_state.block_id]],
+        bump
+    )]
+    pub lp_lock_vault: Box<Account<'info, TokenAccount>>,
+
+    /// LP mint of the adapter pool
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Must be the whitelisted AMM adapter
+    #[account(
+        executable,
+        constraint = protocol_config.amm_program != Pubkey::default() @ RigItError::AmmNotWhitelisted,
+        address = protocol_config.amm_program @ RigItError::AmmNotWhitelisted,
+    )]
+    pub amm_program: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = operator.key() == protocol_config.operator @ RigItError::Unauthorized
+    )]
+    pub operator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub clock: Sysvar<'info, Clock>,
+    // Pool-specific adapter accounts follow in remaining_accounts:
+    // the swap's first, then add-liquidity's
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteBuybackLp<'info>>,
+    args: ExecuteBuybackLpArgs,
+) -> Result<()> {
+    let schedule = &mut ctx.accounts.buyback_schedule;
+    let clock = &ctx.accounts.clock;
+    schedule.check_tranche(args.tranche, schedule.lp_executed, clock.unix_timestamp)?;
+    let amount_in = schedule.tranche_amount(schedule.lp_budget, args.tranche);
+    schedule.lp_executed |= 1 << args.tranche;
+    let exploration_key = schedule.exploration;
+
+    let swap_amount = amount_in / 2;
+    let pair_amount = amount_in - swap_amount;
+    require!(swap_amount > 0, RigItError::InvalidSwapAmount);
+    require!(
+        ctx.accounts.treasury_buyback_lp.amount >= amount_in,
+        RigItError::InsufficientTreasuryBalance
+    );
+    let split = args.swap_account_count as usize;
+    require!(
+        split <= ctx.remaining_accounts.len(),
+        RigItError::InvalidAdapterAccounts
+    );
+    check_pool_accounts(ctx.remaining_accounts, &ctx.accounts.treasury_authority.key())?;
+    let (swap_pool_accounts, liquidity_pool_accounts) = ctx.remaining_accounts.split_at(split);
+
+    let lp_lock = &mut ctx.accounts.lp_lock;
+    if lp_lock.lp_mint == Pubkey::default() {
+        lp_lock.block_id = ctx.accounts.block_state.block_id;
+        lp_lock.lp_mint = ctx.accounts.lp_mint.key();
+        lp_lock.vault = ctx.accounts.lp_lock_vault.key();
+        lp_lock.bump = ctx.bumps.lp_lock;
+    }
+    require_keys_eq!(
+        lp_lock.lp_mint,
+        ctx.accounts.lp_mint.key(),
+        RigItError::LpMintMismatch
+    );
+
+    let block_id = ctx.accounts.block_state.block_id;
+    let seeds = &[
+        TREASURY_AUTHORITY_SEED,
+        &[block_id],
+        &[ctx.bumps.treasury_authority],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let amm_program = ctx.accounts.amm_program.key();
+    let fixed_infos = [
+        ctx.accounts.treasury_buyback_lp.to_account_info(),
+        ctx.accounts.rig_buyback_vault.to_account_info(),
+        ctx.accounts.lp_lock_vault.to_account_info(),
+        ctx.accounts.treasury_authority.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.amm_program.to_account_info(),
+    ];
+
+    // Buy $RIG with half of the amount
+    let treasury_before = ctx.accounts.treasury_buyback_lp.amount;
+    let rig_before = ctx.accounts.rig_buyback_vault.amount;
+    let ix = swap_instruction(
+        amm_program,
+        &AdapterSwapAccounts {
+            source: ctx.accounts.treasury_buyback_lp.key(),
+            destination: ctx.accounts.rig_buyback_vault.key(),
+            authority: ctx.accounts.treasury_authority.key(),
+            token_program: ctx.accounts.token_program.key(),
+        },
+        swap_pool_accounts,
+        swap_amount,
+        args.min_rig_out,
+    );
+    let mut account_infos = fixed_infos.to_vec();
+    account_infos.extend(swap_pool_accounts.iter().cloned());
+    invoke_signed(&ix, &account_infos, signer_seeds)?;
+
+    ctx.accounts.treasury_buyback_lp.reload()?;
+    ctx.accounts.rig_buyback_vault.reload()?;
+    let swap_spent = adapter_spent(
+        treasury_before,
+        ctx.accounts.treasury_buyback_lp.amount,
+        swap_amount,
+    )?;
+    require!(swap_spent == swap_amount, RigItError::AdapterPartialFill);
+    let rig_bought = ctx
+        .accounts
+        .rig_buyback_vault
+        .amount
+        .checked_sub(rig_before)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+    require!(rig_bought >= args.min_rig_out, RigItError::SlippageExceeded);
+
+    // Pair the other half with all $RIG on hand, minting LP into the lock
+    let asset_before = ctx.accounts.treasury_buyback_lp.amount;
+    let rig_offered = ctx.accounts.rig_buyback_vault.amount;
+    let lp_before = ctx.accounts.lp_lock_vault.amount;
+    let ix = add_liquidity_instruction(
+        amm_program,
+        &AdapterLiquidityAccounts {
+            source_a: ctx.accounts.treasury_buyback_lp.key(),
+            source_b: ctx.accounts.rig_buyback_vault.key(),
+            lp_destination: ctx.accounts.lp_lock_vault.key(),
+            authority: ctx.accounts.treasury_authority.key(),
+            token_program: ctx.accounts.token_program.key(),
+        },
+        liquidity_pool_accounts,
+        pair_amount,
+        rig_offered,
+        args.min_lp_out,
+    );
+    let mut account_infos = fixed_infos.to_vec();
+    account_infos.extend(liquidity_pool_accounts.iter().cloned());
+    invoke_signed(&ix, &account_infos, signer_seeds)?;
+
+    ctx.accounts.treasury_buyback_lp.reload()?;
+    ctx.accounts.rig_buyback_vault.reload()?;
+    ctx.accounts.lp_lock_vault.reload()?;
+    let asset_paired = adapter_spent(
+        asset_before,
+        ctx.accounts.treasury_buyback_lp.amount,
+        pair_amount,
+    )?;
+    let rig_paired = rig_offered
+        .checked_sub(ctx.accounts.rig_buyback_vault.amount)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+    let lp_locked = ctx
+        .accounts
+        .lp_lock_vault
+        .amount
+        .checked_sub(lp_before)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+    require!(lp_locked >= args.min_lp_out, RigItError::SlippageExceeded);
+
+    let lp_lock = &mut ctx.accounts.lp_lock;
+    lp_lock.total_lp_locked = lp_lock
+        .total_lp_locked
+        .checked_add(lp_locked)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+    lp_lock.total_asset_paired = lp_lock
+        .total_asset_paired
+        .checked_add(asset_paired)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+    lp_lock.total_rig_paired = lp_lock
+        .total_rig_paired
+        .checked_add(rig_paired)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+    lp_lock.lock_count = lp_lock.lock_count.saturating_add(1);
+
+    emit!(BuybackLpLocked {
+        block_id,
+        exploration_key,
+        tranche: args.tranche,
+        amm_program,
+        swap_amount_in: swap_spent,
+        rig_bought,
+        asset_paired,
+        rig_paired,
+        lp_locked,
+        total_lp_locked: lp_lock.total_lp_locked,
+    });
+
+    msg!(
+        "Buyback LP for block {} tranche {}: {} asset + {} $RIG paired, {} LP locked",
+        block_id,
+        args.tranche,
+        asset_paired,
+        rig_paired,
+        lp_locked
+    );
+
+    Ok(())
+}
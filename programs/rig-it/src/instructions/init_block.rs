@@ -1,13 +1,20 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
-use crate::state::{BlockState, ProtocolConfig};
+use crate::state::{
+    BlockState, ProtocolConfig, DEFAULT_RIGS_PER_EXPLORATION, MAX_PRIZE_TIERS,
+    MAX_RIGS_PER_EXPLORATION, MIN_RIGS_PER_EXPLORATION, SINGLE_WINNER_PRIZE_TIERS,
+};
 use crate::errors::RigItError;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitBlockArgs {
     pub block_id: u8,
     pub min_threshold: u64,
+    /// Rigs per exploration (defaults to 36)
+    pub rig_count: Option<u8>,
+    /// Winner pool split across prize tiers in bps (defaults to a single winner)
+    pub prize_tier_bps: Option<[u16; MAX_PRIZE_TIERS]>,
 }
 
 #[derive(Accounts)]
@@ -60,21 +67,36 @@ pub struct InitBlock<'info> {
 pub fn handler(ctx: Context<InitBlock>, args: InitBlockArgs) -> Result<()> {
     require!(args.block_id <= 2, RigItError::InvalidBlockId);
 
+    let rig_count = args.rig_count.unwrap_or(DEFAULT_RIGS_PER_EXPLORATION);
+    require!(
+        (MIN_RIGS_PER_EXPLORATION..=MAX_RIGS_PER_EXPLORATION).contains(&rig_count),
+        RigItError::InvalidRigCount
+    );
+
+    let prize_tier_bps = args.prize_tier_bps.unwrap_or(SINGLE_WINNER_PRIZE_TIERS);
+    require!(
+        BlockState::validate_prize_tiers(&prize_tier_bps),
+        RigItError::InvalidPrizeTiers
+    );
+
     let block = &mut ctx.accounts.block_state;
 
     block.block_id = args.block_id;
     block.asset_mint = ctx.accounts.asset_mint.key();
     block.asset_decimals = ctx.accounts.asset_mint.decimals;
     block.min_threshold = args.min_threshold;
-    block.current_exploration_index = 0;
+    block.rig_count = rig_count;
+    block.track_count = 0;
     block.paused = false;
     block.total_volume = 0;
     block.total_explorations_completed = 0;
     block.bump = ctx.bumps.block_state;
+    block.prize_tier_bps = prize_tier_bps;
 
     msg!("Block {} initialized", args.block_id);
     msg!("Asset mint: {}", block.asset_mint);
     msg!("Min threshold: {}", block.min_threshold);
+    msg!("Rigs per exploration: {}", block.rig_count);
 
     Ok(())
 }
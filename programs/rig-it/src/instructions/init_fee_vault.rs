@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::{
+    treasury_buckets, BlockState, ProtocolConfig, TREASURY_AUTHORITY_SEED, TREASURY_SEED,
+};
+use crate::errors::RigItError;
+use crate::events::FeeVaultInitialized;
+
+#[derive(Accounts)]
+pub struct InitFeeVault<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ RigItError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [BlockState::SEED, &[block_state.block_id]],
+        bump = block_state.bump,
+    )]
+    pub block_state: Account<'info, BlockState>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = asset_mint,
+        token::authority = treasury_authority,
+        seeds = [TREASURY_SEED, treasury_buckets::DEPOSIT_FEES, &[block_state.block_id]],
+        bump
+    )]
+    pub treasury_deposit_fees: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA authority for the block's treasury vaults
+    #[account(
+        seeds = [TREASURY_AUTHORITY_SEED, &[block_state.block_id]],
+        bump
+    )]
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(address = block_state.asset_mint)]
+    pub asset_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitFeeVault>) -> Result<()> {
+    let block = &ctx.accounts.block_state;
+
+    emit!(FeeVaultInitialized {
+        block_id: block.block_id,
+        asset_mint: block.asset_mint,
+        fee_vault: ctx.accounts.treasury_deposit_fees.key(),
+    });
+
+    msg!(
+        "Deposit fee vault initialized for block {} (mint {})",
+        block.block_id,
+        block.asset_mint
+    );
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::{BlockState, ProtocolConfig, JACKPOT_VAULT_SEED};
+use crate::errors::RigItError;
+use crate::events::JackpotInitialized;
+
+#[derive(Accounts)]
+pub struct InitJackpot<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ RigItError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [BlockState::SEED, &[block_state.block_id]],
+        bump = block_state.bump,
+    )]
+    pub block_state: Account<'info, BlockState>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = asset_mint,
+        token::authority = block_vault_authority,
+        seeds = [JACKPOT_VAULT_SEED, &[block_state.block_id]],
+        bump
+    )]
+    pub jackpot_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA authority for vault
+    #[account(
+        seeds = [b"block_vault_authority", &[block_state.block_id]],
+        bump
+    )]
+    pub block_vault_authority: UncheckedAccount<'info>,
+
+    #[account(address = block_state.asset_mint)]
+    pub asset_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitJackpot>) -> Result<()> {
+    let block = &ctx.accounts.block_state;
+
+    emit!(JackpotInitialized {
+        block_id: block.block_id,
+        asset_mint: block.asset_mint,
+        jackpot_vault: ctx.accounts.jackpot_vault.key(),
+    });
+
+    msg!(
+        "Jackpot vault initialized for block {} (mint {})",
+        block.block_id,
+        block.asset_mint
+    );
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{BlockState, ProtocolConfig, TrackState, MAX_TRACKS_PER_BLOCK};
+use crate::errors::RigItError;
+use crate::events::TrackInitialized;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitTrackArgs {
+    pub track_id: u8,
+    /// Scheduled start of exploration 0 (defaults to now)
+    pub schedule_anchor_ts: Option<i64>,
+    /// Timing overrides (default to the protocol configuration)
+    pub active_duration_secs: Option<u32>,
+    pub cooldown_duration_secs: Option<u32>,
+    pub anti_snipe_window_secs: Option<u32>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitTrackArgs)]
+pub struct InitTrack<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ RigItError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [BlockState::SEED, &[block_state.block_id]],
+        bump = block_state.bump,
+    )]
+    pub block_state: Account<'info, BlockState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = TrackState::LEN,
+        seeds = [TrackState::SEED, &[block_state.block_id], &[args.track_id]],
+        bump
+    )]
+    pub track_state: Account<'info, TrackState>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<InitTrack>, args: InitTrackArgs) -> Result<()> {
+    let config = &ctx.accounts.protocol_config;
+    let block = &mut ctx.accounts.block_state;
+    let track = &mut ctx.accounts.track_state;
+
+    // Tracks are numbered sequentially within a block
+    require!(
+        args.track_id == block.track_count && args.track_id < MAX_TRACKS_PER_BLOCK,
+        RigItError::InvalidTrackId
+    );
+
+    track.block_id = block.block_id;
+    track.track_id = args.track_id;
+    track.current_exploration_index = 0;
+    track.schedule_anchor_ts = args
+        .schedule_anchor_ts
+        .unwrap_or(ctx.accounts.clock.unix_timestamp);
+    track.schedule_anchor_index = 0;
+    track.active_duration_secs = args
+        .active_duration_secs
+        .unwrap_or(config.active_duration_secs);
+    track.cooldown_duration_secs = args
+        .cooldown_duration_secs
+        .unwrap_or(config.cooldown_duration_secs);
+    track.anti_snipe_window_secs = args
+        .anti_snipe_window_secs
+        .unwrap_or(config.anti_snipe_window_secs);
+    track.outstanding_liabilities = 0;
+    track.total_volume = 0;
+    track.total_explorations_completed = 0;
+    track.bump = ctx.bumps.track_state;
+
+    require!(track.validate_timing(), RigItError::InvalidSchedule);
+
+    block.track_count = block
+        .track_count
+        .checked_add(1)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+
+    emit!(TrackInitialized {
+        block_id: track.block_id,
+        track_id: track.track_id,
+        schedule_anchor_ts: track.schedule_anchor_ts,
+        active_duration_secs: track.active_duration_secs,
+        cooldown_duration_secs: track.cooldown_duration_secs,
+        anti_snipe_window_secs: track.anti_snipe_window_secs,
+    });
+
+    msg!(
+        "Track {} initialized for Block {} (period {}s)",
+        track.track_id,
+        track.block_id,
+        track.schedule_period_secs()
+    );
+
+    Ok(())
+}
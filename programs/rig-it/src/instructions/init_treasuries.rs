@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::{
+    treasury_buckets, BlockState, ProtocolConfig, TREASURY_AUTHORITY_SEED, TREASURY_SEED,
+};
+use crate::errors::RigItError;
+use crate::events::TreasuriesInitialized;
+
+#[derive(Accounts)]
+pub struct InitTreasuries<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ RigItError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [BlockState::SEED, &[block_state.block_id]],
+        bump = block_state.bump,
+    )]
+    pub block_state: Account<'info, BlockState>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = asset_mint,
+        token::authority = treasury_authority,
+        seeds = [TREASURY_SEED, treasury_buckets::BUYBACK_BURN, &[block_state.block_id]],
+        bump
+    )]
+    pub treasury_buyback_burn: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = asset_mint,
+        token::authority = treasury_authority,
+        seeds = [TREASURY_SEED, treasury_buckets::BUYBACK_LP, &[block_state.block_id]],
+        bump
+    )]
+    pub treasury_buyback_lp: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = asset_mint,
+        token::authority = treasury_authority,
+        seeds = [TREASURY_SEED, treasury_buckets::TEAM_OPS, &[block_state.block_id]],
+        bump
+    )]
+    pub treasury_team_ops: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = asset_mint,
+        token::authority = treasury_authority,
+        seeds = [TREASURY_SEED, treasury_buckets::ECOSYSTEM, &[block_state.block_id]],
+        bump
+    )]
+    pub treasury_ecosystem: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA authority for the block's treasury vaults
+    #[account(
+        seeds = [TREASURY_AUTHORITY_SEED, &[block_state.block_id]],
+        bump
+    )]
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(address = block_state.asset_mint)]
+    pub asset_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitTreasuries>) -> Result<()> {
+    let block = &ctx.accounts.block_state;
+
+    emit!(TreasuriesInitialized {
+        block_id: block.block_id,
+        asset_mint: block.asset_mint,
+        treasury_authority: ctx.accounts.treasury_authority.key(),
+        buyback_burn: ctx.accounts.treasury_buyback_burn.key(),
+        buyback_lp: ctx.accounts.treasury_buyback_lp.key(),
+        team_ops: ctx.accounts.treasury_team_ops.key(),
+        ecosystem: ctx.accounts.treasury_ecosystem.key(),
+    });
+
+    msg!(
+        "Treasuries initialized for block {} (mint {})",
+        block.block_id,
+        block.asset_mint
+    );
+
+    Ok(())
+}
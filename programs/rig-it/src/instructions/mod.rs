@@ -1,25 +1,57 @@
+// Every instruction module exposes a `handler`; lib.rs calls them by path and the
+// globs below only re-export the account and args types Anchor needs
+#![allow(ambiguous_glob_reexports)]
+
 pub mod init_protocol;
 pub mod init_block;
+pub mod init_track;
+pub mod init_treasuries;
+pub mod init_jackpot;
+pub mod init_fee_vault;
 pub mod start_exploration;
 pub mod deposit_to_rig;
 pub mod commit_randomness;
 pub mod reveal_randomness;
+pub mod post_operator_bond;
+pub mod withdraw_operator_bond;
 pub mod refund_loser;
 pub mod claim_winnings;
+pub mod register_referrer;
+pub mod claim_referral_reward;
 pub mod allocate_buyback;
+pub mod execute_buyback_burn;
+pub mod execute_buyback_lp;
 pub mod carry_forward;
+pub mod sweep_dust;
+pub mod withdraw_treasury;
 pub mod set_params;
+pub mod set_block_params;
+pub mod set_track_schedule;
 pub mod emergency_pause;
 
 pub use init_protocol::*;
 pub use init_block::*;
+pub use init_track::*;
+pub use init_treasuries::*;
+pub use init_jackpot::*;
+pub use init_fee_vault::*;
 pub use start_exploration::*;
 pub use deposit_to_rig::*;
 pub use commit_randomness::*;
 pub use reveal_randomness::*;
+pub use post_operator_bond::*;
+pub use withdraw_operator_bond::*;
 pub use refund_loser::*;
 pub use claim_winnings::*;
+pub use register_referrer::*;
+pub use claim_referral_reward::*;
 pub use allocate_buyback::*;
+pub use execute_buyback_burn::*;
+pub use execute_buyback_lp::*;
 pub use carry_forward::*;
+pub use sweep_dust::*;
+pub use withdraw_treasury::*;
 pub use set_params::*;
+pub use set_block_params::*;
+pub use set_track_schedule::*;
 pub use emergency_pause::*;
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::{BlockState, OperatorBond, ProtocolConfig};
+use crate::errors::RigItError;
+use crate::events::OperatorBondPosted;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PostOperatorBondArgs {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct PostOperatorBond<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [BlockState::SEED, &[block_state.block_id]],
+        bump = block_state.bump,
+    )]
+    pub block_state: Account<'info, BlockState>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = OperatorBond::LEN,
+        seeds = [OperatorBond::SEED, &[block_state.block_id]],
+        bump
+    )]
+    pub operator_bond: Account<'info, OperatorBond>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        token::mint = asset_mint,
+        token::authority = block_vault_authority,
+        seeds = [OperatorBond::VAULT_SEED, &[block_state.block_id]],
+        bump
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority for vault
+    #[account(
+        seeds = [b"block_vault_authority", &[block_state.block_id]],
+        bump
+    )]
+    pub block_vault_authority: UncheckedAccount<'info>,
+
+    #[account(address = block_state.asset_mint)]
+    pub asset_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = operator.key() == protocol_config.operator @ RigItError::Unauthorized
+    )]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = operator_token_account.owner == operator.key(),
+        constraint = operator_token_account.mint == block_state.asset_mint,
+    )]
+    pub operator_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<PostOperatorBond>, args: PostOperatorBondArgs) -> Result<()> {
+    let bond = &mut ctx.accounts.operator_bond;
+    let operator = ctx.accounts.operator.key();
+
+    // A bond left behind by a previous operator must be withdrawn first
+    if bond.operator == Pubkey::default() || bond.amount == 0 {
+        bond.block_id = ctx.accounts.block_state.block_id;
+        bond.operator = operator;
+        bond.bump = ctx.bumps.operator_bond;
+    }
+    require!(bond.operator == operator, RigItError::Unauthorized);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.operator_token_account.to_account_info(),
+        to: ctx.accounts.bond_vault.to_account_info(),
+        authority: ctx.accounts.operator.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, args.amount)?;
+
+    bond.amount = bond
+        .amount
+        .checked_add(args.amount)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+
+    emit!(OperatorBondPosted {
+        block_id: bond.block_id,
+        operator,
+        amount: args.amount,
+        total_bond: bond.amount,
+    });
+
+    msg!(
+        "Operator bond for Block {} increased by {} to {}",
+        bond.block_id,
+        args.amount,
+        bond.amount
+    );
+
+    Ok(())
+}
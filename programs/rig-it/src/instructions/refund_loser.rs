@@ -1,16 +1,24 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
-use crate::state::{DepositReceipt, ExplorationState, ExplorationStatus, RigState};
+use crate::state::{DepositReceipt, ExplorationState, ExplorationStatus, RigState, TrackState};
 use crate::errors::RigItError;
 use crate::events::LoserRefunded;
 
 #[derive(Accounts)]
 pub struct RefundLoser<'info> {
     #[account(
-        constraint = exploration_state.status == ExplorationStatus::Settled @ RigItError::ExplorationNotSettled,
+        mut,
+        constraint = exploration_state.load()?.status() == ExplorationStatus::Settled @ RigItError::ExplorationNotSettled,
+    )]
+    pub exploration_state: AccountLoader<'info, ExplorationState>,
+
+    #[account(
+        mut,
+        seeds = [TrackState::SEED, &[exploration_state.load()?.block_id], &[exploration_state.load()?.track_id]],
+        bump = track_state.bump,
     )]
-    pub exploration_state: Box<Account<'info, ExplorationState>>,
+    pub track_state: Account<'info, TrackState>,
 
     #[account(
         constraint = rig_state.exploration == exploration_state.key(),
@@ -37,14 +45,14 @@ pub struct RefundLoser<'info> {
 
     #[account(
         mut,
-        seeds = [b"block_vault", &[exploration_state.block_id]],
+        seeds = [b"block_vault", &[exploration_state.load()?.block_id]],
         bump,
     )]
     pub block_vault: Box<Account<'info, TokenAccount>>,
 
     /// CHECK: PDA authority for vault
     #[account(
-        seeds = [b"block_vault_authority", &[exploration_state.block_id]],
+        seeds = [b"block_vault_authority", &[exploration_state.load()?.block_id]],
         bump
     )]
     pub block_vault_authority: UncheckedAccount<'info>,
@@ -53,16 +61,29 @@ pub struct RefundLoser<'info> {
 }
 
 pub fn handler(ctx: Context<RefundLoser>) -> Result<()> {
-    let exploration = &ctx.accounts.exploration_state;
+    let exploration_key = ctx.accounts.exploration_state.key();
+    let (mut exploration, rigs) =
+        ExplorationState::load_with_rigs_mut(&ctx.accounts.exploration_state)?;
     let rig = &ctx.accounts.rig_state;
     let receipt = &mut ctx.accounts.deposit_receipt;
 
-    // Verify this rig did NOT win
-    let winning_rig = exploration.winning_rig.ok_or(RigItError::ExplorationNotSettled)?;
-    require!(rig.rig_index != winning_rig, RigItError::NotALoser);
-
-    // Calculate refund: 50% of original deposit
-    let refund_amount = receipt.amount / 2;
+    // Verify this rig did NOT win any prize tier
+    require!(exploration.winning_rig().is_some(), RigItError::ExplorationNotSettled);
+    require!(exploration.prize_tier(rig.rig_index).is_none(), RigItError::NotALoser);
+
+    // Calculate refund: the exploration's loser refund share of the deposit,
+    // plus any consolation bonus
+    let hits = exploration.consolation_hits(
+        &rigs,
+        rig.rig_index,
+        receipt.ticket_offset,
+        receipt.effective_tickets,
+    );
+    let consolation_bonus = exploration.consolation_bonus(hits);
+    let refund_amount = exploration
+        .loser_refund(receipt.amount)
+        .checked_add(consolation_bonus)
+        .ok_or(RigItError::ArithmeticOverflow)?;
 
     // Transfer from vault to user
     let block_id = exploration.block_id;
@@ -82,22 +103,37 @@ pub fn handler(ctx: Context<RefundLoser>) -> Result<()> {
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
     token::transfer(cpi_ctx, refund_amount)?;
 
+    // Refund leaves the vault
+    let track = &mut ctx.accounts.track_state;
+    track.outstanding_liabilities = track
+        .outstanding_liabilities
+        .checked_sub(refund_amount)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+
+    // Paid and entitled amounts are reconciled when dust is swept
+    exploration.record_payout(refund_amount)?;
+    exploration.close_receipt()?;
+
     // Mark as claimed
     receipt.refund_claimed = true;
 
     emit!(LoserRefunded {
         user: receipt.user,
-        exploration_key: exploration.key(),
+        exploration_key,
         deposit_receipt: receipt.key(),
         original_amount: receipt.amount,
         refund_amount,
+        consolation_bonus,
+        loser_refund_bps: exploration.loser_refund_bps,
     });
 
     msg!(
-        "Refunded {} to user {} (50% of {} deposit)",
+        "Refunded {} to user {} ({} bps of {} deposit, consolation bonus {})",
         refund_amount,
         receipt.user,
-        receipt.amount
+        exploration.loser_refund_bps,
+        receipt.amount,
+        consolation_bonus
     );
 
     Ok(())
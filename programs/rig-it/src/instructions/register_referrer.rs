@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ProtocolConfig, ReferralCode, Referrer, UserStats, REFERRAL_CODE_LEN};
+use crate::errors::RigItError;
+use crate::events::ReferrerRegistered;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RegisterReferrerArgs {
+    /// Referral code: 1-16 lowercase letters, digits, '-' or '_', zero-padded
+    pub code: [u8; REFERRAL_CODE_LEN],
+}
+
+#[derive(Accounts)]
+#[instruction(args: RegisterReferrerArgs)]
+pub struct RegisterReferrer<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        constraint = !protocol_config.paused @ RigItError::ProtocolPaused,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = Referrer::LEN,
+        seeds = [Referrer::SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub referrer: Account<'info, Referrer>,
+
+    /// Fails to initialize if another referrer already holds the code
+    #[account(
+        init,
+        payer = owner,
+        space = ReferralCode::LEN,
+        seeds = [ReferralCode::SEED, &args.code],
+        bump
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+
+    /// Referrer's stats, where referred volume and rewards are tracked
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = UserStats::LEN,
+        seeds = [UserStats::SEED, owner.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<RegisterReferrer>, args: RegisterReferrerArgs) -> Result<()> {
+    require!(
+        Referrer::validate_code(&args.code),
+        RigItError::InvalidReferralCode
+    );
+    let owner = ctx.accounts.owner.key();
+
+    let referrer = &mut ctx.accounts.referrer;
+    referrer.owner = owner;
+    referrer.code = args.code;
+    referrer.registered_at = ctx.accounts.clock.unix_timestamp;
+    referrer.bump = ctx.bumps.referrer;
+
+    let referral_code = &mut ctx.accounts.referral_code;
+    referral_code.owner = owner;
+    referral_code.bump = ctx.bumps.referral_code;
+
+    let stats = &mut ctx.accounts.user_stats;
+    if stats.user == Pubkey::default() {
+        stats.user = owner;
+        stats.bump = ctx.bumps.user_stats;
+    }
+
+    emit!(ReferrerRegistered {
+        owner,
+        referrer: referrer.key(),
+        code: args.code,
+    });
+
+    msg!("Referrer {} registered", owner);
+
+    Ok(())
+}
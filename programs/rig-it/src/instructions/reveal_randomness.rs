@@ -1,10 +1,18 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
-use crate::state::{BlockState, ExplorationState, ExplorationStatus, ProtocolConfig};
+use crate::state::{
+    rig_tickets, BlockState, BuybackSchedule, DrawRecord, ExplorationState, ExplorationStatus, OperatorBond,
+    ProtocolConfig, RigTotals, TrackState, FALLBACK_RECOMMIT_DELAY_SLOTS, JACKPOT_VAULT_SEED,
+};
 use crate::errors::RigItError;
-use crate::events::{ExplorationSettled, RandomnessTimeoutFallback};
+use crate::events::{
+    BuybackScheduled, ConsolationFunded, ExplorationSettled, FallbackSlotRecommitted, JackpotFunded, JackpotHit,
+    OperatorSlashed, RandomnessTimeoutFallback,
+};
 use crate::utils::{
-    generate_random_value, get_slot_hash, select_winning_rig, verify_reveal,
+    generate_fallback_random_value, generate_random_value, get_slot_hash, hash_ticket_snapshot,
+    select_prize_rigs, verify_reveal, DRAW_ALGORITHM_VERSION,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -27,19 +35,78 @@ pub struct RevealRandomness<'info> {
     )]
     pub block_state: Account<'info, BlockState>,
 
+    #[account(
+        mut,
+        seeds = [TrackState::SEED, &[block_state.block_id], &[exploration_state.load()?.track_id]],
+        bump = track_state.bump,
+    )]
+    pub track_state: Account<'info, TrackState>,
+
     #[account(
         mut,
         seeds = [
             ExplorationState::SEED,
-            &[exploration_state.block_id],
-            &exploration_state.exploration_index.to_le_bytes()
+            &[block_state.block_id],
+            &[exploration_state.load()?.track_id],
+            &exploration_state.load()?.exploration_index.to_le_bytes()
         ],
-        bump = exploration_state.bump,
-        constraint = exploration_state.status == ExplorationStatus::Finalizing @ RigItError::ExplorationNotFinalizing,
+        bump = exploration_state.load()?.bump,
+        constraint = exploration_state.load()?.status() == ExplorationStatus::Finalizing @ RigItError::ExplorationNotFinalizing,
+    )]
+    pub exploration_state: AccountLoader<'info, ExplorationState>,
+
+    /// Operator bond for the block, required if the commit locked a stake
+    #[account(
+        mut,
+        seeds = [OperatorBond::SEED, &[block_state.block_id]],
+        bump = operator_bond.bump,
+    )]
+    pub operator_bond: Option<Account<'info, OperatorBond>>,
+
+    #[account(
+        mut,
+        seeds = [b"block_vault", &[block_state.block_id]],
+        bump,
+    )]
+    pub block_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA authority for vault
+    #[account(
+        seeds = [b"block_vault_authority", &[block_state.block_id]],
+        bump
+    )]
+    pub block_vault_authority: UncheckedAccount<'info>,
+
+    /// Jackpot vault for the block, required if the exploration funds the
+    /// jackpot or has a chance to hit it
+    #[account(
+        mut,
+        seeds = [JACKPOT_VAULT_SEED, &[block_state.block_id]],
+        bump,
+    )]
+    pub jackpot_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = DrawRecord::LEN,
+        seeds = [DrawRecord::SEED, exploration_state.key().as_ref()],
+        bump
+    )]
+    pub draw_record: Box<Account<'info, DrawRecord>>,
+
+    /// Buyback window and tranches, derived from the revealed random value
+    #[account(
+        init,
+        payer = operator,
+        space = BuybackSchedule::LEN,
+        seeds = [BuybackSchedule::SEED, exploration_state.key().as_ref()],
+        bump
     )]
-    pub exploration_state: Box<Account<'info, ExplorationState>>,
+    pub buyback_schedule: Box<Account<'info, BuybackSchedule>>,
 
     #[account(
+        mut,
         constraint = operator.key() == protocol_config.operator @ RigItError::Unauthorized
     )]
     pub operator: Signer<'info>,
@@ -48,12 +115,17 @@ pub struct RevealRandomness<'info> {
     #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::id())]
     pub slot_hashes: UncheckedAccount<'info>,
 
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
 }
 
 pub fn handler(ctx: Context<RevealRandomness>, args: RevealRandomnessArgs) -> Result<()> {
     let block = &mut ctx.accounts.block_state;
-    let exploration = &mut ctx.accounts.exploration_state;
+    let track = &mut ctx.accounts.track_state;
+    let exploration_key = ctx.accounts.exploration_state.key();
+    let (mut exploration, rigs) =
+        ExplorationState::load_with_rigs_mut(&ctx.accounts.exploration_state)?;
     let clock = &ctx.accounts.clock;
     let slot_hashes_data = ctx.accounts.slot_hashes.try_borrow_data()?;
 
@@ -69,72 +141,79 @@ pub fn handler(ctx: Context<RevealRandomness>, args: RevealRandomnessArgs) -> Re
 
     // Verify the reveal
     require!(
-        verify_reveal(&args.secret, exploration.commit_slot, &exploration.commit_hash),
+        verify_reveal(
+            exploration.commit_scheme,
+            &args.secret,
+            exploration.commit_slot,
+            &exploration.commit_context(),
+            &exploration.commit_hash,
+        ),
         RigItError::InvalidRevealSecret
     );
 
+    // Ticket distribution must be exactly what was committed to
+    exploration.verify_commit_snapshot(&rigs)?;
+
     // Get slot hash for the commit slot
     let slot_hash = get_slot_hash(&slot_hashes_data, exploration.commit_slot)
         .ok_or(RigItError::SlotHashNotAvailable)?;
 
-    // Generate final random value
-    let random_value = generate_random_value(&args.secret, &slot_hash);
-    exploration.revealed_random = Some(random_value);
-
-    // Select winning rig
-    let winning_rig = select_winning_rig(&random_value, &exploration.rig_tickets)
-        .ok_or(RigItError::ThresholdNotMet)?;
-
-    exploration.winning_rig = Some(winning_rig);
-
-    // Calculate W, L, R
-    let w = exploration.rig_deposits[winning_rig as usize]
-        .checked_add(exploration.rollover_amount)
-        .ok_or(RigItError::ArithmeticOverflow)?;
-    
-    let l = exploration
-        .total_deposits
-        .checked_sub(exploration.rig_deposits[winning_rig as usize])
-        .ok_or(RigItError::ArithmeticOverflow)?;
-    
-    // R = W + L/2
-    let r = w
-        .checked_add(l / 2)
-        .ok_or(RigItError::ArithmeticOverflow)?;
-
-    exploration.total_winner_deposits = w;
-    exploration.total_loser_deposits = l;
-    exploration.remaining_pool = r;
-    exploration.status = ExplorationStatus::Settled;
-
-    // Update block stats
-    block.total_volume = block
-        .total_volume
-        .checked_add(exploration.total_deposits as u128)
-        .ok_or(RigItError::ArithmeticOverflow)?;
-    block.total_explorations_completed = block
-        .total_explorations_completed
-        .checked_add(1)
-        .ok_or(RigItError::ArithmeticOverflow)?;
-
-    emit!(ExplorationSettled {
-        block_id: exploration.block_id,
-        exploration_index: exploration.exploration_index,
-        exploration_key: exploration.key(),
-        winning_rig,
-        total_pool: exploration.total_pool(),
-        winner_deposits: w,
-        loser_deposits: l,
-        remaining_pool: r,
-    });
+    // Generate final random value from operator secret, depositor entropy and slot hash
+    let random_value = generate_random_value(
+        &args.secret,
+        &exploration.entropy_accumulator,
+        &slot_hash,
+    );
+    exploration.set_revealed_random(random_value);
+
+    // Revealed in time - release the operator's stake
+    let stake = exploration.operator_bond_at_stake;
+    if stake > 0 {
+        let bond = ctx
+            .accounts
+            .operator_bond
+            .as_mut()
+            .ok_or(RigItError::OperatorBondRequired)?;
+        bond.locked_amount = bond.locked_amount.saturating_sub(stake);
+    }
+
+    finalize_settlement(
+        &mut exploration,
+        &rigs,
+        exploration_key,
+        &random_value,
+        DrawInputs {
+            used_fallback: false,
+            operator_secret: args.secret,
+            fallback_slot: 0,
+            slot_hash,
+            drawn_at: clock.unix_timestamp,
+        },
+        SettlementAccounts {
+            ecosystem_bps: ctx.accounts.protocol_config.ecosystem_bps,
+            block,
+            track,
+            draw_record: &mut ctx.accounts.draw_record,
+            draw_record_bump: ctx.bumps.draw_record,
+            buyback_schedule: &mut ctx.accounts.buyback_schedule,
+            buyback_schedule_bump: ctx.bumps.buyback_schedule,
+            jackpot_vaults: JackpotVaults {
+                block_vault: ctx.accounts.block_vault.to_account_info(),
+                jackpot_vault: ctx.accounts.jackpot_vault.as_mut(),
+                block_vault_authority: ctx.accounts.block_vault_authority.to_account_info(),
+                block_vault_authority_bump: ctx.bumps.block_vault_authority,
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        },
+    )?;
 
     msg!(
         "Exploration {} settled. Winning rig: {}, W={}, L={}, R={}",
         exploration.exploration_index,
-        winning_rig,
-        w,
-        l,
-        r
+        exploration.winning_rig,
+        exploration.total_winner_deposits,
+        exploration.total_loser_deposits,
+        exploration.remaining_pool
     );
 
     Ok(())
@@ -157,31 +236,102 @@ pub struct RevealTimeoutFallback<'info> {
     )]
     pub block_state: Account<'info, BlockState>,
 
+    #[account(
+        mut,
+        seeds = [TrackState::SEED, &[block_state.block_id], &[exploration_state.load()?.track_id]],
+        bump = track_state.bump,
+    )]
+    pub track_state: Account<'info, TrackState>,
+
     #[account(
         mut,
         seeds = [
             ExplorationState::SEED,
-            &[exploration_state.block_id],
-            &exploration_state.exploration_index.to_le_bytes()
+            &[block_state.block_id],
+            &[exploration_state.load()?.track_id],
+            &exploration_state.load()?.exploration_index.to_le_bytes()
         ],
-        bump = exploration_state.bump,
-        constraint = exploration_state.status == ExplorationStatus::Finalizing @ RigItError::ExplorationNotFinalizing,
+        bump = exploration_state.load()?.bump,
+        constraint = exploration_state.load()?.status() == ExplorationStatus::Finalizing @ RigItError::ExplorationNotFinalizing,
+    )]
+    pub exploration_state: AccountLoader<'info, ExplorationState>,
+
+    /// Operator bond for the block, required if the commit locked a stake
+    #[account(
+        mut,
+        seeds = [OperatorBond::SEED, &[block_state.block_id]],
+        bump = operator_bond.bump,
+    )]
+    pub operator_bond: Option<Account<'info, OperatorBond>>,
+
+    #[account(
+        mut,
+        seeds = [OperatorBond::VAULT_SEED, &[block_state.block_id]],
+        bump,
+    )]
+    pub bond_vault: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"block_vault", &[block_state.block_id]],
+        bump,
+    )]
+    pub block_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority for vault
+    #[account(
+        seeds = [b"block_vault_authority", &[block_state.block_id]],
+        bump
+    )]
+    pub block_vault_authority: UncheckedAccount<'info>,
+
+    /// Jackpot vault for the block, required if the exploration funds the
+    /// jackpot or has a chance to hit it
+    #[account(
+        mut,
+        seeds = [JACKPOT_VAULT_SEED, &[block_state.block_id]],
+        bump,
+    )]
+    pub jackpot_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        init,
+        payer = caller,
+        space = DrawRecord::LEN,
+        seeds = [DrawRecord::SEED, exploration_state.key().as_ref()],
+        bump
     )]
-    pub exploration_state: Box<Account<'info, ExplorationState>>,
+    pub draw_record: Box<Account<'info, DrawRecord>>,
+
+    /// Buyback window and tranches, derived from the fallback random value
+    #[account(
+        init,
+        payer = caller,
+        space = BuybackSchedule::LEN,
+        seeds = [BuybackSchedule::SEED, exploration_state.key().as_ref()],
+        bump
+    )]
+    pub buyback_schedule: Box<Account<'info, BuybackSchedule>>,
 
     /// Anyone can call this (permissionless)
+    #[account(mut)]
     pub caller: Signer<'info>,
 
     /// CHECK: SlotHashes sysvar
     #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::id())]
     pub slot_hashes: UncheckedAccount<'info>,
 
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
 }
 
 pub fn timeout_fallback_handler(ctx: Context<RevealTimeoutFallback>) -> Result<()> {
     let block = &mut ctx.accounts.block_state;
-    let exploration = &mut ctx.accounts.exploration_state;
+    let track = &mut ctx.accounts.track_state;
+    let exploration_key = ctx.accounts.exploration_state.key();
+    let (mut exploration, rigs) =
+        ExplorationState::load_with_rigs_mut(&ctx.accounts.exploration_state)?;
     let clock = &ctx.accounts.clock;
     let slot_hashes_data = ctx.accounts.slot_hashes.try_borrow_data()?;
 
@@ -191,37 +341,281 @@ pub fn timeout_fallback_handler(ctx: Context<RevealTimeoutFallback>) -> Result<(
         RigItError::RevealDeadlineNotPassed
     );
 
-    // Use slot hash of deadline slot as randomness
-    let fallback_slot = exploration.reveal_deadline_slot;
+    // Ticket distribution must be exactly what was committed to
+    exploration.verify_commit_snapshot(&rigs)?;
+
+    // The fallback slot is fixed before its hash exists (the reveal deadline, or
+    // a slot re-committed once that entry aged out of SlotHashes), so the caller
+    // can't pick the hash by choosing when to submit.
+    let deadline_slot = exploration.reveal_deadline_slot;
+    let fallback_slot = exploration.fallback_slot();
+    require!(clock.slot > fallback_slot, RigItError::RevealDeadlineNotPassed);
     let slot_hash = get_slot_hash(&slot_hashes_data, fallback_slot)
         .ok_or(RigItError::SlotHashNotAvailable)?;
 
-    // Use slot hash directly as random value (no secret needed)
-    let random_value = slot_hash;
-    exploration.revealed_random = Some(random_value);
+    // Mix the operator commitment and depositor entropy into the slot hash
+    let random_value = generate_fallback_random_value(
+        &exploration.commit_hash,
+        &exploration.entropy_accumulator,
+        &slot_hash,
+    );
+    exploration.set_revealed_random(random_value);
+
+    // Operator missed the reveal - slash the stake into the winner pool
+    let stake = exploration.operator_bond_at_stake;
+    if stake > 0 {
+        let bond = ctx
+            .accounts
+            .operator_bond
+            .as_mut()
+            .ok_or(RigItError::OperatorBondRequired)?;
+        let bond_vault = ctx
+            .accounts
+            .bond_vault
+            .as_ref()
+            .ok_or(RigItError::OperatorBondRequired)?;
+
+        let block_id = exploration.block_id;
+        let seeds = &[
+            b"block_vault_authority".as_ref(),
+            &[block_id],
+            &[ctx.bumps.block_vault_authority],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: bond_vault.to_account_info(),
+            to: ctx.accounts.block_vault.to_account_info(),
+            authority: ctx.accounts.block_vault_authority.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, stake)?;
+
+        bond.amount = bond
+            .amount
+            .checked_sub(stake)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        bond.locked_amount = bond.locked_amount.saturating_sub(stake);
+        bond.total_slashed = bond
+            .total_slashed
+            .checked_add(stake)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        exploration.operator_slash_amount = stake;
+
+        // Slashed funds are now owed to this track's winners
+        track.outstanding_liabilities = track
+            .outstanding_liabilities
+            .checked_add(stake)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+
+        emit!(OperatorSlashed {
+            block_id,
+            exploration_key,
+            operator: bond.operator,
+            amount: stake,
+            remaining_bond: bond.amount,
+        });
+    }
+
+    finalize_settlement(
+        &mut exploration,
+        &rigs,
+        exploration_key,
+        &random_value,
+        DrawInputs {
+            used_fallback: true,
+            operator_secret: [0u8; 32],
+            fallback_slot,
+            slot_hash,
+            drawn_at: clock.unix_timestamp,
+        },
+        SettlementAccounts {
+            ecosystem_bps: ctx.accounts.protocol_config.ecosystem_bps,
+            block,
+            track,
+            draw_record: &mut ctx.accounts.draw_record,
+            draw_record_bump: ctx.bumps.draw_record,
+            buyback_schedule: &mut ctx.accounts.buyback_schedule,
+            buyback_schedule_bump: ctx.bumps.buyback_schedule,
+            jackpot_vaults: JackpotVaults {
+                block_vault: ctx.accounts.block_vault.to_account_info(),
+                jackpot_vault: ctx.accounts.jackpot_vault.as_mut(),
+                block_vault_authority: ctx.accounts.block_vault_authority.to_account_info(),
+                block_vault_authority_bump: ctx.bumps.block_vault_authority,
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        },
+    )?;
+
+    emit!(RandomnessTimeoutFallback {
+        exploration_key,
+        deadline_slot,
+        fallback_slot,
+        winning_rig: exploration.winning_rig,
+    });
+
+    msg!(
+        "Exploration {} settled via timeout fallback. Winning rig: {}",
+        exploration.exploration_index,
+        exploration.winning_rig
+    );
 
-    // Select winning rig
-    let winning_rig = select_winning_rig(&random_value, &exploration.rig_tickets)
-        .ok_or(RigItError::ThresholdNotMet)?;
+    Ok(())
+}
 
-    exploration.winning_rig = Some(winning_rig);
+// === Fallback Slot Re-commit ===
 
-    // Calculate W, L, R (same as normal reveal)
-    let w = exploration.rig_deposits[winning_rig as usize]
-        .checked_add(exploration.rollover_amount)
-        .ok_or(RigItError::ArithmeticOverflow)?;
-    
-    let l = exploration
-        .total_deposits
-        .checked_sub(exploration.rig_deposits[winning_rig as usize])
+#[derive(Accounts)]
+pub struct RecommitFallbackSlot<'info> {
+    #[account(
+        mut,
+        constraint = exploration_state.load()?.status() == ExplorationStatus::Finalizing @ RigItError::ExplorationNotFinalizing,
+    )]
+    pub exploration_state: AccountLoader<'info, ExplorationState>,
+
+    /// Anyone can call this (permissionless)
+    pub caller: Signer<'info>,
+
+    /// CHECK: SlotHashes sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::id())]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Re-commit the fallback to a future slot once the current fallback slot's
+/// hash is no longer in SlotHashes (~512 slots), so a late fallback can never
+/// leave the exploration stuck in Finalizing.
+pub fn recommit_fallback_handler(ctx: Context<RecommitFallbackSlot>) -> Result<()> {
+    let exploration_key = ctx.accounts.exploration_state.key();
+    let mut exploration = ctx.accounts.exploration_state.load_mut()?;
+    let clock = &ctx.accounts.clock;
+    let slot_hashes_data = ctx.accounts.slot_hashes.try_borrow_data()?;
+
+    let previous_slot = exploration.fallback_slot();
+    require!(
+        clock.slot > exploration.reveal_deadline_slot && clock.slot > previous_slot,
+        RigItError::RevealDeadlineNotPassed
+    );
+    require!(
+        get_slot_hash(&slot_hashes_data, previous_slot).is_none(),
+        RigItError::FallbackSlotHashAvailable
+    );
+
+    exploration.fallback_slot = clock
+        .slot
+        .checked_add(FALLBACK_RECOMMIT_DELAY_SLOTS)
         .ok_or(RigItError::ArithmeticOverflow)?;
-    
-    let r = w.checked_add(l / 2).ok_or(RigItError::ArithmeticOverflow)?;
 
-    exploration.total_winner_deposits = w;
-    exploration.total_loser_deposits = l;
-    exploration.remaining_pool = r;
-    exploration.status = ExplorationStatus::Settled;
+    emit!(FallbackSlotRecommitted {
+        exploration_key,
+        previous_slot,
+        fallback_slot: exploration.fallback_slot,
+    });
+
+    msg!(
+        "Exploration {} fallback slot re-committed: {} -> {}",
+        exploration.exploration_index,
+        previous_slot,
+        exploration.fallback_slot
+    );
+
+    Ok(())
+}
+
+/// Draw inputs that differ between an operator reveal and the timeout fallback
+struct DrawInputs {
+    used_fallback: bool,
+    operator_secret: [u8; 32],
+    fallback_slot: u64,
+    slot_hash: [u8; 32],
+    drawn_at: i64,
+}
+
+/// Accounts both reveal paths settle an exploration into
+struct SettlementAccounts<'a, 'info> {
+    ecosystem_bps: u16,
+    block: &'a mut BlockState,
+    track: &'a mut TrackState,
+    draw_record: &'a mut DrawRecord,
+    draw_record_bump: u8,
+    buyback_schedule: &'a mut BuybackSchedule,
+    buyback_schedule_bump: u8,
+    jackpot_vaults: JackpotVaults<'a, 'info>,
+}
+
+/// Draw the prize rigs from the random value and settle the exploration:
+/// split the pool, fund the jackpot and consolation pool, persist the draw,
+/// schedule the buyback, settle the jackpot and record block and track stats.
+/// The operator bond must already be released or slashed.
+fn finalize_settlement(
+    exploration: &mut ExplorationState,
+    rigs: &[RigTotals],
+    exploration_key: Pubkey,
+    random_value: &[u8; 32],
+    inputs: DrawInputs,
+    accounts: SettlementAccounts<'_, '_>,
+) -> Result<()> {
+    let SettlementAccounts {
+        ecosystem_bps,
+        block,
+        track,
+        draw_record,
+        draw_record_bump,
+        buyback_schedule,
+        buyback_schedule_bump,
+        jackpot_vaults,
+    } = accounts;
+
+    // Draw a rig per prize tier without replacement and settle
+    let rig_tickets: Vec<u128> = rig_tickets(rigs).collect();
+    let prize_rigs = select_prize_rigs(random_value, &rig_tickets, exploration.prize_tier_count());
+    exploration.settle(rigs, &prize_rigs)?;
+    exploration.fund_jackpot()?;
+
+    // Reserve the consolation pool for losers out of the ecosystem allocation
+    exploration.fund_consolation(
+        rigs,
+        ecosystem_bps,
+        block.consolation_bps,
+        block.consolation_winners,
+    )?;
+    if exploration.consolation_pool > 0 {
+        emit!(ConsolationFunded {
+            exploration_key,
+            consolation_pool: exploration.consolation_pool,
+            consolation_winners: exploration.consolation_winners,
+            losing_tickets: exploration.losing_tickets(rigs),
+        });
+    }
+
+    // Persist every draw input for independent verification
+    draw_record.record_exploration(exploration_key, exploration);
+    draw_record.algorithm_version = DRAW_ALGORITHM_VERSION;
+    draw_record.used_fallback = inputs.used_fallback;
+    draw_record.operator_secret = inputs.operator_secret;
+    draw_record.fallback_slot = inputs.fallback_slot;
+    draw_record.slot_hash = inputs.slot_hash;
+    draw_record.random_value = *random_value;
+    draw_record.ticket_snapshot_hash = hash_ticket_snapshot(&rig_tickets);
+    draw_record.winning_rig = exploration.winning_rig;
+    draw_record.prize_rigs = exploration.prize_rigs;
+    draw_record.drawn_at = inputs.drawn_at;
+    draw_record.bump = draw_record_bump;
+
+    // Commit the buyback window at settlement so it can't be front-run off a guess
+    schedule_buyback(
+        buyback_schedule,
+        buyback_schedule_bump,
+        exploration_key,
+        exploration,
+        random_value,
+    );
+
+    // Fund the block jackpot and pay it to the winning rig if the draw hit it
+    let rollover_to_jackpot =
+        settle_jackpot(exploration, exploration_key, block, track, jackpot_vaults)?;
 
     // Update block stats
     block.total_volume = block
@@ -232,29 +626,155 @@ pub fn timeout_fallback_handler(ctx: Context<RevealTimeoutFallback>) -> Result<(
         .total_explorations_completed
         .checked_add(1)
         .ok_or(RigItError::ArithmeticOverflow)?;
-
-    emit!(RandomnessTimeoutFallback {
-        exploration_key: exploration.key(),
-        fallback_slot,
-        winning_rig,
-    });
+    track.total_volume = track
+        .total_volume
+        .checked_add(exploration.total_deposits as u128)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+    track.total_explorations_completed = track
+        .total_explorations_completed
+        .checked_add(1)
+        .ok_or(RigItError::ArithmeticOverflow)?;
 
     emit!(ExplorationSettled {
         block_id: exploration.block_id,
+        track_id: exploration.track_id,
         exploration_index: exploration.exploration_index,
-        exploration_key: exploration.key(),
-        winning_rig,
+        exploration_key,
+        winning_rig: exploration.winning_rig,
+        prize_rigs: exploration.prize_rigs,
         total_pool: exploration.total_pool(),
-        winner_deposits: w,
-        loser_deposits: l,
-        remaining_pool: r,
+        winner_deposits: exploration.total_winner_deposits,
+        loser_deposits: exploration.total_loser_deposits,
+        remaining_pool: exploration.remaining_pool,
+        rollover_amount: exploration.rollover_amount,
+        rollover_policy: exploration.rollover_policy,
+        rollover_to_jackpot,
+        loser_refund_bps: exploration.loser_refund_bps,
     });
 
-    msg!(
-        "Exploration {} settled via timeout fallback. Winning rig: {}",
-        exploration.exploration_index,
-        winning_rig
-    );
-
     Ok(())
 }
+
+/// Derive the exploration's buyback window and tranches from its random value
+fn schedule_buyback(
+    schedule: &mut BuybackSchedule,
+    bump: u8,
+    exploration_key: Pubkey,
+    exploration: &ExplorationState,
+    random_value: &[u8; 32],
+) {
+    schedule.exploration = exploration_key;
+    schedule.block_id = exploration.block_id;
+    schedule.bump = bump;
+    schedule.derive(random_value, exploration.active_end_ts);
+
+    emit!(BuybackScheduled {
+        exploration_key,
+        block_id: schedule.block_id,
+        window_start_ts: schedule.window_start_ts,
+        window_end_ts: schedule.window_end_ts,
+        tranche_start_ts: schedule.tranche_start_ts,
+        tranche_bps: schedule.tranche_bps,
+    });
+}
+
+/// Token accounts settlement moves jackpot funds between
+struct JackpotVaults<'a, 'info> {
+    block_vault: AccountInfo<'info>,
+    jackpot_vault: Option<&'a mut Box<Account<'info, TokenAccount>>>,
+    block_vault_authority: AccountInfo<'info>,
+    block_vault_authority_bump: u8,
+    token_program: AccountInfo<'info>,
+}
+
+/// Move rollover under the jackpot policy and the jackpot slice of R from the
+/// block vault into the jackpot vault, then pay the whole jackpot back to the
+/// exploration's first prize if the revealed random value hit it.
+/// Returns the rollover moved to the jackpot.
+fn settle_jackpot<'info>(
+    exploration: &mut ExplorationState,
+    exploration_key: Pubkey,
+    block: &mut BlockState,
+    track: &mut TrackState,
+    vaults: JackpotVaults<'_, 'info>,
+) -> Result<u64> {
+    let rollover_to_jackpot = exploration.jackpot_rollover();
+    let funded = rollover_to_jackpot
+        .checked_add(exploration.jackpot_contribution)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+    // With a hit chance the vault must be checked, so omitting it can't skip a hit
+    let jackpot_vault = match vaults.jackpot_vault {
+        Some(jackpot_vault) => jackpot_vault,
+        // No jackpot configured for the block: nothing to fund or pay
+        None if funded == 0 && exploration.jackpot_hit_bps == 0 => return Ok(0),
+        None => return err!(RigItError::JackpotVaultRequired),
+    };
+
+    let block_id = exploration.block_id;
+    let seeds = &[
+        b"block_vault_authority".as_ref(),
+        &[block_id],
+        &[vaults.block_vault_authority_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    let transfer = |from: AccountInfo<'info>, to: AccountInfo<'info>, amount: u64| {
+        let cpi_accounts = Transfer {
+            from,
+            to,
+            authority: vaults.block_vault_authority.clone(),
+        };
+        let cpi_ctx =
+            CpiContext::new_with_signer(vaults.token_program.clone(), cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, amount)
+    };
+
+    if funded > 0 {
+        transfer(vaults.block_vault.clone(), jackpot_vault.to_account_info(), funded)?;
+        jackpot_vault.reload()?;
+
+        // Funded amounts leave this exploration
+        track.outstanding_liabilities = track
+            .outstanding_liabilities
+            .checked_sub(funded)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        exploration.record_payout(funded)?;
+
+        emit!(JackpotFunded {
+            block_id,
+            exploration_key,
+            rollover_amount: rollover_to_jackpot,
+            pool_contribution: exploration.jackpot_contribution,
+            jackpot_balance: jackpot_vault.amount,
+        });
+    }
+
+    let jackpot = jackpot_vault.amount;
+    if jackpot > 0 && exploration.jackpot_hit() {
+        transfer(jackpot_vault.to_account_info(), vaults.block_vault.clone(), jackpot)?;
+        jackpot_vault.reload()?;
+
+        // The whole jackpot is now owed to the winning rig's depositors
+        exploration.jackpot_won = jackpot;
+        track.outstanding_liabilities = track
+            .outstanding_liabilities
+            .checked_add(jackpot)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        block.jackpot_hits = block
+            .jackpot_hits
+            .checked_add(1)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        block.total_jackpot_paid = block
+            .total_jackpot_paid
+            .checked_add(jackpot)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+
+        emit!(JackpotHit {
+            block_id,
+            exploration_key,
+            winning_rig: exploration.winning_rig,
+            amount: jackpot,
+        });
+    }
+
+    Ok(rollover_to_jackpot)
+}
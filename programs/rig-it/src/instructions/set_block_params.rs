@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token::TokenAccount;
+
+use crate::state::{
+    treasury_buckets, BlockState, ProtocolConfig, RolloverPolicy, JACKPOT_VAULT_SEED,
+    MAX_PRIZE_TIERS, TREASURY_SEED,
+};
+use crate::errors::RigItError;
+use crate::events::BlockParamsUpdated;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetBlockParamsArgs {
+    pub min_threshold: Option<u64>,
+    /// Winner pool split across 1st/2nd/3rd drawn rigs in bps (e.g. [7000, 2000, 1000])
+    pub prize_tier_bps: Option<[u16; MAX_PRIZE_TIERS]>,
+    /// Share of the ecosystem allocation funding the consolation lottery (bps)
+    pub consolation_bps: Option<u16>,
+    /// Consolation tickets drawn over the losing rigs
+    pub consolation_winners: Option<u8>,
+    /// Where rollover goes at settlement
+    pub rollover_policy: Option<RolloverPolicy>,
+    /// Slice of R funding the block jackpot (bps)
+    pub jackpot_bps: Option<u16>,
+    /// Chance per exploration that the jackpot hits (bps)
+    pub jackpot_hit_bps: Option<u16>,
+    /// Fee taken from each deposit (bps, max 500)
+    pub deposit_fee_bps: Option<u16>,
+    /// $RIG balance from which holders get the fee discount (0 = no discount)
+    pub fee_discount_rig_balance: Option<u64>,
+    /// Share of the fee waived for qualifying holders (bps, 10000 = exempt)
+    pub fee_discount_bps: Option<u16>,
+    /// Share of a losing deposit refunded (bps, 1-10000)
+    pub loser_refund_bps: Option<u16>,
+}
+
+#[derive(Accounts)]
+pub struct SetBlockParams<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ RigItError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [BlockState::SEED, &[block_state.block_id]],
+        bump = block_state.bump,
+    )]
+    pub block_state: Account<'info, BlockState>,
+
+    /// Jackpot vault for the block; must exist (init_jackpot) before the
+    /// jackpot or the jackpot rollover policy is enabled
+    #[account(
+        seeds = [JACKPOT_VAULT_SEED, &[block_state.block_id]],
+        bump,
+    )]
+    pub jackpot_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Deposit-fee treasury for the block; must exist (init_fee_vault) before
+    /// a deposit fee is enabled
+    #[account(
+        seeds = [TREASURY_SEED, treasury_buckets::DEPOSIT_FEES, &[block_state.block_id]],
+        bump,
+    )]
+    pub treasury_deposit_fees: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Changes apply to explorations started afterwards; running ones keep their snapshot
+pub fn handler(ctx: Context<SetBlockParams>, args: SetBlockParamsArgs) -> Result<()> {
+    let block = &mut ctx.accounts.block_state;
+
+    if let Some(val) = args.min_threshold {
+        block.min_threshold = val;
+    }
+    if let Some(tiers) = args.prize_tier_bps {
+        require!(
+            BlockState::validate_prize_tiers(&tiers),
+            RigItError::InvalidPrizeTiers
+        );
+        block.prize_tier_bps = tiers;
+    }
+    if let Some(val) = args.consolation_bps {
+        block.consolation_bps = val;
+    }
+    if let Some(val) = args.consolation_winners {
+        block.consolation_winners = val;
+    }
+    if let Some(policy) = args.rollover_policy {
+        block.rollover_policy = policy;
+    }
+    if let Some(val) = args.jackpot_bps {
+        block.jackpot_bps = val;
+    }
+    if let Some(val) = args.jackpot_hit_bps {
+        block.jackpot_hit_bps = val;
+    }
+    if let Some(val) = args.loser_refund_bps {
+        require!(
+            BlockState::validate_loser_refund(val),
+            RigItError::InvalidLoserRefund
+        );
+        block.loser_refund_bps = val;
+    }
+    if let Some(val) = args.deposit_fee_bps {
+        block.deposit_fee_bps = val;
+    }
+    if let Some(val) = args.fee_discount_rig_balance {
+        block.fee_discount_rig_balance = val;
+    }
+    if let Some(val) = args.fee_discount_bps {
+        block.fee_discount_bps = val;
+    }
+    require!(
+        BlockState::validate_deposit_fee(block.deposit_fee_bps, block.fee_discount_bps),
+        RigItError::InvalidDepositFeeConfig
+    );
+    require!(
+        block.deposit_fee_bps == 0 || ctx.accounts.treasury_deposit_fees.is_some(),
+        RigItError::FeeVaultRequired
+    );
+    require!(
+        BlockState::validate_jackpot(block.jackpot_bps, block.jackpot_hit_bps),
+        RigItError::InvalidJackpotConfig
+    );
+    require!(
+        !block.uses_jackpot() || ctx.accounts.jackpot_vault.is_some(),
+        RigItError::JackpotVaultRequired
+    );
+    require!(
+        BlockState::validate_consolation(block.consolation_bps, block.consolation_winners),
+        RigItError::InvalidConsolationConfig
+    );
+
+    emit!(BlockParamsUpdated {
+        block_id: block.block_id,
+        min_threshold: block.min_threshold,
+        prize_tier_bps: block.prize_tier_bps(),
+        consolation_bps: block.consolation_bps,
+        consolation_winners: block.consolation_winners,
+        rollover_policy: block.rollover_policy as u8,
+        jackpot_bps: block.jackpot_bps,
+        jackpot_hit_bps: block.jackpot_hit_bps,
+        deposit_fee_bps: block.deposit_fee_bps,
+        fee_discount_rig_balance: block.fee_discount_rig_balance,
+        fee_discount_bps: block.fee_discount_bps,
+        loser_refund_bps: block.loser_refund_bps(),
+    });
+
+    msg!(
+        "Block {} params updated: min threshold {}, prize tiers {:?}",
+        block.block_id,
+        block.min_threshold,
+        block.prize_tier_bps()
+    );
+
+    Ok(())
+}
@@ -10,6 +10,11 @@ pub struct SetParamsArgs {
     pub cooldown_duration_secs: Option<u32>,
     pub anti_snipe_window_secs: Option<u32>,
     pub commit_reveal_timeout_slots: Option<u64>,
+    pub operator_bond_amount: Option<u64>,
+    /// Whitelisted AMM adapter for buybacks (Pubkey::default() disables)
+    pub amm_program: Option<Pubkey>,
+    /// Share of the team-ops allocation paid to referrers (bps)
+    pub referral_bps: Option<u16>,
     pub new_operator: Option<Pubkey>,
     pub new_emergency_admin: Option<Pubkey>,
 }
@@ -46,6 +51,16 @@ pub fn handler(ctx: Context<SetParams>, args: SetParamsArgs) -> Result<()> {
     if let Some(val) = args.commit_reveal_timeout_slots {
         config.commit_reveal_timeout_slots = val;
     }
+    if let Some(val) = args.operator_bond_amount {
+        config.operator_bond_amount = val;
+    }
+    if let Some(val) = args.amm_program {
+        config.amm_program = val;
+    }
+    if let Some(val) = args.referral_bps {
+        require!(val <= 10000, RigItError::InvalidReferralConfig);
+        config.referral_bps = val;
+    }
     if let Some(val) = args.new_operator {
         config.operator = val;
     }
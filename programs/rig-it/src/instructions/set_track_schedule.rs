@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ProtocolConfig, TrackState};
+use crate::errors::RigItError;
+use crate::events::TrackScheduleUpdated;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetTrackScheduleArgs {
+    /// Scheduled start of the track's next exploration (defaults to now)
+    pub next_start_ts: Option<i64>,
+    pub active_duration_secs: Option<u32>,
+    pub cooldown_duration_secs: Option<u32>,
+    pub anti_snipe_window_secs: Option<u32>,
+}
+
+#[derive(Accounts)]
+pub struct SetTrackSchedule<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ RigItError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [TrackState::SEED, &[track_state.block_id], &[track_state.track_id]],
+        bump = track_state.bump,
+    )]
+    pub track_state: Account<'info, TrackState>,
+
+    pub admin: Signer<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handler(ctx: Context<SetTrackSchedule>, args: SetTrackScheduleArgs) -> Result<()> {
+    let track = &mut ctx.accounts.track_state;
+    let clock = &ctx.accounts.clock;
+
+    if let Some(val) = args.active_duration_secs {
+        track.active_duration_secs = val;
+    }
+    if let Some(val) = args.cooldown_duration_secs {
+        track.cooldown_duration_secs = val;
+    }
+    if let Some(val) = args.anti_snipe_window_secs {
+        track.anti_snipe_window_secs = val;
+    }
+    require!(track.validate_timing(), RigItError::InvalidSchedule);
+
+    // Re-anchor at the next exploration so live and past rounds keep their windows;
+    // each exploration snapshots its anti-snipe window at start
+    track.schedule_anchor_index = track.current_exploration_index;
+    track.schedule_anchor_ts = args.next_start_ts.unwrap_or(clock.unix_timestamp);
+
+    emit!(TrackScheduleUpdated {
+        block_id: track.block_id,
+        track_id: track.track_id,
+        schedule_anchor_ts: track.schedule_anchor_ts,
+        schedule_anchor_index: track.schedule_anchor_index,
+        active_duration_secs: track.active_duration_secs,
+        cooldown_duration_secs: track.cooldown_duration_secs,
+        anti_snipe_window_secs: track.anti_snipe_window_secs,
+    });
+
+    msg!(
+        "Block {} track {} schedule re-anchored: exploration {} at {} (period {}s)",
+        track.block_id,
+        track.track_id,
+        track.schedule_anchor_index,
+        track.schedule_anchor_ts,
+        track.schedule_period_secs()
+    );
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{BlockState, ExplorationState, ExplorationStatus, ProtocolConfig};
+use crate::state::{
+    BlockState, ExplorationState, ExplorationStatus, ProtocolConfig, TrackState,
+    EXPLORATION_LAYOUT_VERSION, MAX_PRIZE_TIERS, NO_WINNING_RIG,
+};
 use crate::errors::RigItError;
 use crate::events::ExplorationStarted;
 
@@ -14,84 +17,114 @@ pub struct StartExploration<'info> {
     pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(
-        mut,
         seeds = [BlockState::SEED, &[block_state.block_id]],
         bump = block_state.bump,
         constraint = !block_state.paused @ RigItError::BlockPaused,
     )]
     pub block_state: Account<'info, BlockState>,
 
+    #[account(
+        mut,
+        seeds = [TrackState::SEED, &[block_state.block_id], &[track_state.track_id]],
+        bump = track_state.bump,
+    )]
+    pub track_state: Account<'info, TrackState>,
+
     #[account(
         init,
-        payer = operator,
-        space = ExplorationState::LEN,
+        payer = caller,
+        space = ExplorationState::space(block_state.rig_count),
         seeds = [
             ExplorationState::SEED,
             &[block_state.block_id],
-            &block_state.current_exploration_index.to_le_bytes()
+            &[track_state.track_id],
+            &track_state.current_exploration_index.to_le_bytes()
         ],
         bump
     )]
-    pub exploration_state: Box<Account<'info, ExplorationState>>,
-
-    /// Previous exploration (optional, for rollover validation)
-    /// If this is the first exploration, this can be any account
-    pub previous_exploration: Option<Box<Account<'info, ExplorationState>>>,
+    pub exploration_state: AccountLoader<'info, ExplorationState>,
 
+    /// Previous exploration of this track, required unless this is the first one
     #[account(
-        mut,
-        constraint = operator.key() == protocol_config.operator @ RigItError::Unauthorized
+        seeds = [
+            ExplorationState::SEED,
+            &[block_state.block_id],
+            &[track_state.track_id],
+            &track_state.current_exploration_index.saturating_sub(1).to_le_bytes()
+        ],
+        bump = previous_exploration.load()?.bump,
     )]
-    pub operator: Signer<'info>,
+    pub previous_exploration: Option<AccountLoader<'info, ExplorationState>>,
+
+    /// Anyone can call this once the scheduled window opens (permissionless)
+    #[account(mut)]
+    pub caller: Signer<'info>,
 
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
 }
 
 pub fn handler(ctx: Context<StartExploration>) -> Result<()> {
-    let config = &ctx.accounts.protocol_config;
-    let block = &mut ctx.accounts.block_state;
-    let exploration = &mut ctx.accounts.exploration_state;
+    let block = &ctx.accounts.block_state;
+    let track = &mut ctx.accounts.track_state;
     let clock = &ctx.accounts.clock;
 
-    // If not first exploration, verify previous is finalized
-    if block.current_exploration_index > 0 {
-        if let Some(prev) = &ctx.accounts.previous_exploration {
-            require!(
-                prev.status == ExplorationStatus::Settled || 
-                prev.status == ExplorationStatus::RolledOver,
-                RigItError::PreviousNotFinalized
-            );
-            
-            // Check cooldown has ended
-            require!(
-                clock.unix_timestamp >= prev.cooldown_end_ts,
-                RigItError::CooldownNotEnded
-            );
-        }
-    }
-
-    // Calculate timing
+    // Timing comes from the track schedule, not from when this was called; slots
+    // whose active phase has passed are skipped rather than started already ended
+    let index = track.current_exploration_index;
+    let window = track
+        .catch_up(index, clock.unix_timestamp)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+    require!(
+        clock.unix_timestamp >= window.start_ts,
+        RigItError::ExplorationNotScheduled
+    );
+
+    // Previous exploration must be finalized and out of cooldown
+    let previous = ctx
+        .accounts
+        .previous_exploration
+        .as_ref()
+        .map(|prev| prev.load())
+        .transpose()?;
+    ExplorationState::validate_previous(
+        previous.as_deref(),
+        block.block_id,
+        track.track_id,
+        track.current_exploration_index,
+        clock.unix_timestamp,
+    )?;
+
+    // A late start is truncated: the scheduled end times stay fixed
     let start_ts = clock.unix_timestamp;
-    let active_end_ts = start_ts + config.active_duration_secs as i64;
-    let cooldown_end_ts = active_end_ts + config.cooldown_duration_secs as i64;
+    let active_end_ts = window.active_end_ts;
+    let cooldown_end_ts = window.cooldown_end_ts;
 
-    // Initialize exploration state
+    // Initialize exploration state (account data starts zeroed)
+    let exploration_key = ctx.accounts.exploration_state.key();
+    let mut exploration = ctx.accounts.exploration_state.load_init()?;
+    exploration.version = EXPLORATION_LAYOUT_VERSION;
     exploration.block_id = block.block_id;
-    exploration.exploration_index = block.current_exploration_index;
-    exploration.status = ExplorationStatus::Active;
+    exploration.track_id = track.track_id;
+    exploration.exploration_index = track.current_exploration_index;
+    exploration.set_status(ExplorationStatus::Active);
     exploration.start_slot = clock.slot;
     exploration.start_ts = start_ts;
     exploration.active_end_ts = active_end_ts;
     exploration.cooldown_end_ts = cooldown_end_ts;
-    exploration.total_deposits = 0;
-    exploration.rig_deposits = [0u64; 36];
-    exploration.rig_tickets = [0u128; 36];
-    exploration.rollover_amount = 0;
+    exploration.anti_snipe_window_secs = track.anti_snipe_window_secs;
+    exploration.rig_count = block.rig_count;
+    exploration.winning_rig = NO_WINNING_RIG;
+    exploration.prize_rigs = [NO_WINNING_RIG; MAX_PRIZE_TIERS];
+    exploration.prize_tier_bps = block.prize_tier_bps();
+    exploration.rollover_policy = block.rollover_policy as u8;
+    exploration.jackpot_bps = block.jackpot_bps;
+    exploration.jackpot_hit_bps = block.jackpot_hit_bps;
+    exploration.loser_refund_bps = block.loser_refund_bps();
     exploration.bump = ctx.bumps.exploration_state;
 
-    // Increment block's exploration counter for next time
-    block.current_exploration_index = block
+    // Increment track's exploration counter for next time
+    track.current_exploration_index = track
         .current_exploration_index
         .checked_add(1)
         .ok_or(RigItError::ArithmeticOverflow)?;
@@ -99,8 +132,10 @@ pub fn handler(ctx: Context<StartExploration>) -> Result<()> {
     // Emit event
     emit!(ExplorationStarted {
         block_id: exploration.block_id,
+        track_id: exploration.track_id,
         exploration_index: exploration.exploration_index,
-        exploration_key: exploration.key(),
+        exploration_key,
+        scheduled_start_ts: window.start_ts,
         start_ts,
         active_end_ts,
         cooldown_end_ts,
@@ -108,9 +143,10 @@ pub fn handler(ctx: Context<StartExploration>) -> Result<()> {
     });
 
     msg!(
-        "Exploration {} started for Block {}",
+        "Exploration {} started for Block {} track {}",
         exploration.exploration_index,
-        exploration.block_id
+        exploration.block_id,
+        exploration.track_id
     );
 
     Ok(())
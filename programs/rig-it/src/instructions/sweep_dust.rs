@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::{
+    exploration_flags, treasury_buckets, ExplorationState, ExplorationStatus, ProtocolConfig,
+    TrackState, TREASURY_SEED,
+};
+use crate::errors::RigItError;
+use crate::events::DustSwept;
+
+#[derive(Accounts)]
+pub struct SweepDust<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        constraint = exploration_state.load()?.status() == ExplorationStatus::Settled @ RigItError::ExplorationNotSettled,
+        constraint = !exploration_state.load()?.has_flag(exploration_flags::DUST_SWEPT) @ RigItError::DustAlreadySwept,
+    )]
+    pub exploration_state: AccountLoader<'info, ExplorationState>,
+
+    #[account(
+        mut,
+        seeds = [TrackState::SEED, &[exploration_state.load()?.block_id], &[exploration_state.load()?.track_id]],
+        bump = track_state.bump,
+    )]
+    pub track_state: Box<Account<'info, TrackState>>,
+
+    #[account(
+        mut,
+        seeds = [b"block_vault", &[exploration_state.load()?.block_id]],
+        bump,
+    )]
+    pub block_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA authority for vault
+    #[account(
+        seeds = [b"block_vault_authority", &[exploration_state.load()?.block_id]],
+        bump
+    )]
+    pub block_vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, treasury_buckets::ECOSYSTEM, &[exploration_state.load()?.block_id]],
+        bump
+    )]
+    pub treasury_ecosystem: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        constraint = operator.key() == protocol_config.operator @ RigItError::Unauthorized
+    )]
+    pub operator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<SweepDust>) -> Result<()> {
+    let exploration_key = ctx.accounts.exploration_state.key();
+    let mut exploration = ctx.accounts.exploration_state.load_mut()?;
+
+    // Only once every receipt has claimed and the buckets are allocated
+    let dust = exploration
+        .residual_dust()?
+        .ok_or(RigItError::PayoutsOutstanding)?;
+    let total_entitled = exploration.total_entitled()?;
+    let total_paid = exploration.total_paid;
+
+    if dust > 0 {
+        let block_id = exploration.block_id;
+        let seeds = &[
+            b"block_vault_authority".as_ref(),
+            &[block_id],
+            &[ctx.bumps.block_vault_authority],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.block_vault.to_account_info(),
+            to: ctx.accounts.treasury_ecosystem.to_account_info(),
+            authority: ctx.accounts.block_vault_authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::transfer(cpi_ctx, dust)?;
+
+        // Dust leaves the vault
+        let track = &mut ctx.accounts.track_state;
+        track.outstanding_liabilities = track
+            .outstanding_liabilities
+            .checked_sub(dust)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        exploration.record_payout(dust)?;
+    }
+
+    exploration.dust_swept = dust;
+    exploration.set_flag(exploration_flags::DUST_SWEPT);
+
+    emit!(DustSwept {
+        exploration_key,
+        block_id: exploration.block_id,
+        total_entitled,
+        total_paid,
+        dust,
+    });
+
+    msg!(
+        "Swept {} dust from exploration {} (entitled {}, paid {})",
+        dust,
+        exploration.exploration_index,
+        total_entitled,
+        total_paid
+    );
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::OperatorBond;
+use crate::errors::RigItError;
+use crate::events::OperatorBondWithdrawn;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawOperatorBondArgs {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawOperatorBond<'info> {
+    #[account(
+        mut,
+        seeds = [OperatorBond::SEED, &[operator_bond.block_id]],
+        bump = operator_bond.bump,
+        has_one = operator @ RigItError::Unauthorized,
+    )]
+    pub operator_bond: Account<'info, OperatorBond>,
+
+    #[account(
+        mut,
+        seeds = [OperatorBond::VAULT_SEED, &[operator_bond.block_id]],
+        bump,
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority for vault
+    #[account(
+        seeds = [b"block_vault_authority", &[operator_bond.block_id]],
+        bump
+    )]
+    pub block_vault_authority: UncheckedAccount<'info>,
+
+    /// Operator that posted the bond (may since have been replaced)
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = operator_token_account.owner == operator.key(),
+        constraint = operator_token_account.mint == bond_vault.mint,
+    )]
+    pub operator_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawOperatorBond>, args: WithdrawOperatorBondArgs) -> Result<()> {
+    let bond = &mut ctx.accounts.operator_bond;
+
+    // Bond at stake in outstanding commits stays locked until they resolve
+    require!(
+        args.amount <= bond.available(),
+        RigItError::InsufficientOperatorBond
+    );
+
+    let block_id = bond.block_id;
+    let seeds = &[
+        b"block_vault_authority".as_ref(),
+        &[block_id],
+        &[ctx.bumps.block_vault_authority],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.bond_vault.to_account_info(),
+        to: ctx.accounts.operator_token_account.to_account_info(),
+        authority: ctx.accounts.block_vault_authority.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, args.amount)?;
+
+    bond.amount = bond
+        .amount
+        .checked_sub(args.amount)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+
+    emit!(OperatorBondWithdrawn {
+        block_id,
+        operator: bond.operator,
+        amount: args.amount,
+        total_bond: bond.amount,
+    });
+
+    msg!(
+        "Operator bond for Block {} reduced by {} to {}",
+        block_id,
+        args.amount,
+        bond.amount
+    );
+
+    Ok(())
+}
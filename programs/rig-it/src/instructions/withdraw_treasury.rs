@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::state::{
+    BlockState, ProtocolConfig, WithdrawableBucket, TREASURY_AUTHORITY_SEED, TREASURY_SEED,
+};
+use crate::errors::RigItError;
+use crate::events::TreasuryWithdrawn;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawTreasuryArgs {
+    pub bucket: WithdrawableBucket,
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: WithdrawTreasuryArgs)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        seeds = [ProtocolConfig::SEED],
+        bump = protocol_config.bump,
+        has_one = admin @ RigItError::Unauthorized,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [BlockState::SEED, &[block_state.block_id]],
+        bump = block_state.bump,
+    )]
+    pub block_state: Account<'info, BlockState>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, args.bucket.seed(), &[block_state.block_id]],
+        bump
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA authority for the block's treasury vaults
+    #[account(
+        seeds = [TREASURY_AUTHORITY_SEED, &[block_state.block_id]],
+        bump
+    )]
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = destination.mint == block_state.asset_mint,
+    )]
+    pub destination: Box<Account<'info, TokenAccount>>,
+
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawTreasury>, args: WithdrawTreasuryArgs) -> Result<()> {
+    require!(
+        args.amount <= ctx.accounts.treasury.amount,
+        RigItError::InsufficientTreasuryBalance
+    );
+
+    let block_id = ctx.accounts.block_state.block_id;
+    let seeds = &[
+        TREASURY_AUTHORITY_SEED,
+        &[block_id],
+        &[ctx.bumps.treasury_authority],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.treasury.to_account_info(),
+        to: ctx.accounts.destination.to_account_info(),
+        authority: ctx.accounts.treasury_authority.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+    token::transfer(cpi_ctx, args.amount)?;
+
+    ctx.accounts.treasury.reload()?;
+
+    emit!(TreasuryWithdrawn {
+        block_id,
+        bucket: args.bucket as u8,
+        destination: ctx.accounts.destination.key(),
+        amount: args.amount,
+        treasury_remaining: ctx.accounts.treasury.amount,
+    });
+
+    msg!(
+        "Withdrew {} from block {} {:?} treasury",
+        args.amount,
+        block_id,
+        args.bucket
+    );
+
+    Ok(())
+}
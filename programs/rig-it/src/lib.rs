@@ -1,9 +1,18 @@
+// `#[program]` expands to IDL handlers at the crate root that call the deprecated
+// `AccountInfo::realloc` (Anchor 0.31); our own modules keep the lint on
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
 
+#[warn(deprecated)]
 pub mod errors;
+#[warn(deprecated)]
 pub mod events;
+#[warn(deprecated)]
 pub mod instructions;
+#[warn(deprecated)]
 pub mod state;
+#[warn(deprecated)]
 pub mod utils;
 
 use instructions::*;
@@ -24,7 +33,27 @@ pub mod rig_it {
         instructions::init_block::handler(ctx, args)
     }
 
-    /// Start a new exploration round for a block
+    /// Initialize an exploration track (parallel arena) within a block
+    pub fn init_track(ctx: Context<InitTrack>, args: InitTrackArgs) -> Result<()> {
+        instructions::init_track::handler(ctx, args)
+    }
+
+    /// Initialize a block's treasury vaults (one per bucket, in the block's asset)
+    pub fn init_treasuries(ctx: Context<InitTreasuries>) -> Result<()> {
+        instructions::init_treasuries::handler(ctx)
+    }
+
+    /// Initialize a block's jackpot vault
+    pub fn init_jackpot(ctx: Context<InitJackpot>) -> Result<()> {
+        instructions::init_jackpot::handler(ctx)
+    }
+
+    /// Initialize a block's deposit-fee treasury vault
+    pub fn init_fee_vault(ctx: Context<InitFeeVault>) -> Result<()> {
+        instructions::init_fee_vault::handler(ctx)
+    }
+
+    /// Start the next scheduled exploration round for a track (permissionless)
     pub fn start_exploration(ctx: Context<StartExploration>) -> Result<()> {
         instructions::start_exploration::handler(ctx)
     }
@@ -50,12 +79,33 @@ pub mod rig_it {
         instructions::reveal_randomness::handler(ctx, args)
     }
 
-    /// Fallback if reveal times out - uses slot hash as randomness
+    /// Fallback if reveal times out - uses slot hash as randomness and slashes the operator bond
     pub fn reveal_timeout_fallback(ctx: Context<RevealTimeoutFallback>) -> Result<()> {
         instructions::reveal_randomness::timeout_fallback_handler(ctx)
     }
 
-    /// Claim refund for losing deposit (50% of deposit)
+    /// Re-commit the timeout fallback to a future slot once its slot hash has aged out
+    pub fn recommit_fallback_slot(ctx: Context<RecommitFallbackSlot>) -> Result<()> {
+        instructions::reveal_randomness::recommit_fallback_handler(ctx)
+    }
+
+    /// Post (or top up) the operator bond for a block
+    pub fn post_operator_bond(
+        ctx: Context<PostOperatorBond>,
+        args: PostOperatorBondArgs,
+    ) -> Result<()> {
+        instructions::post_operator_bond::handler(ctx, args)
+    }
+
+    /// Withdraw unlocked operator bond
+    pub fn withdraw_operator_bond(
+        ctx: Context<WithdrawOperatorBond>,
+        args: WithdrawOperatorBondArgs,
+    ) -> Result<()> {
+        instructions::withdraw_operator_bond::handler(ctx, args)
+    }
+
+    /// Claim refund for losing deposit (the block's loser refund share, 50% by default)
     pub fn refund_loser(ctx: Context<RefundLoser>) -> Result<()> {
         instructions::refund_loser::handler(ctx)
     }
@@ -65,16 +115,59 @@ pub mod rig_it {
         instructions::claim_winnings::handler(ctx)
     }
 
+    /// Register the caller as a referrer under a unique code
+    pub fn register_referrer(
+        ctx: Context<RegisterReferrer>,
+        args: RegisterReferrerArgs,
+    ) -> Result<()> {
+        instructions::register_referrer::handler(ctx, args)
+    }
+
+    /// Claim a referrer's reward for the volume it referred into one exploration
+    pub fn claim_referral_reward(ctx: Context<ClaimReferralReward>) -> Result<()> {
+        instructions::claim_referral_reward::handler(ctx)
+    }
+
     /// Allocate buyback budget from remaining pool
     pub fn allocate_buyback_budget(ctx: Context<AllocateBuybackBudget>) -> Result<()> {
         instructions::allocate_buyback::handler(ctx)
     }
 
+    /// Swap buyback-burn treasury funds for $RIG through the whitelisted AMM and burn them
+    pub fn execute_buyback_burn<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteBuybackBurn<'info>>,
+        args: ExecuteBuybackBurnArgs,
+    ) -> Result<()> {
+        instructions::execute_buyback_burn::handler(ctx, args)
+    }
+
+    /// Pair buyback-LP treasury funds with bought $RIG through the whitelisted AMM
+    /// and lock the LP tokens permanently
+    pub fn execute_buyback_lp<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteBuybackLp<'info>>,
+        args: ExecuteBuybackLpArgs,
+    ) -> Result<()> {
+        instructions::execute_buyback_lp::handler(ctx, args)
+    }
+
     /// Carry forward funds from rolled-over exploration
     pub fn carry_forward(ctx: Context<CarryForward>) -> Result<()> {
         instructions::carry_forward::handler(ctx)
     }
 
+    /// Sweep rounding dust left after every payout to the ecosystem treasury
+    pub fn sweep_dust(ctx: Context<SweepDust>) -> Result<()> {
+        instructions::sweep_dust::handler(ctx)
+    }
+
+    /// Withdraw from a block's team-ops, ecosystem or deposit-fee treasury (admin only)
+    pub fn withdraw_treasury(
+        ctx: Context<WithdrawTreasury>,
+        args: WithdrawTreasuryArgs,
+    ) -> Result<()> {
+        instructions::withdraw_treasury::handler(ctx, args)
+    }
+
     /// Claim anti-sniped deposit into next exploration
     pub fn claim_anti_sniped_deposit(ctx: Context<ClaimAntiSnipedDeposit>) -> Result<()> {
         instructions::deposit_to_rig::claim_anti_sniped_handler(ctx)
@@ -85,6 +178,22 @@ pub mod rig_it {
         instructions::set_params::handler(ctx, args)
     }
 
+    /// Update block parameters (threshold, prize tiers)
+    pub fn set_block_params(
+        ctx: Context<SetBlockParams>,
+        args: SetBlockParamsArgs,
+    ) -> Result<()> {
+        instructions::set_block_params::handler(ctx, args)
+    }
+
+    /// Re-anchor a track's exploration schedule
+    pub fn set_track_schedule(
+        ctx: Context<SetTrackSchedule>,
+        args: SetTrackScheduleArgs,
+    ) -> Result<()> {
+        instructions::set_track_schedule::handler(ctx, args)
+    }
+
     /// Emergency pause/unpause
     pub fn emergency_pause(ctx: Context<EmergencyPause>, pause: bool) -> Result<()> {
         instructions::emergency_pause::handler(ctx, pause)
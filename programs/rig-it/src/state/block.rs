@@ -1,5 +1,52 @@
 use anchor_lang::prelude::*;
 
+/// Maximum number of parallel exploration tracks per block
+pub const MAX_TRACKS_PER_BLOCK: u8 = 4;
+
+/// Maximum number of prize tiers (winning rigs) per exploration
+pub const MAX_PRIZE_TIERS: usize = 3;
+
+/// Default prize structure: the whole winner pool to a single rig
+pub const SINGLE_WINNER_PRIZE_TIERS: [u16; MAX_PRIZE_TIERS] = [10_000, 0, 0];
+
+/// Maximum number of consolation tickets drawn per exploration
+pub const MAX_CONSOLATION_WINNERS: u8 = 16;
+
+/// Largest slice of R that may fund the block jackpot (10%)
+pub const MAX_JACKPOT_BPS: u16 = 1_000;
+
+/// Share of a losing deposit refunded when a block leaves it unset (50%)
+pub const DEFAULT_LOSER_REFUND_BPS: u16 = 5_000;
+
+/// Largest deposit fee a block may charge (5%)
+pub const MAX_DEPOSIT_FEE_BPS: u16 = 500;
+
+/// Where an exploration's rollover (funds carried forward from a rolled-over
+/// predecessor) goes at settlement
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[repr(u8)]
+pub enum RolloverPolicy {
+    /// Added to the remaining pool, so it is split like R: the winner share
+    /// and every treasury bucket
+    #[default]
+    AllBuckets,
+    /// Added to the winner pool on top of its share of R
+    WinnerPool,
+    /// Moved to the block's jackpot vault
+    Jackpot,
+}
+
+impl RolloverPolicy {
+    /// Decode a stored policy byte; unknown values read as AllBuckets
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::WinnerPool,
+            2 => Self::Jackpot,
+            _ => Self::AllBuckets,
+        }
+    }
+}
+
 #[account]
 #[derive(Default)]
 pub struct BlockState {
@@ -11,8 +58,10 @@ pub struct BlockState {
     pub asset_decimals: u8,
     /// Minimum pool threshold for draw to occur
     pub min_threshold: u64,
-    /// Current exploration index (increments each exploration)
-    pub current_exploration_index: u64,
+    /// Number of rigs in each exploration of this block
+    pub rig_count: u8,
+    /// Number of exploration tracks initialized for this block
+    pub track_count: u8,
     /// Block-specific pause state
     pub paused: bool,
     /// Total volume deposited through this block (u128 for overflow safety)
@@ -21,6 +70,31 @@ pub struct BlockState {
     pub total_explorations_completed: u64,
     /// PDA bump
     pub bump: u8,
+    /// Winner pool split across 1st/2nd/3rd drawn rigs in bps (unused tiers are 0)
+    pub prize_tier_bps: [u16; MAX_PRIZE_TIERS],
+    /// Share of the ecosystem allocation paid to losers as consolation bonuses (bps, 0 = off)
+    pub consolation_bps: u16,
+    /// Consolation tickets drawn over the losing rigs each exploration
+    pub consolation_winners: u8,
+    /// Where rollover goes at settlement, snapshotted by each exploration at start
+    pub rollover_policy: RolloverPolicy,
+    /// Slice of each exploration's R moved to the jackpot vault (bps, 0 = off)
+    pub jackpot_bps: u16,
+    /// Chance per settled exploration that the whole jackpot goes to the winning rig (bps)
+    pub jackpot_hit_bps: u16,
+    /// Number of explorations that hit the jackpot
+    pub jackpot_hits: u64,
+    /// Total paid out of the jackpot vault to winning rigs
+    pub total_jackpot_paid: u64,
+    /// Fee taken from each deposit into the deposit-fee treasury (bps, 0 = off)
+    pub deposit_fee_bps: u16,
+    /// $RIG balance from which holders get the fee discount (0 = no discount)
+    pub fee_discount_rig_balance: u64,
+    /// Share of the fee waived for qualifying holders (bps, 10000 = exempt)
+    pub fee_discount_bps: u16,
+    /// Share of a losing deposit refunded, snapshotted by each exploration at
+    /// start (bps, 0 = DEFAULT_LOSER_REFUND_BPS)
+    pub loser_refund_bps: u16,
 }
 
 impl BlockState {
@@ -29,15 +103,96 @@ impl BlockState {
         32 + // asset_mint
         1 +  // asset_decimals
         8 +  // min_threshold
-        8 +  // current_exploration_index
+        1 +  // rig_count
+        1 +  // track_count
         1 +  // paused
         16 + // total_volume
         8 +  // total_explorations_completed
         1 +  // bump
+        2 * MAX_PRIZE_TIERS + // prize_tier_bps
+        2 +  // consolation_bps
+        1 +  // consolation_winners
+        1 +  // rollover_policy
+        2 +  // jackpot_bps
+        2 +  // jackpot_hit_bps
+        8 +  // jackpot_hits
+        8 +  // total_jackpot_paid
+        2 +  // deposit_fee_bps
+        8 +  // fee_discount_rig_balance
+        2 +  // fee_discount_bps
+        2 +  // loser_refund_bps
         64;  // padding
 
     pub const SEED: &'static [u8] = b"block";
 
+    /// Prize tier split, defaulting to a single winner for blocks created
+    /// before tiers were configurable
+    pub fn prize_tier_bps(&self) -> [u16; MAX_PRIZE_TIERS] {
+        if self.prize_tier_bps == [0; MAX_PRIZE_TIERS] {
+            SINGLE_WINNER_PRIZE_TIERS
+        } else {
+            self.prize_tier_bps
+        }
+    }
+
+    /// Loser refund share, defaulting to 50% for blocks created before it
+    /// was configurable
+    pub fn loser_refund_bps(&self) -> u16 {
+        if self.loser_refund_bps == 0 {
+            DEFAULT_LOSER_REFUND_BPS
+        } else {
+            self.loser_refund_bps
+        }
+    }
+
+    /// Refund share must be a positive bps value
+    pub fn validate_loser_refund(loser_refund_bps: u16) -> bool {
+        loser_refund_bps > 0 && loser_refund_bps <= 10_000
+    }
+
+    /// Tiers must sum to 100%, start with a funded first prize and have no gaps
+    pub fn validate_prize_tiers(tiers: &[u16; MAX_PRIZE_TIERS]) -> bool {
+        let total: u32 = tiers.iter().map(|&bps| bps as u32).sum();
+        let contiguous = tiers.windows(2).all(|pair| pair[0] > 0 || pair[1] == 0);
+        total == 10_000 && tiers[0] > 0 && contiguous
+    }
+
+    /// Consolation share must be a valid bps value and the draw count bounded
+    pub fn validate_consolation(consolation_bps: u16, consolation_winners: u8) -> bool {
+        consolation_bps <= 10_000 && consolation_winners <= MAX_CONSOLATION_WINNERS
+    }
+
+    /// Jackpot slice must stay within MAX_JACKPOT_BPS and the hit chance a valid bps value
+    pub fn validate_jackpot(jackpot_bps: u16, jackpot_hit_bps: u16) -> bool {
+        jackpot_bps <= MAX_JACKPOT_BPS && jackpot_hit_bps <= 10_000
+    }
+
+    /// Whether settlement funds or rolls for the jackpot, and so needs its vault
+    pub fn uses_jackpot(&self) -> bool {
+        self.jackpot_bps > 0 ||
+            self.jackpot_hit_bps > 0 ||
+            self.rollover_policy == RolloverPolicy::Jackpot
+    }
+
+    /// Fee stays within MAX_DEPOSIT_FEE_BPS and the discount a valid bps value
+    pub fn validate_deposit_fee(deposit_fee_bps: u16, fee_discount_bps: u16) -> bool {
+        deposit_fee_bps <= MAX_DEPOSIT_FEE_BPS && fee_discount_bps <= 10_000
+    }
+
+    /// Fee taken from a deposit of `amount` by a depositor holding
+    /// `rig_balance` $RIG; holders at or above the discount threshold have
+    /// `fee_discount_bps` of it waived
+    pub fn deposit_fee(&self, amount: u64, rig_balance: u64) -> u64 {
+        let fee = amount as u128 * self.deposit_fee_bps as u128 / 10_000;
+        let discounted = self.fee_discount_rig_balance > 0 &&
+            rig_balance >= self.fee_discount_rig_balance;
+        if discounted {
+            (fee * (10_000 - self.fee_discount_bps as u128) / 10_000) as u64
+        } else {
+            fee as u64
+        }
+    }
+
     /// Returns the seeds for this block's PDA.
     /// The caller must pass in a buffer to hold the block_id byte.
     pub fn seeds_with_bump<'a>(&self, block_id_buf: &'a mut [u8; 1], bump: &'a [u8; 1]) -> [&'a [u8]; 3] {
@@ -52,3 +207,91 @@ pub mod block_ids {
     pub const PUMP_BLOCK: u8 = 1;
     pub const SKR_BLOCK: u8 = 2;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prize_tier_validation() {
+        assert!(BlockState::validate_prize_tiers(&SINGLE_WINNER_PRIZE_TIERS));
+        assert!(BlockState::validate_prize_tiers(&[7_000, 2_000, 1_000]));
+        assert!(BlockState::validate_prize_tiers(&[8_000, 2_000, 0]));
+        assert!(!BlockState::validate_prize_tiers(&[7_000, 2_000, 0]));
+        assert!(!BlockState::validate_prize_tiers(&[0, 10_000, 0]));
+        assert!(!BlockState::validate_prize_tiers(&[9_000, 0, 1_000]));
+    }
+
+    #[test]
+    fn test_unset_prize_tiers_default_to_single_winner() {
+        let block = BlockState::default();
+        assert_eq!(block.prize_tier_bps(), SINGLE_WINNER_PRIZE_TIERS);
+    }
+
+    #[test]
+    fn test_unset_loser_refund_defaults_to_half() {
+        let mut block = BlockState::default();
+        assert_eq!(block.loser_refund_bps(), DEFAULT_LOSER_REFUND_BPS);
+        block.loser_refund_bps = 8_000;
+        assert_eq!(block.loser_refund_bps(), 8_000);
+
+        assert!(BlockState::validate_loser_refund(10_000));
+        assert!(!BlockState::validate_loser_refund(0));
+        assert!(!BlockState::validate_loser_refund(10_001));
+    }
+
+    #[test]
+    fn test_jackpot_validation() {
+        assert!(BlockState::validate_jackpot(0, 0));
+        assert!(BlockState::validate_jackpot(MAX_JACKPOT_BPS, 10_000));
+        assert!(!BlockState::validate_jackpot(MAX_JACKPOT_BPS + 1, 10));
+        assert!(!BlockState::validate_jackpot(100, 10_001));
+    }
+
+    #[test]
+    fn test_uses_jackpot() {
+        let mut block = BlockState::default();
+        assert!(!block.uses_jackpot());
+        // A hit chance alone needs the vault, even with nothing funding it
+        block.jackpot_hit_bps = 1;
+        assert!(block.uses_jackpot());
+        block.jackpot_hit_bps = 0;
+        block.rollover_policy = RolloverPolicy::Jackpot;
+        assert!(block.uses_jackpot());
+    }
+
+    #[test]
+    fn test_deposit_fee_with_holder_discount() {
+        let mut block = BlockState::default();
+        assert_eq!(block.deposit_fee(1_000_000, 0), 0);
+
+        block.deposit_fee_bps = 100;
+        assert_eq!(block.deposit_fee(1_000_000, u64::MAX), 10_000);
+
+        // Half off at or above the threshold, full fee below it
+        block.fee_discount_rig_balance = 5_000;
+        block.fee_discount_bps = 5_000;
+        assert_eq!(block.deposit_fee(1_000_000, 4_999), 10_000);
+        assert_eq!(block.deposit_fee(1_000_000, 5_000), 5_000);
+
+        // Exempt
+        block.fee_discount_bps = 10_000;
+        assert_eq!(block.deposit_fee(1_000_000, 5_000), 0);
+
+        assert!(BlockState::validate_deposit_fee(MAX_DEPOSIT_FEE_BPS, 10_000));
+        assert!(!BlockState::validate_deposit_fee(MAX_DEPOSIT_FEE_BPS + 1, 0));
+        assert!(!BlockState::validate_deposit_fee(100, 10_001));
+    }
+
+    #[test]
+    fn test_rollover_policy_round_trip() {
+        for policy in [
+            RolloverPolicy::AllBuckets,
+            RolloverPolicy::WinnerPool,
+            RolloverPolicy::Jackpot,
+        ] {
+            assert_eq!(RolloverPolicy::from_u8(policy as u8), policy);
+        }
+        assert_eq!(BlockState::default().rollover_policy, RolloverPolicy::AllBuckets);
+    }
+}
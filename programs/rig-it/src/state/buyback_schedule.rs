@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::RigItError;
+use crate::utils::derive_buyback_random;
+
+/// Number of tranches each exploration's buyback is split into
+pub const MAX_BUYBACK_TRANCHES: usize = 4;
+/// The buyback window opens 2-5 hours after the exploration's active phase ends
+pub const BUYBACK_MIN_DELAY_SECS: i64 = 2 * 60 * 60;
+pub const BUYBACK_DELAY_SPREAD_SECS: i64 = 3 * 60 * 60;
+/// Length of the buyback window
+pub const BUYBACK_WINDOW_SECS: i64 = 60 * 60;
+/// Grace period after the window closes during which missed tranches may
+/// still execute
+pub const BUYBACK_CATCH_UP_SECS: i64 = 24 * 60 * 60;
+
+/// Buyback schedule for a settled exploration, derived from its revealed
+/// random value at settlement and funded when the buyback budget is allocated.
+/// Execute-buyback instructions spend one tranche at a time, each from its
+/// start time until BUYBACK_CATCH_UP_SECS after the window closes. The grace
+/// period lets a late allocation or a missed tranche catch up; past it the
+/// tranche has expired and its share stays in the buyback treasury.
+#[account]
+#[derive(Default)]
+pub struct BuybackSchedule {
+    /// Exploration whose buyback budget this schedules
+    pub exploration: Pubkey,
+    /// Block whose treasuries hold the budget
+    pub block_id: u8,
+    /// Amount allocated to the buyback-burn treasury
+    pub burn_budget: u64,
+    /// Amount allocated to the buyback-LP treasury
+    pub lp_budget: u64,
+    /// Window open timestamp
+    pub window_start_ts: i64,
+    /// Window close timestamp: every tranche has opened by then
+    pub window_end_ts: i64,
+    /// Timestamp from which each tranche may execute
+    pub tranche_start_ts: [i64; MAX_BUYBACK_TRANCHES],
+    /// Share of each budget spent by each tranche (sums to 10000)
+    pub tranche_bps: [u16; MAX_BUYBACK_TRANCHES],
+    /// Bitmask of burn tranches already executed
+    pub burn_executed: u8,
+    /// Bitmask of LP tranches already executed
+    pub lp_executed: u8,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl BuybackSchedule {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // exploration
+        1 +  // block_id
+        8 +  // burn_budget
+        8 +  // lp_budget
+        8 +  // window_start_ts
+        8 +  // window_end_ts
+        8 * MAX_BUYBACK_TRANCHES + // tranche_start_ts
+        2 * MAX_BUYBACK_TRANCHES + // tranche_bps
+        1 +  // burn_executed
+        1 +  // lp_executed
+        1 +  // bump
+        32;  // padding
+
+    pub const SEED: &'static [u8] = b"buyback_schedule";
+
+    /// Derive the window and tranches from the exploration's random value,
+    /// relative to the end of its active phase
+    pub fn derive(&mut self, random_value: &[u8; 32], active_end_ts: i64) {
+        let random = derive_buyback_random(random_value);
+        let word = |i: usize| u64::from_le_bytes(random[i * 8..i * 8 + 8].try_into().unwrap());
+
+        let delay = (word(0) % BUYBACK_DELAY_SPREAD_SECS as u64) as i64;
+        self.window_start_ts = active_end_ts + BUYBACK_MIN_DELAY_SECS + delay;
+        self.window_end_ts = self.window_start_ts + BUYBACK_WINDOW_SECS;
+
+        // One tranche per equal slice of the window, at a random offset within it
+        let slice = BUYBACK_WINDOW_SECS / MAX_BUYBACK_TRANCHES as i64;
+        let offsets = word(1).to_le_bytes();
+        for (i, start) in self.tranche_start_ts.iter_mut().enumerate() {
+            let offset = u16::from_le_bytes([offsets[2 * i], offsets[2 * i + 1]]) as i64 % slice;
+            *start = self.window_start_ts + i as i64 * slice + offset;
+        }
+
+        // Tranche sizes weighted 50-150, the last taking the rounding remainder
+        let weights: Vec<u64> = random[16..16 + MAX_BUYBACK_TRANCHES]
+            .iter()
+            .map(|&b| 50 + b as u64 % 101)
+            .collect();
+        let total: u64 = weights.iter().sum();
+        let mut allocated = 0u16;
+        for (i, bps) in self.tranche_bps.iter_mut().enumerate() {
+            *bps = if i + 1 == MAX_BUYBACK_TRANCHES {
+                10000 - allocated
+            } else {
+                (weights[i] * 10000 / total) as u16
+            };
+            allocated += *bps;
+        }
+    }
+
+    /// Amount of `budget` spent by `tranche`; the last tranche takes the
+    /// rounding remainder so the full budget is spent
+    pub fn tranche_amount(&self, budget: u64, tranche: u8) -> u64 {
+        let tranche = tranche as usize;
+        if tranche + 1 == MAX_BUYBACK_TRANCHES {
+            let earlier: u64 = (0..tranche)
+                .map(|t| self.tranche_amount(budget, t as u8))
+                .sum();
+            return budget - earlier;
+        }
+        (budget as u128 * self.tranche_bps[tranche] as u128 / 10000) as u64
+    }
+
+    /// Check `tranche` is open at `current_ts` and has not yet run for the
+    /// bucket whose executed bitmask is given. A tranche opens at its start
+    /// and expires BUYBACK_CATCH_UP_SECS after the window closes.
+    pub fn check_tranche(&self, tranche: u8, executed: u8, current_ts: i64) -> Result<()> {
+        require!(
+            (tranche as usize) < MAX_BUYBACK_TRANCHES,
+            RigItError::InvalidBuybackTranche
+        );
+        require!(
+            executed & (1 << tranche) == 0,
+            RigItError::BuybackTrancheExecuted
+        );
+        require!(
+            current_ts >= self.tranche_start_ts[tranche as usize],
+            RigItError::OutsideBuybackWindow
+        );
+        require!(
+            current_ts <= self.window_end_ts + BUYBACK_CATCH_UP_SECS,
+            RigItError::BuybackTrancheExpired
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule() -> BuybackSchedule {
+        let mut schedule = BuybackSchedule::default();
+        schedule.derive(&[0x77u8; 32], 1_000_000);
+        schedule
+    }
+
+    #[test]
+    fn test_window_within_bounds() {
+        let schedule = schedule();
+        let delay = schedule.window_start_ts - 1_000_000;
+        assert!((BUYBACK_MIN_DELAY_SECS..BUYBACK_MIN_DELAY_SECS + BUYBACK_DELAY_SPREAD_SECS)
+            .contains(&delay));
+        assert_eq!(schedule.window_end_ts - schedule.window_start_ts, BUYBACK_WINDOW_SECS);
+
+        // Tranches open in order, each inside its slice of the window
+        let slice = BUYBACK_WINDOW_SECS / MAX_BUYBACK_TRANCHES as i64;
+        for (i, &start) in schedule.tranche_start_ts.iter().enumerate() {
+            let slice_start = schedule.window_start_ts + i as i64 * slice;
+            assert!((slice_start..slice_start + slice).contains(&start));
+        }
+
+        // Different random values give different windows
+        let mut other = BuybackSchedule::default();
+        other.derive(&[0x78u8; 32], 1_000_000);
+        assert_ne!(other.window_start_ts, schedule.window_start_ts);
+    }
+
+    #[test]
+    fn test_tranches_spend_full_budget() {
+        let schedule = schedule();
+        assert_eq!(schedule.tranche_bps.iter().map(|&b| b as u32).sum::<u32>(), 10000);
+        assert!(schedule.tranche_bps.iter().all(|&b| b > 0));
+
+        let budget = 1_000_003;
+        let total: u64 = (0..MAX_BUYBACK_TRANCHES as u8)
+            .map(|t| schedule.tranche_amount(budget, t))
+            .sum();
+        assert_eq!(total, budget);
+    }
+
+    #[test]
+    fn test_tranche_open_from_start_through_catch_up() {
+        let schedule = schedule();
+        let start = schedule.tranche_start_ts[1];
+        assert!(schedule.check_tranche(1, 0, start - 1).is_err());
+        assert!(schedule.check_tranche(1, 0, start).is_ok());
+        // Missed tranches can catch up for a bounded time after the window closes
+        let expiry = schedule.window_end_ts + BUYBACK_CATCH_UP_SECS;
+        assert!(schedule.check_tranche(1, 0, schedule.window_end_ts).is_ok());
+        assert!(schedule.check_tranche(0, 0, expiry).is_ok());
+        assert!(schedule.check_tranche(0, 0, expiry + 1).is_err());
+        // Already executed
+        assert!(schedule.check_tranche(1, 0b10, start).is_err());
+        assert!(schedule.check_tranche(1, 0b01, start).is_ok());
+        // Out of range
+        assert!(schedule.check_tranche(MAX_BUYBACK_TRANCHES as u8, 0, start).is_err());
+    }
+}
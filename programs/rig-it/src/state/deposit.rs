@@ -34,6 +34,13 @@ pub struct DepositReceipt {
 
     /// PDA bump
     pub bump: u8,
+
+    /// Position of this deposit's first ticket within its rig (consolation draw)
+    pub ticket_offset: u128,
+
+    // Referral
+    /// Wallet of the referrer this deposit was made through
+    pub referrer: Option<Pubkey>,
 }
 
 impl DepositReceipt {
@@ -51,6 +58,8 @@ impl DepositReceipt {
         1 +  // refund_claimed
         1 +  // winnings_claimed
         1 +  // bump
+        16 + // ticket_offset
+        33 + // referrer (Option<Pubkey>)
         32;  // padding
 
     pub const SEED: &'static [u8] = b"deposit";
@@ -73,6 +82,14 @@ pub struct UserStats {
     pub rig_balance_snapshot: u64,
     /// PDA bump
     pub bump: u8,
+
+    // Referral (as a referrer)
+    /// Volume deposited by users this wallet referred
+    pub referred_volume: u128,
+    /// Number of deposits made through this wallet's referrals
+    pub referred_deposits: u64,
+    /// Referral rewards claimed
+    pub referral_rewards: u128,
 }
 
 impl UserStats {
@@ -84,6 +101,9 @@ impl UserStats {
         16 + // total_refunds
         8 +  // rig_balance_snapshot
         1 +  // bump
+        16 + // referred_volume
+        8 +  // referred_deposits
+        16 + // referral_rewards
         32;  // padding
 
     pub const SEED: &'static [u8] = b"user_stats";
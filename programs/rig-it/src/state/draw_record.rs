@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ExplorationState, MAX_PRIZE_TIERS};
+
+/// Audit record of how an exploration's winner was drawn.
+/// Holds every input to the draw so anyone can re-derive the winner.
+#[account]
+#[derive(Default)]
+pub struct DrawRecord {
+    /// Exploration this draw settled
+    pub exploration: Pubkey,
+    /// Parent block ID
+    pub block_id: u8,
+    /// Parent track ID
+    pub track_id: u8,
+    /// Exploration index within the track
+    pub exploration_index: u64,
+    /// Version of the selection algorithm (DRAW_ALGORITHM_VERSION)
+    pub algorithm_version: u8,
+    /// True if settled by the timeout fallback instead of an operator reveal
+    pub used_fallback: bool,
+    /// Revealed operator secret (zero for fallback draws)
+    pub operator_secret: [u8; 32],
+    /// Operator commit hash
+    pub commit_hash: [u8; 32],
+    /// Commit scheme the commit hash was made with
+    pub commit_scheme: u8,
+    /// Committed target slot
+    pub target_slot: u64,
+    /// Slot whose hash was used by the fallback (0 for operator reveals)
+    pub fallback_slot: u64,
+    /// Slot hash mixed into the random value
+    pub slot_hash: [u8; 32],
+    /// Depositor entropy accumulator at commit
+    pub entropy_accumulator: [u8; 32],
+    /// Final random value
+    pub random_value: [u8; 32],
+    /// Hash of the per-rig ticket counts the winner was selected from
+    pub ticket_snapshot_hash: [u8; 32],
+    /// Number of rigs in the draw
+    pub rig_count: u8,
+    /// Selected rig (first prize)
+    pub winning_rig: u8,
+    /// Rig drawn for each prize tier (NO_WINNING_RIG for undrawn tiers)
+    pub prize_rigs: [u8; MAX_PRIZE_TIERS],
+    /// Unix timestamp of the draw
+    pub drawn_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DrawRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // exploration
+        1 +  // block_id
+        1 +  // track_id
+        8 +  // exploration_index
+        1 +  // algorithm_version
+        1 +  // used_fallback
+        32 + // operator_secret
+        32 + // commit_hash
+        1 +  // commit_scheme
+        8 +  // target_slot
+        8 +  // fallback_slot
+        32 + // slot_hash
+        32 + // entropy_accumulator
+        32 + // random_value
+        32 + // ticket_snapshot_hash
+        1 +  // rig_count
+        1 +  // winning_rig
+        MAX_PRIZE_TIERS + // prize_rigs
+        8 +  // drawn_at
+        1 +  // bump
+        64;  // padding for future fields
+
+    pub const SEED: &'static [u8] = b"draw_record";
+
+    /// Copy the draw inputs held on the exploration
+    pub fn record_exploration(&mut self, exploration_key: Pubkey, exploration: &ExplorationState) {
+        self.exploration = exploration_key;
+        self.block_id = exploration.block_id;
+        self.track_id = exploration.track_id;
+        self.exploration_index = exploration.exploration_index;
+        self.commit_hash = exploration.commit_hash;
+        self.commit_scheme = exploration.commit_scheme;
+        self.target_slot = exploration.commit_slot;
+        self.entropy_accumulator = exploration.entropy_accumulator;
+        self.rig_count = exploration.rig_count;
+    }
+}
@@ -1,16 +1,110 @@
+use std::cell::{Ref, RefMut};
+use std::mem::size_of;
+
 use anchor_lang::prelude::*;
 
-/// Number of rigs per exploration
-pub const RIGS_PER_EXPLORATION: usize = 36;
+use crate::errors::RigItError;
+use crate::state::{RolloverPolicy, MAX_PRIZE_TIERS};
+use crate::utils::{
+    accumulate_entropy, hash_commit_snapshot, jackpot_roll, select_consolation_tickets,
+    CommitContext,
+};
+
+/// Default number of rigs per exploration
+pub const DEFAULT_RIGS_PER_EXPLORATION: u8 = 36;
+/// Smallest configurable rig count (a draw needs at least two rigs)
+pub const MIN_RIGS_PER_EXPLORATION: u8 = 2;
+/// Largest configurable rig count
+pub const MAX_RIGS_PER_EXPLORATION: u8 = 64;
+
+/// Layout version written to the header of every exploration account
+pub const EXPLORATION_LAYOUT_VERSION: u8 = 2;
+
+/// Slots between re-committing an aged-out fallback slot and the new fallback slot
+pub const FALLBACK_RECOMMIT_DELAY_SLOTS: u64 = 8;
+
+/// Sentinel stored in `winning_rig` until the exploration is settled
+pub const NO_WINNING_RIG: u8 = u8::MAX;
+
+/// Bit flags stored in `ExplorationState::flags`
+pub mod exploration_flags {
+    pub const RANDOM_REVEALED: u8 = 1 << 0;
+    pub const LOSER_REFUNDS_PROCESSED: u8 = 1 << 1;
+    pub const WINNER_DISTRIBUTION_PROCESSED: u8 = 1 << 2;
+    pub const BUYBACK_ALLOCATED: u8 = 1 << 3;
+    pub const CARRY_FORWARD_DONE: u8 = 1 << 4;
+    pub const DUST_SWEPT: u8 = 1 << 5;
+}
+
+/// u128 stored as little-endian bytes so it has an alignment of 1.
+/// `u128` itself is 16-byte aligned on the host but 8-byte aligned on SBF,
+/// which would give the zero-copy layout different padding on each target.
+#[zero_copy]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct PodU128 {
+    pub bytes: [u8; 16],
+}
+
+impl PodU128 {
+    pub fn get(&self) -> u128 {
+        u128::from_le_bytes(self.bytes)
+    }
+
+    pub fn set(&mut self, value: u128) {
+        self.bytes = value.to_le_bytes();
+    }
+}
 
-#[account]
+impl From<u128> for PodU128 {
+    fn from(value: u128) -> Self {
+        Self { bytes: value.to_le_bytes() }
+    }
+}
+
+/// Deposit and ticket totals of one rig.
+/// An exploration account stores `rig_count` of these right after the
+/// `ExplorationState` fields, so accounts are sized by their rig count.
+#[zero_copy]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct RigTotals {
+    /// Deposits in the rig
+    pub deposits: u64,
+    /// Weighted ticket count of the rig
+    pub tickets: PodU128,
+}
+
+/// Ticket totals of `rigs`, in rig order
+pub fn rig_tickets(rigs: &[RigTotals]) -> impl ExactSizeIterator<Item = u128> + Clone + '_ {
+    rigs.iter().map(|rig| rig.tickets.get())
+}
+
+/// Zero-copy exploration round state.
+/// Fields are ordered so the `repr(C)` layout has no implicit padding: an 8-byte
+/// header of single-byte fields, then 8-byte words, then byte arrays.
+/// The account data continues with `rig_count` `RigTotals` entries; load both
+/// with `load_with_rigs` / `load_with_rigs_mut`.
+#[account(zero_copy)]
 pub struct ExplorationState {
+    // Header
+    /// Layout version (EXPLORATION_LAYOUT_VERSION)
+    pub version: u8,
+    /// PDA bump
+    pub bump: u8,
     /// Parent block ID
     pub block_id: u8,
-    /// Sequential exploration index
+    /// Parent track ID within the block
+    pub track_id: u8,
+    /// Current status (ExplorationStatus as u8)
+    pub status: u8,
+    /// Number of rigs, snapshotted from the block at start
+    pub rig_count: u8,
+    /// Winning rig index (< rig_count), NO_WINNING_RIG if not settled
+    pub winning_rig: u8,
+    /// Progress flags (see `exploration_flags`)
+    pub flags: u8,
+
+    /// Sequential exploration index within the track
     pub exploration_index: u64,
-    /// Current status
-    pub status: ExplorationStatus,
 
     // Timing
     /// Slot when exploration was started
@@ -25,114 +119,597 @@ pub struct ExplorationState {
     // Deposits
     /// Total deposits across all rigs (excluding rollover)
     pub total_deposits: u64,
-    /// Deposits per rig
-    pub rig_deposits: [u64; RIGS_PER_EXPLORATION],
-    /// Weighted ticket count per rig (u128 for precision)
-    pub rig_tickets: [u128; RIGS_PER_EXPLORATION],
     /// Amount rolled over from previous exploration
     pub rollover_amount: u64,
 
     // Randomness (commit-reveal)
     /// Target slot for randomness reveal
     pub commit_slot: u64,
-    /// Hash of (secret || target_slot)
-    pub commit_hash: [u8; 32],
     /// Deadline slot for reveal (after which fallback kicks in)
     pub reveal_deadline_slot: u64,
-    /// Revealed random value (set after reveal)
-    pub revealed_random: Option<[u8; 32]>,
-
-    // Result
-    /// Winning rig index (0-35), None if not settled
-    pub winning_rig: Option<u8>,
-
-    // Payout tracking flags
-    pub loser_refunds_processed: bool,
-    pub winner_distribution_processed: bool,
-    pub buyback_allocated: bool,
-    pub carry_forward_done: bool,
+    /// Slot whose hash seeds the timeout fallback, re-committed after the
+    /// deadline's hash aged out (0 = the reveal deadline slot)
+    pub fallback_slot: u64,
 
     // Accounting (set after settlement)
-    /// Total deposits in winning rig (W)
+    /// Total deposits in prize rigs (W, excluding rollover)
     pub total_winner_deposits: u64,
     /// Total deposits in losing rigs (L)
     pub total_loser_deposits: u64,
-    /// Remaining pool after refunds: R = W + 0.5L
+    /// Remaining pool after refunds: R = W + L * (1 - loser refund), plus rollover under the
+    /// all-buckets rollover policy, less the jackpot contribution
     pub remaining_pool: u64,
 
-    /// PDA bump
-    pub bump: u8,
-}
+    /// Hash of (secret || target_slot)
+    pub commit_hash: [u8; 32],
+    /// Revealed random value (valid once RANDOM_REVEALED is set)
+    pub revealed_random: [u8; 32],
 
-impl Default for ExplorationState {
-    fn default() -> Self {
-        Self {
-            block_id: 0,
-            exploration_index: 0,
-            status: ExplorationStatus::default(),
-            start_slot: 0,
-            start_ts: 0,
-            active_end_ts: 0,
-            cooldown_end_ts: 0,
-            total_deposits: 0,
-            rig_deposits: [0u64; RIGS_PER_EXPLORATION],
-            rig_tickets: [0u128; RIGS_PER_EXPLORATION],
-            rollover_amount: 0,
-            commit_slot: 0,
-            commit_hash: [0u8; 32],
-            reveal_deadline_slot: 0,
-            revealed_random: None,
-            winning_rig: None,
-            loser_refunds_processed: false,
-            winner_distribution_processed: false,
-            buyback_allocated: false,
-            carry_forward_done: false,
-            total_winner_deposits: 0,
-            total_loser_deposits: 0,
-            remaining_pool: 0,
-            bump: 0,
-        }
-    }
+    /// Hash chain of depositor entropy commitments, frozen at commit
+    pub entropy_accumulator: [u8; 32],
+    /// Hash of rig deposits, rig tickets and rollover, taken at commit
+    pub commit_snapshot_hash: [u8; 32],
+
+    /// Operator bond locked by the randomness commit
+    pub operator_bond_at_stake: u64,
+    /// Bond slashed into the winner pool after a missed reveal
+    pub operator_slash_amount: u64,
+    /// Slice of the ecosystem allocation paid out as consolation bonuses to losers
+    pub consolation_pool: u64,
+    /// Amount paid out of the vault for this exploration (claims, refunds,
+    /// buckets and swept dust)
+    pub total_paid: u64,
+    /// Rounding dust swept to the ecosystem treasury
+    pub dust_swept: u64,
+    /// Slice of R moved to the block's jackpot vault at settlement
+    pub jackpot_contribution: u64,
+    /// Jackpot paid into this exploration's first prize (0 unless the jackpot hit)
+    pub jackpot_won: u64,
+    /// Deposits made through a referrer
+    pub referred_volume: u64,
+    /// Slice of the team-ops allocation left in the vault for referrers
+    pub referral_pool: u64,
+    /// Referral rewards claimed out of the referral pool
+    pub referral_paid: u64,
+
+    /// Number of deposits that contributed entropy
+    pub entropy_contributions: u32,
+    /// Deposit receipts counted in this exploration's rigs
+    pub receipt_count: u32,
+    /// Receipts that have claimed winnings or a refund
+    pub receipts_closed: u32,
+    /// Anti-snipe window before `active_end_ts`, snapshotted from the track at start
+    pub anti_snipe_window_secs: u32,
+    /// Commit scheme the operator commitment was made with (0 before commit)
+    pub commit_scheme: u8,
+    /// Rig drawn for each prize tier, NO_WINNING_RIG for undrawn tiers
+    pub prize_rigs: [u8; MAX_PRIZE_TIERS],
+    /// Winner pool split per prize tier in bps, snapshotted from the block at start
+    pub prize_tier_bps: [u16; MAX_PRIZE_TIERS],
+
+    /// Number of consolation tickets drawn over the losing rigs
+    pub consolation_winners: u8,
+
+    /// Rollover policy (RolloverPolicy as u8), snapshotted from the block at start
+    pub rollover_policy: u8,
+    /// Slice of R funding the block jackpot in bps, snapshotted from the block at start
+    pub jackpot_bps: u16,
+    /// Chance of the jackpot hitting in bps, snapshotted from the block at start
+    pub jackpot_hit_bps: u16,
+    /// Share of a losing deposit refunded in bps, snapshotted from the block at start
+    pub loser_refund_bps: u16,
+
+    /// Reserved for future fields
+    pub reserved: [u8; 6],
 }
 
 impl ExplorationState {
-    pub const LEN: usize = 8 + // discriminator
-        1 +   // block_id
-        8 +   // exploration_index
-        1 +   // status
-        8 +   // start_slot
-        8 +   // start_ts
-        8 +   // active_end_ts
-        8 +   // cooldown_end_ts
-        8 +   // total_deposits
-        (8 * RIGS_PER_EXPLORATION) + // rig_deposits
-        (16 * RIGS_PER_EXPLORATION) + // rig_tickets
-        8 +   // rollover_amount
-        8 +   // commit_slot
-        32 +  // commit_hash
-        8 +   // reveal_deadline_slot
-        33 +  // revealed_random (Option<[u8;32]>)
-        2 +   // winning_rig (Option<u8>)
-        1 +   // loser_refunds_processed
-        1 +   // winner_distribution_processed
-        1 +   // buyback_allocated
-        1 +   // carry_forward_done
-        8 +   // total_winner_deposits
-        8 +   // total_loser_deposits
-        8 +   // remaining_pool
-        1 +   // bump
-        64;   // padding
+    /// Size of the fixed part of the account (discriminator + fields)
+    pub const LEN: usize = 8 + size_of::<ExplorationState>();
+
+    /// Account size for an exploration with `rig_count` rigs
+    pub const fn space(rig_count: u8) -> usize {
+        Self::LEN + rig_count as usize * size_of::<RigTotals>()
+    }
+
+    /// Load an exploration and its rig totals for reading
+    pub fn load_with_rigs<'a>(
+        loader: &'a AccountLoader<'_, ExplorationState>,
+    ) -> Result<(Ref<'a, ExplorationState>, Ref<'a, [RigTotals]>)> {
+        let data = loader.as_ref().try_borrow_data()?;
+        let rigs_len = Self::rigs_len(&data)?;
+        Ok(Ref::map_split(data, |data| {
+            let (head, tail) = data.split_at(Self::LEN);
+            (
+                bytemuck::from_bytes(&head[8..]),
+                bytemuck::cast_slice(&tail[..rigs_len]),
+            )
+        }))
+    }
+
+    /// Load an exploration and its rig totals for writing
+    pub fn load_with_rigs_mut<'a>(
+        loader: &'a AccountLoader<'_, ExplorationState>,
+    ) -> Result<(RefMut<'a, ExplorationState>, RefMut<'a, [RigTotals]>)> {
+        let info = loader.as_ref();
+        require!(info.is_writable, ErrorCode::AccountNotMutable);
+        let data = info.try_borrow_mut_data()?;
+        let rigs_len = Self::rigs_len(&data)?;
+        Ok(RefMut::map_split(data, |data| {
+            let (head, tail) = data.split_at_mut(Self::LEN);
+            (
+                bytemuck::from_bytes_mut(&mut head[8..]),
+                bytemuck::cast_slice_mut(&mut tail[..rigs_len]),
+            )
+        }))
+    }
+
+    /// Check the discriminator and return the byte length of the rig totals
+    fn rigs_len(data: &[u8]) -> Result<usize> {
+        require!(data.len() >= Self::LEN, ErrorCode::AccountDidNotDeserialize);
+        require!(
+            data[..8] == *Self::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        let state: &ExplorationState = bytemuck::from_bytes(&data[8..Self::LEN]);
+        let rigs_len = state.rig_count as usize * size_of::<RigTotals>();
+        require!(
+            data.len() >= Self::LEN + rigs_len,
+            ErrorCode::AccountDidNotDeserialize
+        );
+        Ok(rigs_len)
+    }
 
     pub const SEED: &'static [u8] = b"exploration";
 
+    /// Current status
+    pub fn status(&self) -> ExplorationStatus {
+        ExplorationStatus::from_u8(self.status)
+    }
+
+    pub fn set_status(&mut self, status: ExplorationStatus) {
+        self.status = status as u8;
+    }
+
+    pub fn has_flag(&self, flag: u8) -> bool {
+        self.flags & flag != 0
+    }
+
+    pub fn set_flag(&mut self, flag: u8) {
+        self.flags |= flag;
+    }
+
+    /// Winning rig, None if not settled
+    pub fn winning_rig(&self) -> Option<u8> {
+        (self.winning_rig != NO_WINNING_RIG).then_some(self.winning_rig)
+    }
+
+    /// Slot whose hash seeds the timeout fallback
+    pub fn fallback_slot(&self) -> u64 {
+        if self.fallback_slot != 0 {
+            self.fallback_slot
+        } else {
+            self.reveal_deadline_slot
+        }
+    }
+
+    /// Revealed random value, None before settlement
+    pub fn revealed_random(&self) -> Option<[u8; 32]> {
+        self.has_flag(exploration_flags::RANDOM_REVEALED)
+            .then_some(self.revealed_random)
+    }
+
+    pub fn set_revealed_random(&mut self, random_value: [u8; 32]) {
+        self.revealed_random = random_value;
+        self.set_flag(exploration_flags::RANDOM_REVEALED);
+    }
+
+    /// Where this exploration's rollover goes at settlement
+    pub fn rollover_policy(&self) -> RolloverPolicy {
+        RolloverPolicy::from_u8(self.rollover_policy)
+    }
+
+    /// Rollover paid to prize rigs on top of their share of R
+    pub fn winner_pool_rollover(&self) -> u64 {
+        match self.rollover_policy() {
+            RolloverPolicy::WinnerPool => self.rollover_amount,
+            _ => 0,
+        }
+    }
+
+    /// Rollover moved to the block's jackpot vault at settlement
+    pub fn jackpot_rollover(&self) -> u64 {
+        match self.rollover_policy() {
+            RolloverPolicy::Jackpot => self.rollover_amount,
+            _ => 0,
+        }
+    }
+
+    /// Pool split across the prize tiers: the winner share of R, any slashed
+    /// operator bond and rollover under the winner-pool policy
+    pub fn winner_pool(&self, winner_share_bps: u16) -> Result<u128> {
+        let share = (self.remaining_pool as u128)
+            .checked_mul(winner_share_bps as u128)
+            .ok_or(RigItError::ArithmeticOverflow)?
+            / 10000;
+        share
+            .checked_add(self.operator_slash_amount as u128)
+            .and_then(|pool| pool.checked_add(self.winner_pool_rollover() as u128))
+            .ok_or(RigItError::ArithmeticOverflow.into())
+    }
+
+    /// Pool paid to a prize tier: its share of the winner pool, plus the
+    /// whole jackpot for the first prize if the jackpot hit
+    pub fn tier_pool(&self, tier: usize, winner_share_bps: u16) -> Result<u128> {
+        let pool = self
+            .winner_pool(winner_share_bps)?
+            .checked_mul(self.prize_tier_share_bps(tier) as u128)
+            .ok_or(RigItError::ArithmeticOverflow)?
+            / 10000;
+        let jackpot = if tier == 0 { self.jackpot_won as u128 } else { 0 };
+        pool.checked_add(jackpot)
+            .ok_or(RigItError::ArithmeticOverflow.into())
+    }
+
+    /// Move the jackpot slice out of R. Call after `settle` and before the
+    /// consolation pool is funded, so every split applies to what remains.
+    pub fn fund_jackpot(&mut self) -> Result<()> {
+        let contribution = (self.remaining_pool as u128)
+            .checked_mul(self.jackpot_bps as u128)
+            .ok_or(RigItError::ArithmeticOverflow)?
+            / 10000;
+        self.jackpot_contribution = contribution as u64;
+        self.remaining_pool = self
+            .remaining_pool
+            .checked_sub(self.jackpot_contribution)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Whether the revealed random value hits the jackpot
+    pub fn jackpot_hit(&self) -> bool {
+        match self.revealed_random() {
+            Some(random_value) => jackpot_roll(&random_value) < self.jackpot_hit_bps,
+            None => false,
+        }
+    }
+
+    /// Add a deposit and its tickets to a rig's totals. Returns the deposit's
+    /// ticket offset within the rig (the rig's tickets before this deposit).
+    pub fn add_to_rig(
+        &mut self,
+        rigs: &mut [RigTotals],
+        rig_index: u8,
+        amount: u64,
+        tickets: u128,
+    ) -> Result<u128> {
+        let rig = rigs
+            .get_mut(rig_index as usize)
+            .ok_or(RigItError::InvalidRigIndex)?;
+        self.total_deposits = self
+            .total_deposits
+            .checked_add(amount)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        rig.deposits = rig
+            .deposits
+            .checked_add(amount)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        let ticket_offset = rig.tickets.get();
+        let rig_tickets = ticket_offset
+            .checked_add(tickets)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        rig.tickets.set(rig_tickets);
+        self.receipt_count = self
+            .receipt_count
+            .checked_add(1)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        Ok(ticket_offset)
+    }
+
+    /// Everything the vault holds for this exploration once settled:
+    /// deposits, rollover, any slashed operator bond and any jackpot won
+    pub fn total_entitled(&self) -> Result<u64> {
+        self.total_deposits
+            .checked_add(self.rollover_amount)
+            .and_then(|total| total.checked_add(self.operator_slash_amount))
+            .and_then(|total| total.checked_add(self.jackpot_won))
+            .ok_or(RigItError::ArithmeticOverflow.into())
+    }
+
+    /// Record an amount paid out of the vault for this exploration
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        self.total_paid = self
+            .total_paid
+            .checked_add(amount)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        require!(
+            self.total_paid <= self.total_entitled()?,
+            RigItError::ArithmeticOverflow
+        );
+        Ok(())
+    }
+
+    /// Record a receipt claiming its winnings or refund
+    pub fn close_receipt(&mut self) -> Result<()> {
+        self.receipts_closed = self
+            .receipts_closed
+            .checked_add(1)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Rounding remainder left in the vault, once every receipt is claimed and
+    /// the buckets are allocated; None while payouts are still outstanding.
+    /// Unclaimed referral rewards stay reserved and are not dust.
+    pub fn residual_dust(&self) -> Result<Option<u64>> {
+        if self.receipts_closed < self.receipt_count ||
+            !self.has_flag(exploration_flags::BUYBACK_ALLOCATED)
+        {
+            return Ok(None);
+        }
+        let referral_unclaimed = self
+            .referral_pool
+            .checked_sub(self.referral_paid)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        let dust = self
+            .total_entitled()?
+            .checked_sub(self.total_paid)
+            .and_then(|left| left.checked_sub(referral_unclaimed))
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        Ok(Some(dust))
+    }
+
+    /// Count a deposit made through a referrer
+    pub fn add_referred(&mut self, amount: u64) -> Result<()> {
+        self.referred_volume = self
+            .referred_volume
+            .checked_add(amount)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Reserve the referral pool out of the team-ops allocation; skipped if
+    /// no deposit came through a referrer. Returns the pool.
+    pub fn fund_referrals(&mut self, team_amount: u64, referral_bps: u16) -> Result<u64> {
+        if self.referred_volume == 0 {
+            return Ok(0);
+        }
+        let pool = (team_amount as u128)
+            .checked_mul(referral_bps as u128)
+            .ok_or(RigItError::ArithmeticOverflow)?
+            / 10000;
+        self.referral_pool = pool as u64;
+        Ok(self.referral_pool)
+    }
+
+    /// Referrer reward for the volume it referred: the pool pro-rata to volume
+    pub fn referral_reward(&self, amount: u64) -> u64 {
+        if self.referred_volume == 0 {
+            return 0;
+        }
+        (self.referral_pool as u128 * amount as u128 / self.referred_volume as u128) as u64
+    }
+
+    /// Record a referral reward paid out of the referral pool
+    pub fn record_referral_payout(&mut self, amount: u64) -> Result<()> {
+        self.referral_paid = self
+            .referral_paid
+            .checked_add(amount)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        require!(
+            self.referral_paid <= self.referral_pool,
+            RigItError::ArithmeticOverflow
+        );
+        self.record_payout(amount)
+    }
+
+    /// Identity a commitment for this exploration is bound to
+    pub fn commit_context(&self) -> CommitContext {
+        CommitContext {
+            program_id: crate::ID.to_bytes(),
+            block_id: self.block_id,
+            track_id: self.track_id,
+            exploration_index: self.exploration_index,
+        }
+    }
+
+    /// Number of prize tiers to draw
+    pub fn prize_tier_count(&self) -> usize {
+        self.prize_tier_bps.iter().take_while(|&&bps| bps > 0).count()
+    }
+
+    /// Rigs drawn for each prize tier, in tier order
+    pub fn prize_rigs(&self) -> Vec<u8> {
+        if self.prize_tier_bps == [0; MAX_PRIZE_TIERS] {
+            return self.winning_rig().into_iter().collect();
+        }
+        self.prize_rigs
+            .iter()
+            .copied()
+            .take_while(|&rig| rig != NO_WINNING_RIG)
+            .collect()
+    }
+
+    /// Prize tier a rig won, None for losing rigs
+    pub fn prize_tier(&self, rig_index: u8) -> Option<usize> {
+        self.prize_rigs().iter().position(|&rig| rig == rig_index)
+    }
+
+    /// Share of the winner pool paid to a tier in bps. Tiers left undrawn
+    /// because too few rigs had tickets fold into the first prize.
+    pub fn prize_tier_share_bps(&self, tier: usize) -> u16 {
+        let tiers = self.prize_tier_bps;
+        let drawn = self.prize_rigs().len();
+        match tier {
+            _ if tier >= drawn => 0,
+            0 => tiers[0] + tiers[drawn..].iter().sum::<u16>(),
+            _ => tiers[tier],
+        }
+    }
+
+    /// Record the drawn prize rigs and settle: W = prize rig deposits,
+    /// L = all other deposits, R = W + the part of L not owed back through
+    /// `loser_refund()` (+ rollover under the all-buckets policy; see
+    /// `RolloverPolicy`). The first prize rig is the winning rig.
+    pub fn settle(&mut self, rigs: &[RigTotals], prize_rigs: &[u8]) -> Result<()> {
+        require!(
+            !prize_rigs.is_empty() && prize_rigs.len() <= MAX_PRIZE_TIERS,
+            RigItError::InvalidPrizeTiers
+        );
+
+        self.prize_rigs = [NO_WINNING_RIG; MAX_PRIZE_TIERS];
+        self.prize_rigs[..prize_rigs.len()].copy_from_slice(prize_rigs);
+        self.winning_rig = prize_rigs[0];
+
+        let mut prize_deposits: u64 = 0;
+        for &rig in prize_rigs {
+            prize_deposits = prize_deposits
+                .checked_add(
+                    rigs.get(rig as usize)
+                        .ok_or(RigItError::InvalidRigIndex)?
+                        .deposits,
+                )
+                .ok_or(RigItError::ArithmeticOverflow)?;
+        }
+
+        let w = prize_deposits;
+        let l = self
+            .total_deposits
+            .checked_sub(prize_deposits)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        // R = W + L * (1 - loser refund)
+        let mut r = w
+            .checked_add(self.loser_kept(l))
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        if self.rollover_policy() == RolloverPolicy::AllBuckets {
+            r = r
+                .checked_add(self.rollover_amount)
+                .ok_or(RigItError::ArithmeticOverflow)?;
+        }
+
+        self.total_winner_deposits = w;
+        self.total_loser_deposits = l;
+        self.remaining_pool = r;
+        self.set_status(ExplorationStatus::Settled);
+        Ok(())
+    }
+
+    /// Tickets held by rigs that won no prize
+    pub fn losing_tickets(&self, rigs: &[RigTotals]) -> u128 {
+        let prize_rigs = self.prize_rigs();
+        rig_tickets(rigs)
+            .enumerate()
+            .filter(|(rig, _)| !prize_rigs.contains(&(*rig as u8)))
+            .map(|(_, tickets)| tickets)
+            .sum()
+    }
+
+    /// Reserve a consolation pool for losers out of the ecosystem allocation.
+    /// Call after `settle`; skipped if no losing rig holds tickets.
+    pub fn fund_consolation(
+        &mut self,
+        rigs: &[RigTotals],
+        ecosystem_bps: u16,
+        consolation_bps: u16,
+        consolation_winners: u8,
+    ) -> Result<()> {
+        if consolation_bps == 0 || consolation_winners == 0 || self.losing_tickets(rigs) == 0 {
+            return Ok(());
+        }
+        // Same rounding as the ecosystem bucket in allocate_buyback_budget
+        let ecosystem_amount = (self.remaining_pool as u128)
+            .checked_mul(ecosystem_bps as u128)
+            .ok_or(RigItError::ArithmeticOverflow)?
+            / 10000;
+        let pool = ecosystem_amount
+            .checked_mul(consolation_bps as u128)
+            .ok_or(RigItError::ArithmeticOverflow)?
+            / 10000;
+        self.consolation_pool = u64::try_from(pool).map_err(|_| RigItError::ArithmeticOverflow)?;
+        self.consolation_winners = consolation_winners;
+        Ok(())
+    }
+
+    /// Number of consolation tickets that fall inside a losing deposit's
+    /// ticket range `[ticket_offset, ticket_offset + tickets)` within its rig.
+    /// Losing tickets are numbered rig by rig, skipping prize rigs.
+    pub fn consolation_hits(
+        &self,
+        rigs: &[RigTotals],
+        rig_index: u8,
+        ticket_offset: u128,
+        tickets: u128,
+    ) -> u8 {
+        let random_value = match self.revealed_random() {
+            Some(random_value) if self.consolation_pool > 0 => random_value,
+            _ => return 0,
+        };
+        let prize_rigs = self.prize_rigs();
+        if prize_rigs.contains(&rig_index) {
+            return 0;
+        }
+
+        // Start of this rig's range among all losing tickets
+        let rig_start: u128 = rig_tickets(rigs)
+            .take(rig_index as usize)
+            .enumerate()
+            .filter(|(rig, _)| !prize_rigs.contains(&(*rig as u8)))
+            .map(|(_, tickets)| tickets)
+            .sum();
+        let start = rig_start.saturating_add(ticket_offset);
+        let end = start.saturating_add(tickets);
+
+        select_consolation_tickets(&random_value, self.losing_tickets(rigs), self.consolation_winners)
+            .iter()
+            .filter(|&&ticket| ticket >= start && ticket < end)
+            .count() as u8
+    }
+
+    /// Consolation bonus for a number of hits (an equal share of the pool per hit)
+    pub fn consolation_bonus(&self, hits: u8) -> u64 {
+        if self.consolation_winners == 0 {
+            return 0;
+        }
+        (self.consolation_pool as u128 * hits as u128 / self.consolation_winners as u128) as u64
+    }
+
+    /// Hash of the current settlement inputs (deposits, tickets, rollover)
+    pub fn settlement_snapshot_hash(&self, rigs: &[RigTotals]) -> [u8; 32] {
+        hash_commit_snapshot(
+            rigs.iter().map(|rig| rig.deposits),
+            rig_tickets(rigs),
+            self.rollover_amount,
+        )
+    }
+
+    /// Reject settlement if deposits, tickets or rollover changed after commit
+    pub fn verify_commit_snapshot(&self, rigs: &[RigTotals]) -> Result<()> {
+        require!(
+            self.settlement_snapshot_hash(rigs) == self.commit_snapshot_hash,
+            RigItError::TicketSnapshotMismatch
+        );
+        Ok(())
+    }
+
+    /// Mix a depositor's entropy commitment into the accumulator
+    pub fn contribute_entropy(&mut self, depositor: &Pubkey, commitment: &[u8; 32]) -> Result<()> {
+        self.entropy_accumulator =
+            accumulate_entropy(&self.entropy_accumulator, &depositor.to_bytes(), commitment);
+        self.entropy_contributions = self
+            .entropy_contributions
+            .checked_add(1)
+            .ok_or(RigItError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
     /// Check if currently in active deposit phase
     pub fn is_active(&self, current_ts: i64) -> bool {
-        self.status == ExplorationStatus::Active && current_ts < self.active_end_ts
+        self.status() == ExplorationStatus::Active && current_ts < self.active_end_ts
     }
 
     /// Check if in anti-snipe window
-    pub fn is_anti_snipe_window(&self, current_ts: i64, window_secs: u32) -> bool {
-        let snipe_start = self.active_end_ts - window_secs as i64;
+    pub fn is_anti_snipe_window(&self, current_ts: i64) -> bool {
+        let snipe_start = self.active_end_ts - self.anti_snipe_window_secs as i64;
         current_ts >= snipe_start && current_ts < self.active_end_ts
     }
 
@@ -141,15 +718,55 @@ impl ExplorationState {
         self.total_deposits.saturating_add(self.rollover_amount)
     }
 
-    /// Calculate remaining pool: R = W + L/2
-    pub fn calculate_remaining_pool(&self) -> u64 {
-        let w = self.total_winner_deposits;
-        let l = self.total_loser_deposits;
-        w.saturating_add(l / 2)
+    /// Refund owed on a losing deposit of `amount`
+    pub fn loser_refund(&self, amount: u64) -> u64 {
+        (amount as u128 * self.loser_refund_bps as u128 / 10_000) as u64
+    }
+
+    /// Part of the loser deposits `l` kept in the pool; rounds down so R plus
+    /// the per-receipt refunds never exceeds the deposits
+    fn loser_kept(&self, l: u64) -> u64 {
+        (l as u128 * (10_000 - self.loser_refund_bps) as u128 / 10_000) as u64
+    }
+
+    /// Validate that exploration `next_index` of a track may start after `previous`.
+    /// The first exploration of a track has no predecessor; every later one must be
+    /// preceded by the finalized exploration at `next_index - 1` of the same track,
+    /// whose cooldown has ended.
+    pub fn validate_previous(
+        previous: Option<&ExplorationState>,
+        block_id: u8,
+        track_id: u8,
+        next_index: u64,
+        current_ts: i64,
+    ) -> Result<()> {
+        if next_index == 0 {
+            return Ok(());
+        }
+
+        let prev = previous.ok_or(RigItError::PreviousExplorationRequired)?;
+        require!(
+            prev.block_id == block_id &&
+            prev.track_id == track_id &&
+            prev.exploration_index + 1 == next_index,
+            RigItError::InvalidPreviousExploration
+        );
+        require!(
+            prev.status() == ExplorationStatus::Settled ||
+            prev.status() == ExplorationStatus::RolledOver,
+            RigItError::PreviousNotFinalized
+        );
+        require!(
+            current_ts >= prev.cooldown_end_ts,
+            RigItError::CooldownNotEnded
+        );
+
+        Ok(())
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[repr(u8)]
 pub enum ExplorationStatus {
     #[default]
     Pending,
@@ -158,3 +775,436 @@ pub enum ExplorationStatus {
     Settled,
     RolledOver,
 }
+
+impl ExplorationStatus {
+    /// Decode a stored status byte; unknown values read as Pending
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Active,
+            2 => Self::Finalizing,
+            3 => Self::Settled,
+            4 => Self::RolledOver,
+            _ => Self::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{DEFAULT_LOSER_REFUND_BPS, SINGLE_WINNER_PRIZE_TIERS};
+    use bytemuck::Zeroable;
+
+    const BLOCK_ID: u8 = 1;
+    const TRACK_ID: u8 = 0;
+    const COOLDOWN_END: i64 = 10_000;
+
+    fn previous(status: ExplorationStatus) -> ExplorationState {
+        let mut prev = ExplorationState::zeroed();
+        prev.block_id = BLOCK_ID;
+        prev.track_id = TRACK_ID;
+        prev.exploration_index = 4;
+        prev.set_status(status);
+        prev.cooldown_end_ts = COOLDOWN_END;
+        prev
+    }
+
+    #[test]
+    fn test_layout_has_no_padding() {
+        // 8-byte header, 13 words, four hashes, bond/consolation/payout/jackpot/referral
+        // words, counters, anti-snipe window, commit scheme, prize tiers, consolation count, rollover policy,
+        // jackpot params, loser refund, reserved
+        let expected = 8 +
+            13 * 8 +
+            32 + 32 + 32 + 32 +
+            10 * 8 +
+            4 + 4 + 4 + 4 + 1 +
+            MAX_PRIZE_TIERS + 2 * MAX_PRIZE_TIERS +
+            1 + 1 + 2 + 2 + 2 + 6;
+        assert_eq!(std::mem::size_of::<ExplorationState>(), expected);
+        assert_eq!(std::mem::align_of::<ExplorationState>(), 8);
+        assert_eq!(std::mem::align_of::<PodU128>(), 1);
+        // Rig totals follow the fixed fields without padding
+        assert_eq!(std::mem::size_of::<RigTotals>(), 24);
+        assert_eq!(ExplorationState::LEN % std::mem::align_of::<RigTotals>(), 0);
+        assert_eq!(ExplorationState::space(6), ExplorationState::LEN + 6 * 24);
+    }
+
+    #[test]
+    fn test_status_round_trip() {
+        let mut exploration = ExplorationState::zeroed();
+        assert_eq!(exploration.status(), ExplorationStatus::Pending);
+        for status in [
+            ExplorationStatus::Active,
+            ExplorationStatus::Finalizing,
+            ExplorationStatus::Settled,
+            ExplorationStatus::RolledOver,
+        ] {
+            exploration.set_status(status);
+            assert_eq!(exploration.status(), status);
+        }
+    }
+
+    #[test]
+    fn test_anti_snipe_window_uses_snapshot() {
+        let mut exploration = ExplorationState::zeroed();
+        exploration.active_end_ts = 1_000;
+        exploration.anti_snipe_window_secs = 300;
+        assert!(!exploration.is_anti_snipe_window(699));
+        assert!(exploration.is_anti_snipe_window(700));
+        assert!(exploration.is_anti_snipe_window(999));
+        assert!(!exploration.is_anti_snipe_window(1_000));
+
+        // A zero window anti-snipes nothing
+        exploration.anti_snipe_window_secs = 0;
+        assert!(!exploration.is_anti_snipe_window(999));
+    }
+
+    #[test]
+    fn test_fallback_slot_defaults_to_deadline() {
+        let mut exploration = ExplorationState::zeroed();
+        exploration.reveal_deadline_slot = 1_200;
+        assert_eq!(exploration.fallback_slot(), 1_200);
+
+        exploration.fallback_slot = 2_000;
+        assert_eq!(exploration.fallback_slot(), 2_000);
+    }
+
+    #[test]
+    fn test_add_to_rig_tracks_totals() {
+        let mut exploration = ExplorationState::zeroed();
+        let mut rigs = vec![RigTotals::default(); 6];
+        exploration.add_to_rig(&mut rigs, 5, 1_000, u128::from(u64::MAX) + 1).unwrap();
+        exploration.add_to_rig(&mut rigs, 5, 500, 1).unwrap();
+
+        assert_eq!(exploration.total_deposits, 1_500);
+        assert_eq!(rigs[5].deposits, 1_500);
+        assert_eq!(
+            rig_tickets(&rigs).collect::<Vec<_>>(),
+            vec![0, 0, 0, 0, 0, u128::from(u64::MAX) + 2]
+        );
+        assert!(exploration.add_to_rig(&mut rigs, 6, 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_commit_snapshot_detects_changes() {
+        let mut exploration = ExplorationState::zeroed();
+        let mut rigs = vec![RigTotals::default(); 4];
+        exploration.add_to_rig(&mut rigs, 1, 1_000, 31).unwrap();
+        exploration.commit_snapshot_hash = exploration.settlement_snapshot_hash(&rigs);
+        assert!(exploration.verify_commit_snapshot(&rigs).is_ok());
+
+        exploration.add_to_rig(&mut rigs, 2, 1, 1).unwrap();
+        assert!(exploration.verify_commit_snapshot(&rigs).is_err());
+
+        exploration.commit_snapshot_hash = exploration.settlement_snapshot_hash(&rigs);
+        exploration.rollover_amount += 1;
+        assert!(exploration.verify_commit_snapshot(&rigs).is_err());
+    }
+
+    fn tiered(tiers: [u16; MAX_PRIZE_TIERS]) -> (ExplorationState, Vec<RigTotals>) {
+        let mut exploration = ExplorationState::zeroed();
+        let mut rigs = vec![RigTotals::default(); 4];
+        exploration.rig_count = 4;
+        exploration.prize_tier_bps = tiers;
+        exploration.loser_refund_bps = DEFAULT_LOSER_REFUND_BPS;
+        exploration.add_to_rig(&mut rigs, 0, 1_000, 10).unwrap();
+        exploration.add_to_rig(&mut rigs, 1, 2_000, 10).unwrap();
+        exploration.add_to_rig(&mut rigs, 2, 3_000, 10).unwrap();
+        exploration.add_to_rig(&mut rigs, 3, 4_000, 10).unwrap();
+        (exploration, rigs)
+    }
+
+    #[test]
+    fn test_settle_single_winner() {
+        let (mut exploration, rigs) = tiered(SINGLE_WINNER_PRIZE_TIERS);
+        exploration.rollover_amount = 500;
+        exploration.settle(&rigs, &[2]).unwrap();
+
+        assert_eq!(exploration.winning_rig(), Some(2));
+        assert_eq!(exploration.prize_rigs(), vec![2]);
+        assert_eq!(exploration.total_winner_deposits, 3_000);
+        assert_eq!(exploration.total_loser_deposits, 7_000);
+        assert_eq!(exploration.remaining_pool, 7_000);
+        assert_eq!(exploration.prize_tier_share_bps(0), 10_000);
+        assert_eq!(exploration.prize_tier(1), None);
+    }
+
+    #[test]
+    fn test_settle_rejects_invalid_prize_rig_count() {
+        let (mut exploration, rigs) = tiered(SINGLE_WINNER_PRIZE_TIERS);
+        let err = exploration.settle(&rigs, &[]).unwrap_err();
+        assert_eq!(err, RigItError::InvalidPrizeTiers.into());
+        let err = exploration
+            .settle(&rigs, &[0; MAX_PRIZE_TIERS + 1])
+            .unwrap_err();
+        assert_eq!(err, RigItError::InvalidPrizeTiers.into());
+    }
+
+    #[test]
+    fn test_loser_refund_share() {
+        // The default 50% refund keeps R = W + L/2
+        let (mut exploration, rigs) = tiered(SINGLE_WINNER_PRIZE_TIERS);
+        exploration.settle(&rigs, &[2]).unwrap();
+        assert_eq!(exploration.remaining_pool, 3_000 + 3_500);
+        assert_eq!(exploration.loser_refund(1_001), 500);
+
+        // 80% refunded: only a fifth of L stays in R
+        let (mut exploration, rigs) = tiered(SINGLE_WINNER_PRIZE_TIERS);
+        exploration.loser_refund_bps = 8_000;
+        exploration.settle(&rigs, &[2]).unwrap();
+        assert_eq!(exploration.remaining_pool, 3_000 + 1_400);
+        assert_eq!(exploration.loser_refund(1_001), 800);
+    }
+
+    #[test]
+    fn test_rollover_policies() {
+        let settled = |policy: RolloverPolicy| {
+            let (mut exploration, rigs) = tiered(SINGLE_WINNER_PRIZE_TIERS);
+            exploration.rollover_amount = 600;
+            exploration.rollover_policy = policy as u8;
+            exploration.settle(&rigs, &[2]).unwrap();
+            exploration
+        };
+
+        // Split like R: half of it reaches the winner pool
+        let all_buckets = settled(RolloverPolicy::AllBuckets);
+        assert_eq!(all_buckets.total_winner_deposits, 3_000);
+        assert_eq!(all_buckets.remaining_pool, 7_100);
+        assert_eq!(all_buckets.winner_pool(5_000).unwrap(), 3_550);
+        assert_eq!(all_buckets.jackpot_rollover(), 0);
+
+        // Whole rollover to the prize rigs, buckets computed without it
+        let winner_pool = settled(RolloverPolicy::WinnerPool);
+        assert_eq!(winner_pool.remaining_pool, 6_500);
+        assert_eq!(winner_pool.winner_pool(5_000).unwrap(), 3_850);
+
+        // Kept out of this exploration entirely
+        let jackpot = settled(RolloverPolicy::Jackpot);
+        assert_eq!(jackpot.remaining_pool, 6_500);
+        assert_eq!(jackpot.winner_pool(5_000).unwrap(), 3_250);
+        assert_eq!(jackpot.jackpot_rollover(), 600);
+    }
+
+    #[test]
+    fn test_jackpot_slice_and_first_prize_payout() {
+        let (mut exploration, rigs) = tiered([7_000, 2_000, 1_000]);
+        exploration.jackpot_bps = 200;
+        exploration.settle(&rigs, &[3, 0, 2]).unwrap();
+        exploration.fund_jackpot().unwrap();
+        // R = 8_000 + 1_000 = 9_000; 2% to the jackpot
+        assert_eq!(exploration.jackpot_contribution, 180);
+        assert_eq!(exploration.remaining_pool, 8_820);
+
+        exploration.jackpot_won = 5_000;
+        // Winner pool 4_410 split 70/20/10, jackpot on top of the first prize only
+        assert_eq!(exploration.tier_pool(0, 5_000).unwrap(), 3_087 + 5_000);
+        assert_eq!(exploration.tier_pool(1, 5_000).unwrap(), 882);
+        assert_eq!(exploration.total_entitled().unwrap(), 15_000);
+    }
+
+    #[test]
+    fn test_jackpot_hit_follows_hit_chance() {
+        let (mut exploration, rigs) = tiered(SINGLE_WINNER_PRIZE_TIERS);
+        exploration.settle(&rigs, &[1]).unwrap();
+        exploration.jackpot_hit_bps = 10_000;
+        // Nothing can hit before the random value is revealed
+        assert!(!exploration.jackpot_hit());
+
+        exploration.set_revealed_random([0x5au8; 32]);
+        assert!(exploration.jackpot_hit());
+        exploration.jackpot_hit_bps = 0;
+        assert!(!exploration.jackpot_hit());
+
+        let roll = jackpot_roll(&exploration.revealed_random);
+        exploration.jackpot_hit_bps = roll;
+        assert!(!exploration.jackpot_hit());
+        exploration.jackpot_hit_bps = roll + 1;
+        assert!(exploration.jackpot_hit());
+    }
+
+    #[test]
+    fn test_settle_tiered_prizes() {
+        let (mut exploration, rigs) = tiered([7_000, 2_000, 1_000]);
+        assert_eq!(exploration.prize_tier_count(), 3);
+        exploration.settle(&rigs, &[3, 0, 2]).unwrap();
+
+        assert_eq!(exploration.winning_rig(), Some(3));
+        assert_eq!(exploration.prize_tier(0), Some(1));
+        assert_eq!(exploration.prize_tier(2), Some(2));
+        assert_eq!(exploration.prize_tier(1), None);
+        assert_eq!(exploration.total_winner_deposits, 8_000);
+        assert_eq!(exploration.total_loser_deposits, 2_000);
+        assert_eq!(exploration.remaining_pool, 9_000);
+        assert_eq!(exploration.prize_tier_share_bps(2), 1_000);
+    }
+
+    #[test]
+    fn test_undrawn_tiers_fold_into_first_prize() {
+        let (mut exploration, rigs) = tiered([7_000, 2_000, 1_000]);
+        exploration.settle(&rigs, &[1, 3]).unwrap();
+
+        assert_eq!(exploration.prize_tier_share_bps(0), 8_000);
+        assert_eq!(exploration.prize_tier_share_bps(1), 2_000);
+        assert_eq!(exploration.prize_tier_share_bps(2), 0);
+    }
+
+    #[test]
+    fn test_consolation_hits_cover_all_losing_tickets() {
+        let (mut exploration, rigs) = tiered(SINGLE_WINNER_PRIZE_TIERS);
+        exploration.settle(&rigs, &[1]).unwrap();
+        exploration.set_revealed_random([0x33u8; 32]);
+        exploration.fund_consolation(&rigs, 1_000, 5_000, 4).unwrap();
+        // R = 2_000 + 8_000 / 2 = 6_000; ecosystem 600; consolation 300
+        assert_eq!(exploration.consolation_pool, 300);
+        assert_eq!(exploration.losing_tickets(&rigs), 30);
+
+        // One-ticket slices over every losing rig account for every draw
+        let mut total_hits = 0u32;
+        for rig in [0u8, 2, 3] {
+            for offset in 0..10 {
+                total_hits += exploration.consolation_hits(&rigs, rig, offset, 1) as u32;
+            }
+        }
+        assert_eq!(total_hits, 4);
+        assert_eq!(exploration.consolation_hits(&rigs, 1, 0, 10), 0);
+        assert_eq!(exploration.consolation_bonus(1), 75);
+    }
+
+    #[test]
+    fn test_residual_dust_after_all_payouts() {
+        let (mut exploration, rigs) = tiered(SINGLE_WINNER_PRIZE_TIERS);
+        exploration.rollover_amount = 7;
+        exploration.settle(&rigs, &[1]).unwrap();
+        assert_eq!(exploration.receipt_count, 4);
+        assert_eq!(exploration.total_entitled().unwrap(), 10_007);
+
+        // Three receipts closed, buckets allocated: still outstanding
+        for amount in [500, 1_500, 2_000] {
+            exploration.record_payout(amount).unwrap();
+            exploration.close_receipt().unwrap();
+        }
+        exploration.record_payout(2_000).unwrap();
+        exploration.set_flag(exploration_flags::BUYBACK_ALLOCATED);
+        assert_eq!(exploration.residual_dust().unwrap(), None);
+
+        exploration.record_payout(4_003).unwrap();
+        exploration.close_receipt().unwrap();
+        assert_eq!(exploration.residual_dust().unwrap(), Some(4));
+
+        // Paying out more than the exploration holds is rejected
+        assert!(exploration.record_payout(5).is_err());
+    }
+
+    #[test]
+    fn test_referral_rewards_pro_rata() {
+        let (mut exploration, rigs) = tiered(SINGLE_WINNER_PRIZE_TIERS);
+        assert_eq!(exploration.fund_referrals(1_000, 2_000).unwrap(), 0);
+
+        exploration.add_referred(1_000).unwrap();
+        exploration.add_referred(2_000).unwrap();
+        exploration.settle(&rigs, &[1]).unwrap();
+        // 20% of a 1_000 team allocation, split 1:2 by referred volume
+        assert_eq!(exploration.fund_referrals(1_000, 2_000).unwrap(), 200);
+        assert_eq!(exploration.referral_reward(1_000), 66);
+        assert_eq!(exploration.referral_reward(2_000), 133);
+
+        // Unclaimed rewards stay reserved but never hold up the dust sweep
+        for _ in 0..4 {
+            exploration.close_receipt().unwrap();
+        }
+        exploration.set_flag(exploration_flags::BUYBACK_ALLOCATED);
+        let dust = exploration.residual_dust().unwrap().unwrap();
+        exploration.record_referral_payout(66).unwrap();
+        assert_eq!(exploration.residual_dust().unwrap(), Some(dust));
+        // The pool can't be overdrawn
+        assert!(exploration.record_referral_payout(135).is_err());
+    }
+
+    #[test]
+    fn test_consolation_disabled_by_default() {
+        let (mut exploration, rigs) = tiered(SINGLE_WINNER_PRIZE_TIERS);
+        exploration.settle(&rigs, &[1]).unwrap();
+        exploration.set_revealed_random([0x33u8; 32]);
+        exploration.fund_consolation(&rigs, 1_000, 0, 4).unwrap();
+        assert_eq!(exploration.consolation_pool, 0);
+        assert_eq!(exploration.consolation_hits(&rigs, 0, 0, 10), 0);
+    }
+
+    #[test]
+    fn test_first_exploration_needs_no_previous() {
+        assert!(ExplorationState::validate_previous(None, BLOCK_ID, TRACK_ID, 0, 0).is_ok());
+    }
+
+    #[test]
+    fn test_finalized_previous_allows_start() {
+        for status in [ExplorationStatus::Settled, ExplorationStatus::RolledOver] {
+            let prev = previous(status);
+            assert!(
+                ExplorationState::validate_previous(Some(&prev), BLOCK_ID, TRACK_ID, 5, COOLDOWN_END).is_ok()
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_previous_rejected() {
+        let err = ExplorationState::validate_previous(None, BLOCK_ID, TRACK_ID, 5, COOLDOWN_END).unwrap_err();
+        assert_eq!(err, RigItError::PreviousExplorationRequired.into());
+    }
+
+    #[test]
+    fn test_previous_from_other_block_rejected() {
+        let mut prev = previous(ExplorationStatus::Settled);
+        prev.block_id = BLOCK_ID + 1;
+        let err = ExplorationState::validate_previous(Some(&prev), BLOCK_ID, TRACK_ID, 5, COOLDOWN_END)
+            .unwrap_err();
+        assert_eq!(err, RigItError::InvalidPreviousExploration.into());
+    }
+
+    #[test]
+    fn test_previous_from_other_track_rejected() {
+        let mut prev = previous(ExplorationStatus::Settled);
+        prev.track_id = TRACK_ID + 1;
+        let err = ExplorationState::validate_previous(Some(&prev), BLOCK_ID, TRACK_ID, 5, COOLDOWN_END)
+            .unwrap_err();
+        assert_eq!(err, RigItError::InvalidPreviousExploration.into());
+    }
+
+    #[test]
+    fn test_non_adjacent_previous_rejected() {
+        let prev = previous(ExplorationStatus::Settled);
+        for next_index in [4, 6, 100] {
+            let err = ExplorationState::validate_previous(
+                Some(&prev),
+                BLOCK_ID,
+                TRACK_ID,
+                next_index,
+                COOLDOWN_END,
+            )
+            .unwrap_err();
+            assert_eq!(err, RigItError::InvalidPreviousExploration.into());
+        }
+    }
+
+    #[test]
+    fn test_overlapping_exploration_rejected() {
+        for status in [
+            ExplorationStatus::Pending,
+            ExplorationStatus::Active,
+            ExplorationStatus::Finalizing,
+        ] {
+            let prev = previous(status);
+            let err = ExplorationState::validate_previous(Some(&prev), BLOCK_ID, TRACK_ID, 5, COOLDOWN_END)
+                .unwrap_err();
+            assert_eq!(err, RigItError::PreviousNotFinalized.into());
+        }
+    }
+
+    #[test]
+    fn test_start_during_cooldown_rejected() {
+        let prev = previous(ExplorationStatus::Settled);
+        let err = ExplorationState::validate_previous(Some(&prev), BLOCK_ID, TRACK_ID, 5, COOLDOWN_END - 1)
+            .unwrap_err();
+        assert_eq!(err, RigItError::CooldownNotEnded.into());
+    }
+}
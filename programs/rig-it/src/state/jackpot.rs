@@ -0,0 +1,4 @@
+/// Per-block jackpot vault: a token account in the block's asset at
+/// `[JACKPOT_VAULT_SEED, &[block_id]]`, owned by the block vault authority so
+/// settlement can move funds between it and the block vault.
+pub const JACKPOT_VAULT_SEED: &[u8] = b"jackpot_vault";
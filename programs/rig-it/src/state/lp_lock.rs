@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Permanent LP lock for a block's buyback-LP bucket.
+/// The lock PDA owns its LP vault and no instruction ever signs with its
+/// seeds, so locked LP tokens have no withdrawal path.
+#[account]
+#[derive(Default)]
+pub struct LpLock {
+    /// Block whose buyback-LP treasury funds this lock
+    pub block_id: u8,
+    /// LP token mint of the locked pool
+    pub lp_mint: Pubkey,
+    /// Token account holding the locked LP tokens
+    pub vault: Pubkey,
+    /// Lifetime LP tokens locked
+    pub total_lp_locked: u64,
+    /// Lifetime block asset paired into the pool
+    pub total_asset_paired: u64,
+    /// Lifetime $RIG paired into the pool
+    pub total_rig_paired: u64,
+    /// Number of lock executions
+    pub lock_count: u32,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl LpLock {
+    pub const LEN: usize = 8 + // discriminator
+        1 +  // block_id
+        32 + // lp_mint
+        32 + // vault
+        8 +  // total_lp_locked
+        8 +  // total_asset_paired
+        8 +  // total_rig_paired
+        4 +  // lock_count
+        1 +  // bump
+        32;  // padding
+
+    pub const SEED: &'static [u8] = b"lp_lock";
+    pub const VAULT_SEED: &'static [u8] = b"lp_lock_vault";
+}
@@ -1,11 +1,27 @@
 pub mod protocol;
 pub mod block;
+pub mod track;
 pub mod exploration;
 pub mod rig;
 pub mod deposit;
+pub mod operator_bond;
+pub mod draw_record;
+pub mod treasury;
+pub mod lp_lock;
+pub mod buyback_schedule;
+pub mod jackpot;
+pub mod referral;
 
 pub use protocol::*;
 pub use block::*;
+pub use track::*;
 pub use exploration::*;
 pub use rig::*;
 pub use deposit::*;
+pub use operator_bond::*;
+pub use draw_record::*;
+pub use treasury::*;
+pub use lp_lock::*;
+pub use buyback_schedule::*;
+pub use jackpot::*;
+pub use referral::*;
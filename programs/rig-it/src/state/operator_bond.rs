@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Operator bond for a block, held in the block asset.
+/// Part of the bond is locked while a randomness commit is outstanding and is
+/// slashed into the winner pool if the operator fails to reveal.
+#[account]
+#[derive(Default)]
+pub struct OperatorBond {
+    /// Block this bond backs
+    pub block_id: u8,
+    /// Operator that posted the bond
+    pub operator: Pubkey,
+    /// Bonded amount held in the bond vault
+    pub amount: u64,
+    /// Portion of `amount` at stake in outstanding commits
+    pub locked_amount: u64,
+    /// Lifetime amount slashed from this bond
+    pub total_slashed: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl OperatorBond {
+    pub const LEN: usize = 8 + // discriminator
+        1 +  // block_id
+        32 + // operator
+        8 +  // amount
+        8 +  // locked_amount
+        8 +  // total_slashed
+        1 +  // bump
+        32;  // padding
+
+    pub const SEED: &'static [u8] = b"operator_bond";
+    pub const VAULT_SEED: &'static [u8] = b"operator_bond_vault";
+
+    /// Bonded amount not at stake in any outstanding commit
+    pub fn available(&self) -> u64 {
+        self.amount.saturating_sub(self.locked_amount)
+    }
+}
@@ -26,7 +26,7 @@ pub struct ProtocolConfig {
     /// Ecosystem/retention: 10% = 1000 bps
     pub ecosystem_bps: u16,
 
-    // Timing configuration
+    // Timing configuration (defaults for new blocks' schedules)
     /// Active exploration duration in seconds (default: 7200 = 2 hours)
     pub active_duration_secs: u32,
     /// Cooldown duration in seconds (default: 2400 = 40 minutes)
@@ -37,9 +37,17 @@ pub struct ProtocolConfig {
     // Randomness configuration
     /// Timeout slots for commit-reveal (default: ~150 slots = ~1 minute)
     pub commit_reveal_timeout_slots: u64,
+    /// Operator bond put at stake by each commit, in whole units of the
+    /// block asset (0 disables the bond requirement)
+    pub operator_bond_amount: u64,
 
     /// PDA bump
     pub bump: u8,
+
+    /// Whitelisted AMM adapter program for treasury buybacks (default = disabled)
+    pub amm_program: Pubkey,
+    /// Share of the team-ops allocation paid to referrers in bps (0 = off)
+    pub referral_bps: u16,
 }
 
 impl ProtocolConfig {
@@ -58,7 +66,10 @@ impl ProtocolConfig {
         4 +  // cooldown_duration_secs
         4 +  // anti_snipe_window_secs
         8 +  // commit_reveal_timeout_slots
+        8 +  // operator_bond_amount
         1 +  // bump
+        32 + // amm_program
+        2 +  // referral_bps
         64;  // padding for future fields
 
     pub const SEED: &'static [u8] = b"protocol";
@@ -73,6 +84,13 @@ impl ProtocolConfig {
         total == 10000
     }
 
+    /// Bond at stake per commit in base units of an asset with `decimals`
+    pub fn required_operator_bond(&self, decimals: u8) -> Option<u64> {
+        10u64
+            .checked_pow(decimals as u32)
+            .and_then(|unit| self.operator_bond_amount.checked_mul(unit))
+    }
+
     /// Initialize with default values
     pub fn init_defaults(&mut self) {
         self.winner_share_bps = 5000;
@@ -84,5 +102,6 @@ impl ProtocolConfig {
         self.cooldown_duration_secs = 2400;
         self.anti_snipe_window_secs = 300;
         self.commit_reveal_timeout_slots = 150;
+        self.operator_bond_amount = 0;
     }
 }
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+/// Length of a referral code in bytes (shorter codes are zero-padded)
+pub const REFERRAL_CODE_LEN: usize = 16;
+
+/// A wallet registered to refer depositors. Referred volume and claimed
+/// rewards are tracked on the owner's `UserStats`.
+#[account]
+#[derive(Default)]
+pub struct Referrer {
+    /// Wallet that registered and receives the rewards
+    pub owner: Pubkey,
+    /// Referral code, unique across the protocol
+    pub code: [u8; REFERRAL_CODE_LEN],
+    /// Unix timestamp of registration
+    pub registered_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Referrer {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        REFERRAL_CODE_LEN + // code
+        8 +  // registered_at
+        1 +  // bump
+        32;  // padding
+
+    pub const SEED: &'static [u8] = b"referrer";
+
+    /// Codes are 1-16 lowercase ASCII letters, digits, '-' or '_', zero-padded
+    pub fn validate_code(code: &[u8; REFERRAL_CODE_LEN]) -> bool {
+        let len = code.iter().position(|&b| b == 0).unwrap_or(REFERRAL_CODE_LEN);
+        len > 0 &&
+            code[..len].iter().all(|&b| {
+                b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_'
+            }) &&
+            code[len..].iter().all(|&b| b == 0)
+    }
+}
+
+/// Reserves a referral code for one referrer, so codes stay unique and can be
+/// resolved to the referrer's wallet
+#[account]
+#[derive(Default)]
+pub struct ReferralCode {
+    /// Wallet of the referrer holding this code
+    pub owner: Pubkey,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ReferralCode {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // owner
+        1 +  // bump
+        16;  // padding
+
+    pub const SEED: &'static [u8] = b"referral_code";
+}
+
+/// Volume one referrer brought into one exploration, and whether the
+/// referrer has claimed its share of that exploration's referral pool
+#[account]
+#[derive(Default)]
+pub struct ReferralVolume {
+    /// Exploration the volume was deposited into
+    pub exploration: Pubkey,
+    /// Wallet of the referrer
+    pub referrer: Pubkey,
+    /// Referred deposits counted in the exploration's rigs
+    pub volume: u64,
+    /// Whether the referrer has claimed its reward
+    pub claimed: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ReferralVolume {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // exploration
+        32 + // referrer
+        8 +  // volume
+        1 +  // claimed
+        1 +  // bump
+        16;  // padding
+
+    pub const SEED: &'static [u8] = b"referral_volume";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code(s: &str) -> [u8; REFERRAL_CODE_LEN] {
+        let mut code = [0u8; REFERRAL_CODE_LEN];
+        code[..s.len()].copy_from_slice(s.as_bytes());
+        code
+    }
+
+    #[test]
+    fn test_code_validation() {
+        assert!(Referrer::validate_code(&code("miner_42")));
+        assert!(Referrer::validate_code(&code("abcdefghijklmnop")));
+        assert!(!Referrer::validate_code(&code("")));
+        assert!(!Referrer::validate_code(&code("Miner")));
+        assert!(!Referrer::validate_code(&code("a b")));
+
+        // Bytes after the zero padding are rejected
+        let mut gap = code("ab");
+        gap[3] = b'c';
+        assert!(!Referrer::validate_code(&gap));
+    }
+}
@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+
+/// An independent exploration chain inside a block (e.g. a fast 15-minute track
+/// next to the standard 2-hour track). Tracks share the block vault but keep their
+/// own schedule, exploration index and liabilities.
+#[account]
+#[derive(Default)]
+pub struct TrackState {
+    /// Parent block ID
+    pub block_id: u8,
+    /// Track identifier within the block (0 = standard track)
+    pub track_id: u8,
+    /// Current exploration index (increments each exploration)
+    pub current_exploration_index: u64,
+
+    // Schedule
+    /// Unix timestamp at which exploration `schedule_anchor_index` is scheduled to start
+    pub schedule_anchor_ts: i64,
+    /// Exploration index the schedule anchor refers to
+    pub schedule_anchor_index: u64,
+    /// Active phase length in seconds
+    pub active_duration_secs: u32,
+    /// Cooldown length in seconds (period = active + cooldown)
+    pub cooldown_duration_secs: u32,
+    /// Anti-snipe window at the end of the active phase, in seconds
+    pub anti_snipe_window_secs: u32,
+
+    // Accounting
+    /// Tokens held in the block vault on behalf of this track's explorations
+    pub outstanding_liabilities: u64,
+    /// Total volume deposited through this track
+    pub total_volume: u128,
+    /// Total explorations on this track that reached settlement
+    pub total_explorations_completed: u64,
+
+    /// Deposit fees collected on this track
+    pub total_fees_collected: u64,
+
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Deterministic timing of a single exploration slot in a track's schedule
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScheduledWindow {
+    /// Earliest time the exploration may be started
+    pub start_ts: i64,
+    /// End of the active (deposit) phase
+    pub active_end_ts: i64,
+    /// End of the cooldown, i.e. start of the next slot
+    pub cooldown_end_ts: i64,
+}
+
+impl TrackState {
+    pub const LEN: usize = 8 + // discriminator
+        1 +  // block_id
+        1 +  // track_id
+        8 +  // current_exploration_index
+        8 +  // schedule_anchor_ts
+        8 +  // schedule_anchor_index
+        4 +  // active_duration_secs
+        4 +  // cooldown_duration_secs
+        4 +  // anti_snipe_window_secs
+        8 +  // outstanding_liabilities
+        16 + // total_volume
+        8 +  // total_explorations_completed
+        8 +  // total_fees_collected
+        1 +  // bump
+        64;  // padding
+
+    pub const SEED: &'static [u8] = b"track";
+
+    /// Length of one schedule slot: active phase + cooldown
+    pub fn schedule_period_secs(&self) -> i64 {
+        self.active_duration_secs as i64 + self.cooldown_duration_secs as i64
+    }
+
+    /// Scheduled window for the given exploration index.
+    /// Exploration N starts at `anchor_ts + (N - anchor_index) * period`, so a late
+    /// start never shifts the slots that follow it.
+    /// Returns None for indices before the anchor or on overflow.
+    pub fn scheduled_window(&self, exploration_index: u64) -> Option<ScheduledWindow> {
+        let slots_since_anchor = exploration_index.checked_sub(self.schedule_anchor_index)?;
+        let offset = i64::try_from(slots_since_anchor)
+            .ok()?
+            .checked_mul(self.schedule_period_secs())?;
+        let start_ts = self.schedule_anchor_ts.checked_add(offset)?;
+        let active_end_ts = start_ts.checked_add(self.active_duration_secs as i64)?;
+        let cooldown_end_ts = active_end_ts.checked_add(self.cooldown_duration_secs as i64)?;
+
+        Some(ScheduledWindow {
+            start_ts,
+            active_end_ts,
+            cooldown_end_ts,
+        })
+    }
+
+    /// Window exploration `exploration_index` runs in when started at `now`.
+    /// If its scheduled active phase has already ended (e.g. after an outage), the
+    /// schedule is re-anchored so the index takes the slot in progress, or the next
+    /// slot once that one's active phase is over too; slots keep their cadence.
+    pub fn catch_up(&mut self, exploration_index: u64, now: i64) -> Option<ScheduledWindow> {
+        let window = self.scheduled_window(exploration_index)?;
+        if now < window.active_end_ts {
+            return Some(window);
+        }
+
+        let period = self.schedule_period_secs();
+        let mut skipped = now.checked_sub(window.start_ts)? / period;
+        let slot_active_end = window
+            .start_ts
+            .checked_add(skipped.checked_mul(period)?)?
+            .checked_add(self.active_duration_secs as i64)?;
+        if now >= slot_active_end {
+            skipped += 1;
+        }
+
+        self.schedule_anchor_index = exploration_index;
+        self.schedule_anchor_ts = window.start_ts.checked_add(skipped.checked_mul(period)?)?;
+        self.scheduled_window(exploration_index)
+    }
+
+    /// Check the timing parameters are usable
+    pub fn validate_timing(&self) -> bool {
+        self.active_duration_secs > 0 && self.anti_snipe_window_secs < self.active_duration_secs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scheduled_track() -> TrackState {
+        TrackState {
+            schedule_anchor_ts: 1_000_000,
+            schedule_anchor_index: 0,
+            active_duration_secs: 7200,
+            cooldown_duration_secs: 2400,
+            anti_snipe_window_secs: 300,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_scheduled_window_is_deterministic() {
+        let track = scheduled_track();
+
+        let first = track.scheduled_window(0).unwrap();
+        assert_eq!(first.start_ts, 1_000_000);
+        assert_eq!(first.active_end_ts, 1_007_200);
+        assert_eq!(first.cooldown_end_ts, 1_009_600);
+
+        // Each slot starts exactly where the previous cooldown ends
+        let second = track.scheduled_window(1).unwrap();
+        assert_eq!(second.start_ts, first.cooldown_end_ts);
+
+        // 9 slots per day at the default 2h40m cadence
+        let tenth = track.scheduled_window(9).unwrap();
+        assert_eq!(tenth.start_ts - first.start_ts, 86_400);
+    }
+
+    #[test]
+    fn test_scheduled_window_respects_anchor_index() {
+        let mut track = scheduled_track();
+        track.schedule_anchor_index = 5;
+
+        assert!(track.scheduled_window(4).is_none());
+        assert_eq!(track.scheduled_window(5).unwrap().start_ts, 1_000_000);
+        assert_eq!(track.scheduled_window(6).unwrap().start_ts, 1_009_600);
+    }
+
+    #[test]
+    fn test_scheduled_window_overflow() {
+        let mut track = scheduled_track();
+        track.schedule_anchor_ts = i64::MAX - 10;
+
+        assert!(track.scheduled_window(1).is_none());
+        assert!(track.scheduled_window(u64::MAX).is_none());
+    }
+
+    #[test]
+    fn test_catch_up_skips_missed_slots() {
+        let mut track = scheduled_track();
+
+        // On time or late within the active phase: schedule unchanged
+        assert_eq!(track.catch_up(0, 1_000_000), track.scheduled_window(0));
+        assert_eq!(track.catch_up(0, 1_007_199).unwrap().start_ts, 1_000_000);
+        assert_eq!(track.schedule_anchor_ts, 1_000_000);
+
+        // Three slots later, mid active phase: index 0 takes that slot
+        let window = track.catch_up(0, 1_000_000 + 3 * 9_600 + 100).unwrap();
+        assert_eq!(window.start_ts, 1_028_800);
+        assert_eq!(track.schedule_anchor_index, 0);
+        assert_eq!(track.scheduled_window(1).unwrap().start_ts, 1_038_400);
+
+        // In that slot's cooldown: index 1 moves to the next slot
+        let window = track.catch_up(1, 1_038_400 + 7_200 + 10).unwrap();
+        assert_eq!(window.start_ts, 1_048_000);
+        assert_eq!(track.schedule_anchor_index, 1);
+    }
+
+    #[test]
+    fn test_validate_timing() {
+        let mut track = scheduled_track();
+        assert!(track.validate_timing());
+
+        // Fast track: 15 minutes active, 5 minutes cooldown, 1 minute anti-snipe
+        track.active_duration_secs = 900;
+        track.cooldown_duration_secs = 300;
+        track.anti_snipe_window_secs = 60;
+        assert!(track.validate_timing());
+
+        // Anti-snipe window may not swallow the whole active phase
+        track.anti_snipe_window_secs = 900;
+        assert!(!track.validate_timing());
+
+        track.active_duration_secs = 0;
+        track.anti_snipe_window_secs = 0;
+        assert!(!track.validate_timing());
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+/// Treasury vaults are token accounts in the block's asset, one per bucket
+/// per block: seeds `[TREASURY_SEED, bucket, &[block_id]]`, owned by the
+/// block's treasury authority PDA `[TREASURY_AUTHORITY_SEED, &[block_id]]`.
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const TREASURY_AUTHORITY_SEED: &[u8] = b"treasury_authority";
+/// Per-block $RIG account receiving buybacks before they are burned
+pub const RIG_BUYBACK_SEED: &[u8] = b"rig_buyback";
+
+/// Treasury bucket seeds
+pub mod treasury_buckets {
+    pub const BUYBACK_BURN: &[u8] = b"buyback_burn";
+    pub const BUYBACK_LP: &[u8] = b"buyback_lp";
+    pub const TEAM_OPS: &[u8] = b"team_ops";
+    pub const ECOSYSTEM: &[u8] = b"ecosystem";
+    /// Deposit fees; initialized separately by `init_fee_vault`
+    pub const DEPOSIT_FEES: &[u8] = b"deposit_fees";
+}
+
+/// Treasury buckets the admin can withdraw from, including collected deposit
+/// fees. The buyback buckets are only spent by the buyback instructions.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WithdrawableBucket {
+    TeamOps,
+    Ecosystem,
+    DepositFees,
+}
+
+impl WithdrawableBucket {
+    /// Bucket seed of the treasury vault
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            WithdrawableBucket::TeamOps => treasury_buckets::TEAM_OPS,
+            WithdrawableBucket::Ecosystem => treasury_buckets::ECOSYSTEM,
+            WithdrawableBucket::DepositFees => treasury_buckets::DEPOSIT_FEES,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_withdrawable_buckets_exclude_buybacks() {
+        for bucket in [
+            WithdrawableBucket::TeamOps,
+            WithdrawableBucket::Ecosystem,
+            WithdrawableBucket::DepositFees,
+        ] {
+            assert_ne!(bucket.seed(), treasury_buckets::BUYBACK_BURN);
+            assert_ne!(bucket.seed(), treasury_buckets::BUYBACK_LP);
+        }
+        assert_eq!(WithdrawableBucket::TeamOps.seed(), treasury_buckets::TEAM_OPS);
+        assert_eq!(WithdrawableBucket::Ecosystem.seed(), treasury_buckets::ECOSYSTEM);
+        assert_eq!(WithdrawableBucket::DepositFees.seed(), treasury_buckets::DEPOSIT_FEES);
+    }
+}
@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token::spl_token::state::Account as TokenAccount;
+
+use crate::errors::RigItError;
+
+/// Instruction names every AMM adapter exposes for treasury buybacks
+pub const ADAPTER_SWAP_IX: &str = "swap";
+pub const ADAPTER_ADD_LIQUIDITY_IX: &str = "add_liquidity";
+
+/// Anchor-style discriminator of an adapter instruction
+pub fn adapter_discriminator(name: &str) -> [u8; 8] {
+    let preimage = format!("global:{name}");
+    let hash = hash(preimage.as_bytes()).to_bytes();
+    hash[..8].try_into().unwrap()
+}
+
+/// Anchor-style discriminator of the adapter's `swap` instruction
+pub fn swap_discriminator() -> [u8; 8] {
+    adapter_discriminator(ADAPTER_SWAP_IX)
+}
+
+/// Pass-through metas for pool-specific accounts, keeping their writability
+fn pool_account_metas(pool_accounts: &[AccountInfo]) -> Vec<AccountMeta> {
+    pool_accounts
+        .iter()
+        .map(|account| {
+            if account.is_writable {
+                AccountMeta::new(account.key(), false)
+            } else {
+                AccountMeta::new_readonly(account.key(), false)
+            }
+        })
+        .collect()
+}
+
+/// Fixed accounts of an adapter swap, followed by pool-specific accounts
+pub struct AdapterSwapAccounts {
+    /// `[writable]` token account of the asset being sold
+    pub source: Pubkey,
+    /// `[writable]` token account of the asset being bought
+    pub destination: Pubkey,
+    /// `[signer]` owner of `source`
+    pub authority: Pubkey,
+    /// `[]` SPL token program
+    pub token_program: Pubkey,
+}
+
+/// Build the adapter `swap(amount_in, min_amount_out)` CPI. Pool-specific
+/// accounts are passed through (typically from `remaining_accounts`) after
+/// the fixed accounts, keeping their writability.
+pub fn swap_instruction(
+    amm_program: Pubkey,
+    swap: &AdapterSwapAccounts,
+    pool_accounts: &[AccountInfo],
+    amount_in: u64,
+    min_amount_out: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(swap.source, false),
+        AccountMeta::new(swap.destination, false),
+        AccountMeta::new_readonly(swap.authority, true),
+        AccountMeta::new_readonly(swap.token_program, false),
+    ];
+    accounts.extend(pool_account_metas(pool_accounts));
+
+    let mut data = Vec::with_capacity(8 + 8 + 8);
+    data.extend_from_slice(&swap_discriminator());
+    data.extend_from_slice(&amount_in.to_le_bytes());
+    data.extend_from_slice(&min_amount_out.to_le_bytes());
+
+    Instruction {
+        program_id: amm_program,
+        accounts,
+        data,
+    }
+}
+
+/// Fixed accounts of an adapter add-liquidity, followed by pool-specific accounts
+pub struct AdapterLiquidityAccounts {
+    /// `[writable]` token account supplying the first side
+    pub source_a: Pubkey,
+    /// `[writable]` token account supplying the second side
+    pub source_b: Pubkey,
+    /// `[writable]` token account receiving the LP tokens
+    pub lp_destination: Pubkey,
+    /// `[signer]` owner of both sources
+    pub authority: Pubkey,
+    /// `[]` SPL token program
+    pub token_program: Pubkey,
+}
+
+/// Build the adapter `add_liquidity(amount_a, amount_b, min_lp_out)` CPI.
+/// The adapter may take less than offered on one side to keep the pool ratio.
+pub fn add_liquidity_instruction(
+    amm_program: Pubkey,
+    liquidity: &AdapterLiquidityAccounts,
+    pool_accounts: &[AccountInfo],
+    amount_a: u64,
+    amount_b: u64,
+    min_lp_out: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(liquidity.source_a, false),
+        AccountMeta::new(liquidity.source_b, false),
+        AccountMeta::new(liquidity.lp_destination, false),
+        AccountMeta::new_readonly(liquidity.authority, true),
+        AccountMeta::new_readonly(liquidity.token_program, false),
+    ];
+    accounts.extend(pool_account_metas(pool_accounts));
+
+    let mut data = Vec::with_capacity(8 + 8 + 8 + 8);
+    data.extend_from_slice(&adapter_discriminator(ADAPTER_ADD_LIQUIDITY_IX));
+    data.extend_from_slice(&amount_a.to_le_bytes());
+    data.extend_from_slice(&amount_b.to_le_bytes());
+    data.extend_from_slice(&min_lp_out.to_le_bytes());
+
+    Instruction {
+        program_id: amm_program,
+        accounts,
+        data,
+    }
+}
+
+/// Reject pool accounts that are token accounts owned by `authority`. The
+/// adapter CPI is signed by `authority`, so passing any of its other vaults
+/// through would hand the adapter control of them.
+pub fn check_pool_accounts(pool_accounts: &[AccountInfo], authority: &Pubkey) -> Result<()> {
+    for account in pool_accounts {
+        if account.owner != &anchor_spl::token::ID {
+            continue;
+        }
+        let data = account.try_borrow_data()?;
+        // SPL token account: mint (32 bytes), then owner
+        let owned_by_authority =
+            data.len() == TokenAccount::LEN && data[32..64] == authority.to_bytes();
+        require!(!owned_by_authority, RigItError::TreasuryAccountNotAllowed);
+    }
+    Ok(())
+}
+
+/// Amount an adapter CPI took from a source account, rejecting a CPI that
+/// took more than it was offered
+pub fn adapter_spent(before: u64, after: u64, offered: u64) -> Result<u64> {
+    let spent = before
+        .checked_sub(after)
+        .ok_or(RigItError::ArithmeticOverflow)?;
+    require!(spent <= offered, RigItError::AdapterOverspent);
+    Ok(spent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adapter_spent_bounded_by_offer() {
+        assert_eq!(adapter_spent(1_000, 400, 600).unwrap(), 600);
+        assert_eq!(adapter_spent(1_000, 700, 600).unwrap(), 300);
+        assert!(adapter_spent(1_000, 399, 600).is_err());
+        // A source that grew is not a spend
+        assert!(adapter_spent(1_000, 1_001, 600).is_err());
+    }
+
+    fn account_info<'a>(
+        key: &'a Pubkey,
+        lamports: &'a mut u64,
+        data: &'a mut [u8],
+        owner: &'a Pubkey,
+    ) -> AccountInfo<'a> {
+        AccountInfo::new(key, false, true, lamports, data, owner, false, 0)
+    }
+
+    #[test]
+    fn test_pool_accounts_exclude_authority_vaults() {
+        let authority = Pubkey::new_unique();
+        let token_account = |owner: &Pubkey| {
+            let mut data = vec![0u8; TokenAccount::LEN];
+            data[32..64].copy_from_slice(owner.as_ref());
+            data
+        };
+        let keys: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let mut lamports = [0u64; 3];
+        let [l0, l1, l2] = &mut lamports;
+        let mut pool_data = token_account(&Pubkey::new_unique());
+        let mut vault_data = token_account(&authority);
+        let mut other_data = token_account(&authority);
+
+        let pool = account_info(&keys[0], l0, &mut pool_data, &anchor_spl::token::ID);
+        let vault = account_info(&keys[1], l1, &mut vault_data, &anchor_spl::token::ID);
+        // Same bytes, but not owned by the token program
+        let other = account_info(&keys[2], l2, &mut other_data, &keys[0]);
+
+        assert!(check_pool_accounts(&[pool.clone(), other], &authority).is_ok());
+        assert!(check_pool_accounts(&[pool, vault], &authority).is_err());
+    }
+
+    #[test]
+    fn test_swap_instruction_encoding() {
+        let keys: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let swap = AdapterSwapAccounts {
+            source: keys[1],
+            destination: keys[2],
+            authority: keys[3],
+            token_program: keys[4],
+        };
+        let ix = swap_instruction(keys[0], &swap, &[], 1_000, 990);
+
+        assert_eq!(ix.program_id, keys[0]);
+        assert_eq!(&ix.data[..8], &swap_discriminator());
+        assert_eq!(u64::from_le_bytes(ix.data[8..16].try_into().unwrap()), 1_000);
+        assert_eq!(u64::from_le_bytes(ix.data[16..24].try_into().unwrap()), 990);
+        assert_eq!(ix.accounts.len(), 4);
+        assert!(ix.accounts[0].is_writable && ix.accounts[1].is_writable);
+        assert!(ix.accounts[2].is_signer);
+    }
+
+    #[test]
+    fn test_add_liquidity_instruction_encoding() {
+        let keys: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+        let liquidity = AdapterLiquidityAccounts {
+            source_a: keys[1],
+            source_b: keys[2],
+            lp_destination: keys[3],
+            authority: keys[4],
+            token_program: keys[5],
+        };
+        let ix = add_liquidity_instruction(keys[0], &liquidity, &[], 500, 700, 10);
+
+        assert_eq!(&ix.data[..8], &adapter_discriminator(ADAPTER_ADD_LIQUIDITY_IX));
+        assert_ne!(&ix.data[..8], &swap_discriminator());
+        assert_eq!(u64::from_le_bytes(ix.data[8..16].try_into().unwrap()), 500);
+        assert_eq!(u64::from_le_bytes(ix.data[16..24].try_into().unwrap()), 700);
+        assert_eq!(u64::from_le_bytes(ix.data[24..32].try_into().unwrap()), 10);
+        assert_eq!(ix.accounts.len(), 5);
+        assert!(ix.accounts[2].is_writable && !ix.accounts[2].is_signer);
+        assert!(ix.accounts[3].is_signer);
+    }
+}
@@ -1,5 +1,6 @@
 pub mod tickets;
-pub mod randomness;
+pub mod amm;
 
 pub use tickets::*;
-pub use randomness::*;
+pub use amm::*;
+pub use rig_it_draw::*;
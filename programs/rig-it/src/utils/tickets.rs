@@ -1,5 +1,3 @@
-use anchor_lang::prelude::*;
-
 /// Minimum deposit amount (in lamports or token base units)
 pub const MIN_DEPOSIT_AMOUNT: u64 = 1_000_000; // 0.001 SOL equivalent
 
@@ -86,7 +84,7 @@ pub fn integer_sqrt(n: u128) -> u128 {
     }
     
     let mut x = n;
-    let mut y = (x + 1) / 2;
+    let mut y = x.div_ceil(2);
     
     while y < x {
         x = y;
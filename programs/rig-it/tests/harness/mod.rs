@@ -0,0 +1,410 @@
+//! Native instruction harness: runs the program's entrypoint against in-memory
+//! accounts, with the system and token program CPIs the instructions make
+//! emulated through the syscall stubs.
+//!
+//! Execution is native, so compute units are not metered here; measure them
+//! against the SBF build (`anchor test`) instead.
+
+use std::collections::HashMap;
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::program_stubs::{self, SyscallStubs};
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use anchor_spl::token::spl_token;
+use anchor_spl::token::spl_token::instruction::TokenInstruction;
+use anchor_spl::token::spl_token::state::{Account as TokenAccount, AccountState, Mint};
+
+use rig_it::state::{ExplorationState, RigTotals};
+
+const SYSVAR_SUCCESS: u64 = 0;
+
+/// Account data and metadata as the runtime would hold it
+#[derive(Clone)]
+pub struct TestAccount {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+/// In-memory ledger that instructions are processed against
+pub struct Harness {
+    accounts: HashMap<Pubkey, TestAccount>,
+    slot_hashes: Vec<(u64, [u8; 32])>,
+    pub clock: Clock,
+}
+
+impl Harness {
+    pub fn new() -> Self {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            program_stubs::set_syscall_stubs(Box::new(Stubs));
+        });
+
+        let mut harness = Self {
+            accounts: HashMap::new(),
+            slot_hashes: Vec::new(),
+            clock: Clock::default(),
+        };
+        for program in [rig_it::ID, system_program::ID, spl_token::ID] {
+            harness.accounts.insert(
+                program,
+                TestAccount {
+                    lamports: 1,
+                    data: Vec::new(),
+                    owner: Pubkey::default(),
+                    executable: true,
+                },
+            );
+        }
+        harness.set_clock(1, 0);
+        harness.write_slot_hashes();
+        harness
+    }
+
+    /// Move the clock sysvar to `slot` and `unix_timestamp`
+    pub fn set_clock(&mut self, slot: u64, unix_timestamp: i64) {
+        self.clock.slot = slot;
+        self.clock.unix_timestamp = unix_timestamp;
+        let mut data = Vec::with_capacity(40);
+        data.extend_from_slice(&self.clock.slot.to_le_bytes());
+        data.extend_from_slice(&self.clock.epoch_start_timestamp.to_le_bytes());
+        data.extend_from_slice(&self.clock.epoch.to_le_bytes());
+        data.extend_from_slice(&self.clock.leader_schedule_epoch.to_le_bytes());
+        data.extend_from_slice(&self.clock.unix_timestamp.to_le_bytes());
+        self.set_sysvar(sysvar::clock::ID, data);
+    }
+
+    /// Record the hash of `slot` in the SlotHashes sysvar
+    pub fn add_slot_hash(&mut self, slot: u64, hash: [u8; 32]) {
+        self.slot_hashes.push((slot, hash));
+        // Newest first, as the runtime keeps them
+        self.slot_hashes
+            .sort_by_key(|&(slot, _)| std::cmp::Reverse(slot));
+        self.write_slot_hashes();
+    }
+
+    fn write_slot_hashes(&mut self) {
+        let mut data = (self.slot_hashes.len() as u64).to_le_bytes().to_vec();
+        for (slot, hash) in &self.slot_hashes {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(hash);
+        }
+        self.set_sysvar(sysvar::slot_hashes::ID, data);
+    }
+
+    fn set_sysvar(&mut self, key: Pubkey, data: Vec<u8>) {
+        self.set_account(
+            key,
+            TestAccount {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: sysvar::ID,
+                executable: false,
+            },
+        );
+    }
+
+    pub fn set_account(&mut self, key: Pubkey, account: TestAccount) {
+        self.accounts.insert(key, account);
+    }
+
+    /// System-owned wallet holding `lamports`
+    pub fn fund(&mut self, key: Pubkey, lamports: u64) {
+        self.set_account(
+            key,
+            TestAccount {
+                lamports,
+                data: Vec::new(),
+                owner: system_program::ID,
+                executable: false,
+            },
+        );
+    }
+
+    /// Store a Borsh program account, padded out to `space`
+    pub fn set_anchor_account<T: AccountSerialize>(
+        &mut self,
+        key: Pubkey,
+        account: &T,
+        space: usize,
+    ) {
+        let mut data = Vec::with_capacity(space);
+        account.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= space, "account larger than its space");
+        data.resize(space, 0);
+        self.set_program_account(key, data);
+    }
+
+    pub fn anchor_account<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        let account = self.accounts.get(key).expect("account not found");
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    /// Store an exploration and its rig totals
+    pub fn set_exploration(
+        &mut self,
+        key: Pubkey,
+        exploration: &ExplorationState,
+        rigs: &[RigTotals],
+    ) {
+        let mut data = ExplorationState::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(exploration));
+        data.extend_from_slice(bytemuck::cast_slice(rigs));
+        self.set_program_account(key, data);
+    }
+
+    pub fn exploration(&self, key: &Pubkey) -> (ExplorationState, Vec<RigTotals>) {
+        let data = &self.accounts.get(key).expect("exploration not found").data;
+        let (head, tail) = data.split_at(ExplorationState::LEN);
+        let exploration: ExplorationState = bytemuck::pod_read_unaligned(&head[8..]);
+        let rigs = tail
+            .chunks_exact(std::mem::size_of::<RigTotals>())
+            .map(bytemuck::pod_read_unaligned)
+            .collect();
+        (exploration, rigs)
+    }
+
+    fn set_program_account(&mut self, key: Pubkey, data: Vec<u8>) {
+        self.set_account(
+            key,
+            TestAccount {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: rig_it::ID,
+                executable: false,
+            },
+        );
+    }
+
+    pub fn create_mint(&mut self, key: Pubkey, decimals: u8) {
+        let mint = Mint {
+            decimals,
+            is_initialized: true,
+            ..Mint::default()
+        };
+        let mut data = vec![0; Mint::LEN];
+        Mint::pack(mint, &mut data).unwrap();
+        self.set_token_program_account(key, data);
+    }
+
+    pub fn create_token_account(&mut self, key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) {
+        let account = TokenAccount {
+            mint,
+            owner,
+            amount,
+            state: AccountState::Initialized,
+            ..TokenAccount::default()
+        };
+        let mut data = vec![0; TokenAccount::LEN];
+        TokenAccount::pack(account, &mut data).unwrap();
+        self.set_token_program_account(key, data);
+    }
+
+    pub fn token_balance(&self, key: &Pubkey) -> u64 {
+        let account = self.accounts.get(key).expect("token account not found");
+        TokenAccount::unpack(&account.data).unwrap().amount
+    }
+
+    fn set_token_program_account(&mut self, key: Pubkey, data: Vec<u8>) {
+        self.set_account(
+            key,
+            TestAccount {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: spl_token::ID,
+                executable: false,
+            },
+        );
+    }
+
+    /// Run `ix` through the program entrypoint; account changes are kept only
+    /// if it succeeds, like a transaction
+    pub fn process(&mut self, ix: &Instruction) -> ProgramResult {
+        let mut keys: Vec<Pubkey> = Vec::new();
+        let mut infos: Vec<AccountInfo<'static>> = Vec::new();
+        for meta in &ix.accounts {
+            // Duplicate keys share one account, as in the runtime
+            if let Some(index) = keys.iter().position(|key| *key == meta.pubkey) {
+                infos.push(infos[index].clone());
+                keys.push(meta.pubkey);
+                continue;
+            }
+            let account = self
+                .accounts
+                .get(&meta.pubkey)
+                .cloned()
+                .unwrap_or(TestAccount {
+                    lamports: 0,
+                    data: Vec::new(),
+                    owner: system_program::ID,
+                    executable: false,
+                });
+            infos.push(AccountInfo::new(
+                Box::leak(Box::new(meta.pubkey)),
+                meta.is_signer,
+                meta.is_writable,
+                Box::leak(Box::new(account.lamports)),
+                Box::leak(account.data.into_boxed_slice()),
+                Box::leak(Box::new(account.owner)),
+                account.executable,
+                0,
+            ));
+            keys.push(meta.pubkey);
+        }
+        let infos: &'static [AccountInfo<'static>] = Box::leak(infos.into_boxed_slice());
+
+        rig_it::entry(&ix.program_id, infos, &ix.data)?;
+
+        for info in infos {
+            self.accounts.insert(
+                *info.key,
+                TestAccount {
+                    lamports: info.lamports(),
+                    data: info.data.borrow().to_vec(),
+                    owner: *info.owner,
+                    executable: info.executable,
+                },
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Syscalls the program makes natively: the rent sysvar and the system and
+/// token program CPIs
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { std::ptr::write_unaligned(var_addr as *mut Rent, Rent::default()) };
+        SYSVAR_SUCCESS
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let accounts = instruction
+            .accounts
+            .iter()
+            .map(|meta| {
+                let info = account_infos
+                    .iter()
+                    .find(|info| *info.key == meta.pubkey)
+                    .ok_or(ProgramError::NotEnoughAccountKeys)?;
+                // Signers are either transaction signers or PDAs of the program
+                let signed = info.is_signer
+                    || signers_seeds.iter().any(|seeds| {
+                        Pubkey::create_program_address(seeds, &rig_it::ID) == Ok(meta.pubkey)
+                    });
+                if meta.is_signer && !signed {
+                    return Err(ProgramError::MissingRequiredSignature);
+                }
+                Ok(info.clone())
+            })
+            .collect::<std::result::Result<Vec<_>, ProgramError>>()?;
+
+        if instruction.program_id == system_program::ID {
+            process_system(&instruction.data, &accounts)
+        } else if instruction.program_id == spl_token::ID {
+            process_token(&instruction.data, &accounts)
+        } else {
+            Err(ProgramError::IncorrectProgramId)
+        }
+    }
+}
+
+fn read_u64(data: &[u8], offset: usize) -> std::result::Result<u64, ProgramError> {
+    data.get(offset..offset + 8)
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> std::result::Result<Pubkey, ProgramError> {
+    data.get(offset..offset + 32)
+        .map(|bytes| Pubkey::try_from(bytes).unwrap())
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+fn move_lamports(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    let mut from_lamports = from.try_borrow_mut_lamports()?;
+    **from_lamports = from_lamports
+        .checked_sub(lamports)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    let mut to_lamports = to.try_borrow_mut_lamports()?;
+    **to_lamports = to_lamports
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+fn allocate(account: &AccountInfo, space: u64) -> ProgramResult {
+    if !account.data_is_empty() || *account.owner != system_program::ID {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+    *account.try_borrow_mut_data()? = Box::leak(vec![0; space as usize].into_boxed_slice());
+    Ok(())
+}
+
+/// CreateAccount, Assign, Transfer and Allocate, by their bincode tags
+fn process_system(data: &[u8], accounts: &[AccountInfo]) -> ProgramResult {
+    let tag = data
+        .get(0..4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    match (tag, accounts) {
+        (0, [from, to, ..]) => {
+            allocate(to, read_u64(data, 12)?)?;
+            move_lamports(from, to, read_u64(data, 4)?)?;
+            to.assign(&read_pubkey(data, 20)?);
+            Ok(())
+        }
+        (1, [account, ..]) => {
+            account.assign(&read_pubkey(data, 4)?);
+            Ok(())
+        }
+        (2, [from, to, ..]) => move_lamports(from, to, read_u64(data, 4)?),
+        (8, [account, ..]) => allocate(account, read_u64(data, 4)?),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+fn process_token(data: &[u8], accounts: &[AccountInfo]) -> ProgramResult {
+    match (TokenInstruction::unpack(data)?, accounts) {
+        (TokenInstruction::Transfer { amount }, [source, destination, authority, ..])
+        | (
+            TokenInstruction::TransferChecked { amount, .. },
+            [source, _, destination, authority, ..],
+        ) => {
+            let mut from = TokenAccount::unpack(&source.try_borrow_data()?)?;
+            let mut to = TokenAccount::unpack(&destination.try_borrow_data()?)?;
+            if from.owner != *authority.key {
+                return Err(spl_token::error::TokenError::OwnerMismatch.into());
+            }
+            if from.mint != to.mint {
+                return Err(spl_token::error::TokenError::MintMismatch.into());
+            }
+            from.amount = from
+                .amount
+                .checked_sub(amount)
+                .ok_or(spl_token::error::TokenError::InsufficientFunds)?;
+            to.amount = to
+                .amount
+                .checked_add(amount)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            TokenAccount::pack(from, &mut source.try_borrow_mut_data()?)?;
+            TokenAccount::pack(to, &mut destination.try_borrow_mut_data()?)?;
+            Ok(())
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
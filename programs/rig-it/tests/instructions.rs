@@ -0,0 +1,471 @@
+//! Instruction-level tests run natively through the program entrypoint
+
+mod harness;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+
+use rig_it::errors::RigItError;
+use rig_it::instructions::{CommitRandomnessArgs, DepositToRigArgs, RevealRandomnessArgs};
+use rig_it::state::{
+    BlockState, BuybackSchedule, DepositReceipt, DrawRecord, ExplorationState, ExplorationStatus,
+    ProtocolConfig, RigState, RigTotals, TrackState, DEFAULT_LOSER_REFUND_BPS,
+    SINGLE_WINNER_PRIZE_TIERS,
+};
+use rig_it::utils::generate_commit_hash_v2;
+
+use harness::Harness;
+
+const BLOCK_ID: u8 = 1;
+const TRACK_ID: u8 = 0;
+const RIG_COUNT: u8 = 4;
+const DECIMALS: u8 = 6;
+const SCHEDULE_ANCHOR_TS: i64 = 1_000_000;
+const USER_BALANCE: u64 = 1_000_000_000;
+
+fn pda(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &rig_it::ID)
+}
+
+fn exploration_key(track_id: u8, index: u64) -> Pubkey {
+    pda(&[
+        ExplorationState::SEED,
+        &[BLOCK_ID],
+        &[track_id],
+        &index.to_le_bytes(),
+    ])
+    .0
+}
+
+fn ix(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: rig_it::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn assert_rig_it_error(result: ProgramResult, error: RigItError) {
+    assert_eq!(result, Err(ProgramError::Custom(error.into())));
+}
+
+/// Block 1 / track 0 with its config, vault and the operator
+struct World {
+    harness: Harness,
+    operator: Pubkey,
+    asset_mint: Pubkey,
+    protocol_config: Pubkey,
+    block_state: Pubkey,
+    track_state: Pubkey,
+    block_vault: Pubkey,
+    block_vault_authority: Pubkey,
+}
+
+impl World {
+    fn new() -> Self {
+        let mut harness = Harness::new();
+        let operator = Pubkey::new_unique();
+        let asset_mint = Pubkey::new_unique();
+        harness.fund(operator, 10_000_000_000);
+        harness.create_mint(asset_mint, DECIMALS);
+
+        let (protocol_config, bump) = pda(&[ProtocolConfig::SEED]);
+        let mut config = ProtocolConfig {
+            admin: Pubkey::new_unique(),
+            operator,
+            rig_token_mint: Pubkey::new_unique(),
+            bump,
+            ..ProtocolConfig::default()
+        };
+        config.init_defaults();
+        harness.set_anchor_account(protocol_config, &config, ProtocolConfig::LEN);
+
+        let (block_state, bump) = pda(&[BlockState::SEED, &[BLOCK_ID]]);
+        let block = BlockState {
+            block_id: BLOCK_ID,
+            asset_mint,
+            asset_decimals: DECIMALS,
+            min_threshold: 1,
+            rig_count: RIG_COUNT,
+            track_count: 1,
+            bump,
+            prize_tier_bps: SINGLE_WINNER_PRIZE_TIERS,
+            loser_refund_bps: DEFAULT_LOSER_REFUND_BPS,
+            ..BlockState::default()
+        };
+        harness.set_anchor_account(block_state, &block, BlockState::LEN);
+
+        let (track_state, bump) = pda(&[TrackState::SEED, &[BLOCK_ID], &[TRACK_ID]]);
+        let track = TrackState {
+            block_id: BLOCK_ID,
+            track_id: TRACK_ID,
+            schedule_anchor_ts: SCHEDULE_ANCHOR_TS,
+            active_duration_secs: config.active_duration_secs,
+            cooldown_duration_secs: config.cooldown_duration_secs,
+            anti_snipe_window_secs: config.anti_snipe_window_secs,
+            bump,
+            ..TrackState::default()
+        };
+        harness.set_anchor_account(track_state, &track, TrackState::LEN);
+
+        let (block_vault, _) = pda(&[b"block_vault", &[BLOCK_ID]]);
+        let (block_vault_authority, _) = pda(&[b"block_vault_authority", &[BLOCK_ID]]);
+        harness.create_token_account(block_vault, asset_mint, block_vault_authority, 0);
+
+        harness.set_clock(100, SCHEDULE_ANCHOR_TS);
+
+        Self {
+            harness,
+            operator,
+            asset_mint,
+            protocol_config,
+            block_state,
+            track_state,
+            block_vault,
+            block_vault_authority,
+        }
+    }
+
+    fn track(&self) -> TrackState {
+        self.harness.anchor_account(&self.track_state)
+    }
+
+    /// Wallet with an asset token account holding `USER_BALANCE`
+    fn user(&mut self) -> (Pubkey, Pubkey) {
+        let user = Pubkey::new_unique();
+        let token_account = Pubkey::new_unique();
+        self.harness.fund(user, 1_000_000_000);
+        self.harness
+            .create_token_account(token_account, self.asset_mint, user, USER_BALANCE);
+        (user, token_account)
+    }
+
+    fn start_exploration(&mut self, previous_exploration: Option<Pubkey>) -> ProgramResult {
+        let caller = Pubkey::new_unique();
+        self.harness.fund(caller, 1_000_000_000);
+        let index = self.track().current_exploration_index;
+        let ix = ix(
+            rig_it::accounts::StartExploration {
+                protocol_config: self.protocol_config,
+                block_state: self.block_state,
+                track_state: self.track_state,
+                exploration_state: exploration_key(TRACK_ID, index),
+                previous_exploration,
+                caller,
+                system_program: system_program::ID,
+                clock: sysvar::clock::ID,
+            },
+            rig_it::instruction::StartExploration {},
+        );
+        self.harness.process(&ix)
+    }
+
+    fn deposit(
+        &mut self,
+        exploration: Pubkey,
+        user: (Pubkey, Pubkey),
+        rig_index: u8,
+        amount: u64,
+    ) -> (ProgramResult, Pubkey, Pubkey) {
+        let (user, user_token_account) = user;
+        let rig_state = pda(&[RigState::SEED, exploration.as_ref(), &[rig_index]]).0;
+        let deposit_receipt = pda(&[
+            DepositReceipt::SEED,
+            rig_state.as_ref(),
+            user.as_ref(),
+            &0u64.to_le_bytes(),
+        ])
+        .0;
+        let ix = ix(
+            rig_it::accounts::DepositToRig {
+                protocol_config: self.protocol_config,
+                block_state: self.block_state,
+                track_state: self.track_state,
+                exploration_state: exploration,
+                rig_state,
+                deposit_receipt,
+                user,
+                user_token_account,
+                block_vault: self.block_vault,
+                treasury_deposit_fees: None,
+                user_rig_token_account: None,
+                rig_token_mint: None,
+                referrer: None,
+                referrer_stats: None,
+                referral_volume: None,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                clock: sysvar::clock::ID,
+            },
+            rig_it::instruction::DepositToRig {
+                args: DepositToRigArgs {
+                    rig_index,
+                    amount,
+                    deposit_nonce: 0,
+                    entropy_commitment: Some([rig_index; 32]),
+                    referrer: None,
+                },
+            },
+        );
+        (self.harness.process(&ix), rig_state, deposit_receipt)
+    }
+
+    /// Commit to `secret` at `target_slot`
+    fn commit(
+        &mut self,
+        exploration: Pubkey,
+        secret: &[u8; 32],
+        target_slot: u64,
+    ) -> ProgramResult {
+        let (state, _) = self.harness.exploration(&exploration);
+        let commit_hash = generate_commit_hash_v2(secret, target_slot, &state.commit_context());
+        let ix = ix(
+            rig_it::accounts::CommitRandomness {
+                protocol_config: self.protocol_config,
+                block_state: self.block_state,
+                exploration_state: exploration,
+                operator_bond: None,
+                operator: self.operator,
+                clock: sysvar::clock::ID,
+            },
+            rig_it::instruction::CommitRandomness {
+                args: CommitRandomnessArgs {
+                    commit_hash,
+                    target_slot,
+                },
+            },
+        );
+        self.harness.process(&ix)
+    }
+
+    fn reveal(&mut self, exploration: Pubkey, secret: [u8; 32]) -> ProgramResult {
+        let ix = ix(
+            rig_it::accounts::RevealRandomness {
+                protocol_config: self.protocol_config,
+                block_state: self.block_state,
+                track_state: self.track_state,
+                exploration_state: exploration,
+                operator_bond: None,
+                block_vault: self.block_vault,
+                block_vault_authority: self.block_vault_authority,
+                jackpot_vault: None,
+                draw_record: pda(&[DrawRecord::SEED, exploration.as_ref()]).0,
+                buyback_schedule: pda(&[BuybackSchedule::SEED, exploration.as_ref()]).0,
+                operator: self.operator,
+                slot_hashes: sysvar::slot_hashes::ID,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                clock: sysvar::clock::ID,
+            },
+            rig_it::instruction::RevealRandomness {
+                args: RevealRandomnessArgs { secret },
+            },
+        );
+        self.harness.process(&ix)
+    }
+
+    fn claim_winnings(
+        &mut self,
+        exploration: Pubkey,
+        user: (Pubkey, Pubkey),
+        rig_state: Pubkey,
+        deposit_receipt: Pubkey,
+    ) -> ProgramResult {
+        let (user, user_token_account) = user;
+        let ix = ix(
+            rig_it::accounts::ClaimWinnings {
+                protocol_config: self.protocol_config,
+                exploration_state: exploration,
+                track_state: self.track_state,
+                rig_state,
+                deposit_receipt,
+                user,
+                user_token_account,
+                block_vault: self.block_vault,
+                block_vault_authority: self.block_vault_authority,
+                token_program: spl_token::ID,
+            },
+            rig_it::instruction::ClaimWinnings {},
+        );
+        self.harness.process(&ix)
+    }
+}
+
+#[test]
+fn test_deposit_reveal_claim() {
+    let mut world = World::new();
+    world.start_exploration(None).unwrap();
+    let exploration = exploration_key(TRACK_ID, 0);
+
+    // Two depositors on different rigs, before the anti-snipe window
+    let alice = world.user();
+    let bob = world.user();
+    let (result, alice_rig, alice_receipt) = world.deposit(exploration, alice, 0, 3_000_000);
+    result.unwrap();
+    let (result, bob_rig, bob_receipt) = world.deposit(exploration, bob, 1, 7_000_000);
+    result.unwrap();
+    assert_eq!(world.harness.token_balance(&world.block_vault), 10_000_000);
+    assert_eq!(
+        world.harness.token_balance(&alice.1),
+        USER_BALANCE - 3_000_000
+    );
+    let (state, rigs) = world.harness.exploration(&exploration);
+    assert_eq!(state.total_deposits, 10_000_000);
+    assert_eq!(state.entropy_contributions, 2);
+    assert_eq!(rigs[0].deposits, 3_000_000);
+    assert_eq!(rigs[1].deposits, 7_000_000);
+
+    // Commit once the active phase ends, reveal at the target slot
+    let secret = [7u8; 32];
+    let target_slot = 200;
+    world.harness.set_clock(150, state.active_end_ts);
+    world.commit(exploration, &secret, target_slot).unwrap();
+    world.harness.add_slot_hash(target_slot, [9u8; 32]);
+    world
+        .harness
+        .set_clock(target_slot + 1, state.active_end_ts + 60);
+    world.reveal(exploration, secret).unwrap();
+
+    let (state, _) = world.harness.exploration(&exploration);
+    assert_eq!(state.status(), ExplorationStatus::Settled);
+    let draw: DrawRecord = world
+        .harness
+        .anchor_account(&pda(&[DrawRecord::SEED, exploration.as_ref()]).0);
+    assert_eq!(draw.winning_rig, state.winning_rig);
+    assert!(!draw.used_fallback);
+
+    // The sole depositor of the winning rig takes its whole tier pool
+    let (winner, rig_state, receipt, loser, loser_rig, loser_receipt) = match state.winning_rig {
+        0 => (alice, alice_rig, alice_receipt, bob, bob_rig, bob_receipt),
+        1 => (bob, bob_rig, bob_receipt, alice, alice_rig, alice_receipt),
+        rig => panic!("rig {rig} had no tickets"),
+    };
+    let config: ProtocolConfig = world.harness.anchor_account(&world.protocol_config);
+    let payout = state.tier_pool(0, config.winner_share_bps).unwrap() as u64;
+    let winner_balance = world.harness.token_balance(&winner.1);
+    let vault_balance = world.harness.token_balance(&world.block_vault);
+    world
+        .claim_winnings(exploration, winner, rig_state, receipt)
+        .unwrap();
+    assert_eq!(
+        world.harness.token_balance(&winner.1),
+        winner_balance + payout
+    );
+    assert_eq!(
+        world.harness.token_balance(&world.block_vault),
+        vault_balance - payout
+    );
+    let receipt: DepositReceipt = world.harness.anchor_account(&receipt);
+    assert!(receipt.winnings_claimed);
+
+    // A second claim and a claim on the losing rig are both rejected
+    let result = world.claim_winnings(exploration, winner, rig_state, receipt_key(&receipt));
+    assert_rig_it_error(result, RigItError::WinningsAlreadyClaimed);
+    let result = world.claim_winnings(exploration, loser, loser_rig, loser_receipt);
+    assert_rig_it_error(result, RigItError::NotAWinner);
+}
+
+/// Receipt PDA of a deposit receipt's own fields
+fn receipt_key(receipt: &DepositReceipt) -> Pubkey {
+    pda(&[
+        DepositReceipt::SEED,
+        receipt.rig.as_ref(),
+        receipt.user.as_ref(),
+        &receipt.deposit_nonce.to_le_bytes(),
+    ])
+    .0
+}
+
+/// Track 0 scheduled to start exploration `index`, with its window open
+fn world_at_index(index: u64) -> World {
+    let mut world = World::new();
+    let mut track = world.track();
+    track.current_exploration_index = index;
+    let window = track.scheduled_window(index).unwrap();
+    world
+        .harness
+        .set_anchor_account(world.track_state, &track, TrackState::LEN);
+    world.harness.set_clock(100, window.start_ts);
+    world
+}
+
+/// Store exploration `index` of `track_id` in `status`, its cooldown ended
+fn store_exploration(
+    world: &mut World,
+    track_id: u8,
+    index: u64,
+    status: ExplorationStatus,
+) -> Pubkey {
+    let (key, bump) = pda(&[
+        ExplorationState::SEED,
+        &[BLOCK_ID],
+        &[track_id],
+        &index.to_le_bytes(),
+    ]);
+    let mut exploration: ExplorationState = bytemuck::Zeroable::zeroed();
+    exploration.bump = bump;
+    exploration.block_id = BLOCK_ID;
+    exploration.track_id = track_id;
+    exploration.exploration_index = index;
+    exploration.rig_count = RIG_COUNT;
+    exploration.cooldown_end_ts = world.harness.clock.unix_timestamp;
+    exploration.set_status(status);
+    let rigs = vec![RigTotals::default(); RIG_COUNT as usize];
+    world.harness.set_exploration(key, &exploration, &rigs);
+    key
+}
+
+#[test]
+fn test_start_exploration_after_finalized_previous() {
+    for status in [ExplorationStatus::Settled, ExplorationStatus::RolledOver] {
+        let mut world = world_at_index(5);
+        let previous = store_exploration(&mut world, TRACK_ID, 4, status);
+        world.start_exploration(Some(previous)).unwrap();
+
+        let (started, _) = world.harness.exploration(&exploration_key(TRACK_ID, 5));
+        assert_eq!(started.status(), ExplorationStatus::Active);
+        assert_eq!(world.track().current_exploration_index, 6);
+    }
+}
+
+#[test]
+fn test_start_exploration_requires_previous() {
+    let mut world = world_at_index(5);
+    store_exploration(&mut world, TRACK_ID, 4, ExplorationStatus::Settled);
+    let result = world.start_exploration(None);
+    assert_rig_it_error(result, RigItError::PreviousExplorationRequired);
+}
+
+#[test]
+fn test_start_exploration_rejects_wrong_previous() {
+    let mut world = world_at_index(5);
+    store_exploration(&mut world, TRACK_ID, 4, ExplorationStatus::Settled);
+    let stale = store_exploration(&mut world, TRACK_ID, 3, ExplorationStatus::Settled);
+    let other_track = store_exploration(&mut world, TRACK_ID + 1, 4, ExplorationStatus::Settled);
+
+    // Only the PDA of the track's exploration at index - 1 is accepted
+    for previous in [stale, other_track] {
+        let result = world.start_exploration(Some(previous));
+        assert_eq!(
+            result,
+            Err(ProgramError::Custom(
+                anchor_lang::error::ErrorCode::ConstraintSeeds.into()
+            ))
+        );
+    }
+}
+
+#[test]
+fn test_start_exploration_rejects_unfinalized_previous() {
+    for status in [ExplorationStatus::Active, ExplorationStatus::Finalizing] {
+        let mut world = world_at_index(5);
+        let previous = store_exploration(&mut world, TRACK_ID, 4, status);
+        let result = world.start_exploration(Some(previous));
+        assert_rig_it_error(result, RigItError::PreviousNotFinalized);
+    }
+}
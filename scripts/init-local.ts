@@ -119,6 +119,8 @@ async function main() {
       .initBlock({
         blockId: SOL_BLOCK_ID,
         minThreshold: new BN(1 * LAMPORTS_PER_SOL),
+        rigCount: null,
+        prizeTierBps: null,
       })
       .accounts({
         protocolConfig: protocolConfigPDA,
@@ -140,6 +142,76 @@ async function main() {
     }
   }
 
+  // Initialize the standard track of the SOL Block
+  const [solTrackPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from('track'), Buffer.from([SOL_BLOCK_ID]), Buffer.from([0])],
+    programId
+  );
+
+  try {
+    await program.methods
+      .initTrack({
+        trackId: 0,
+        scheduleAnchorTs: null,
+        activeDurationSecs: null,
+        cooldownDurationSecs: null,
+        antiSnipeWindowSecs: null,
+      })
+      .accounts({
+        protocolConfig: protocolConfigPDA,
+        blockState: solBlockPDA,
+        trackState: solTrackPDA,
+        admin: provider.wallet.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+        clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+      })
+      .rpc();
+    console.log('✅ SOL Block standard track initialized');
+  } catch (error: any) {
+    if (error.message.includes('already in use')) {
+      console.log('⚠️ SOL Block standard track already initialized');
+    } else {
+      throw error;
+    }
+  }
+
+  // Initialize the SOL Block's treasury vaults
+  const solTreasury = (bucket: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('treasury'), Buffer.from(bucket), Buffer.from([SOL_BLOCK_ID])],
+      programId
+    )[0];
+  const [solTreasuryAuthority] = PublicKey.findProgramAddressSync(
+    [Buffer.from('treasury_authority'), Buffer.from([SOL_BLOCK_ID])],
+    programId
+  );
+
+  try {
+    await program.methods
+      .initTreasuries()
+      .accounts({
+        protocolConfig: protocolConfigPDA,
+        blockState: solBlockPDA,
+        treasuryBuybackBurn: solTreasury('buyback_burn'),
+        treasuryBuybackLp: solTreasury('buyback_lp'),
+        treasuryTeamOps: solTreasury('team_ops'),
+        treasuryEcosystem: solTreasury('ecosystem'),
+        treasuryAuthority: solTreasuryAuthority,
+        assetMint: solTokenMint,
+        admin: provider.wallet.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    console.log('✅ SOL Block treasuries initialized');
+  } catch (error: any) {
+    if (error.message.includes('already in use')) {
+      console.log('⚠️ SOL Block treasuries already initialized');
+    } else {
+      throw error;
+    }
+  }
+
   // Save configuration
   const config = {
     programId: programId.toBase58(),
@@ -154,6 +226,7 @@ async function main() {
       sol: {
         blockId: SOL_BLOCK_ID,
         blockState: solBlockPDA.toBase58(),
+        trackState: solTrackPDA.toBase58(),
         blockVault: solBlockVault.toBase58(),
         assetMint: solTokenMint.toBase58(),
       },
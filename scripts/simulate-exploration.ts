@@ -26,6 +26,7 @@ interface LocalConfig {
     sol: {
       blockId: number;
       blockState: string;
+      trackState: string;
       blockVault: string;
       assetMint: string;
     };
@@ -56,10 +57,11 @@ async function startExploration() {
   const operator = Keypair.fromSecretKey(Uint8Array.from(config.operatorSecretKey));
   const protocolConfigPDA = new PublicKey(config.protocolConfig);
   const blockStatePDA = new PublicKey(config.blocks.sol.blockState);
+  const trackStatePDA = new PublicKey(config.blocks.sol.trackState);
 
   // Get current exploration index
-  const block = await program.account.blockState.fetch(blockStatePDA);
-  const explorationIndex = block.currentExplorationIndex;
+  const track = await program.account.trackState.fetch(trackStatePDA);
+  const explorationIndex = track.currentExplorationIndex;
 
   console.log('Current exploration index:', explorationIndex.toString());
 
@@ -67,18 +69,29 @@ async function startExploration() {
   const indexBuffer = Buffer.alloc(8);
   indexBuffer.writeBigUInt64LE(BigInt(explorationIndex.toString()));
   const [explorationPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from('exploration'), Buffer.from([config.blocks.sol.blockId]), indexBuffer],
+    [Buffer.from('exploration'), Buffer.from([config.blocks.sol.blockId]), Buffer.from([0]), indexBuffer],
     programId
   );
 
+  let previousExploration: PublicKey | null = null;
+  if (!explorationIndex.isZero()) {
+    const prevIndexBuffer = Buffer.alloc(8);
+    prevIndexBuffer.writeBigUInt64LE(BigInt(explorationIndex.subn(1).toString()));
+    [previousExploration] = PublicKey.findProgramAddressSync(
+      [Buffer.from('exploration'), Buffer.from([config.blocks.sol.blockId]), Buffer.from([0]), prevIndexBuffer],
+      programId
+    );
+  }
+
   await program.methods
     .startExploration()
     .accounts({
       protocolConfig: protocolConfigPDA,
       blockState: blockStatePDA,
+      trackState: trackStatePDA,
       explorationState: explorationPDA,
-      previousExploration: null,
-      operator: operator.publicKey,
+      previousExploration,
+      caller: operator.publicKey,
       systemProgram: anchor.web3.SystemProgram.programId,
       clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
     })
@@ -105,17 +118,18 @@ async function makeDeposits() {
 
   const protocolConfigPDA = new PublicKey(config.protocolConfig);
   const blockStatePDA = new PublicKey(config.blocks.sol.blockState);
+  const trackStatePDA = new PublicKey(config.blocks.sol.trackState);
   const solTokenMint = new PublicKey(config.blocks.sol.assetMint);
   const blockVault = new PublicKey(config.blocks.sol.blockVault);
 
   // Get current exploration
-  const block = await program.account.blockState.fetch(blockStatePDA);
-  const explorationIndex = block.currentExplorationIndex.subn(1);
+  const track = await program.account.trackState.fetch(trackStatePDA);
+  const explorationIndex = track.currentExplorationIndex.subn(1);
 
   const indexBuffer = Buffer.alloc(8);
   indexBuffer.writeBigUInt64LE(BigInt(explorationIndex.toString()));
   const [explorationPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from('exploration'), Buffer.from([config.blocks.sol.blockId]), indexBuffer],
+    [Buffer.from('exploration'), Buffer.from([config.blocks.sol.blockId]), Buffer.from([0]), indexBuffer],
     programId
   );
 
@@ -173,10 +187,12 @@ async function makeDeposits() {
         rigIndex,
         amount,
         depositNonce,
+        entropyCommitment: null,
       })
       .accounts({
         protocolConfig: protocolConfigPDA,
         blockState: blockStatePDA,
+        trackState: trackStatePDA,
         explorationState: explorationPDA,
         rigState: rigPDA,
         depositReceipt: depositReceiptPDA,
@@ -217,15 +233,16 @@ async function finalizeExploration() {
   const operator = Keypair.fromSecretKey(Uint8Array.from(config.operatorSecretKey));
   const protocolConfigPDA = new PublicKey(config.protocolConfig);
   const blockStatePDA = new PublicKey(config.blocks.sol.blockState);
+  const trackStatePDA = new PublicKey(config.blocks.sol.trackState);
 
   // Get current exploration
-  const block = await program.account.blockState.fetch(blockStatePDA);
-  const explorationIndex = block.currentExplorationIndex.subn(1);
+  const track = await program.account.trackState.fetch(trackStatePDA);
+  const explorationIndex = track.currentExplorationIndex.subn(1);
 
   const indexBuffer = Buffer.alloc(8);
   indexBuffer.writeBigUInt64LE(BigInt(explorationIndex.toString()));
   const [explorationPDA] = PublicKey.findProgramAddressSync(
-    [Buffer.from('exploration'), Buffer.from([config.blocks.sol.blockId]), indexBuffer],
+    [Buffer.from('exploration'), Buffer.from([config.blocks.sol.blockId]), Buffer.from([0]), indexBuffer],
     programId
   );
 
@@ -235,10 +252,18 @@ async function finalizeExploration() {
   const currentSlot = await provider.connection.getSlot();
   const targetSlot = currentSlot + 15;
 
+  // v2 commit: bound to program, block, track and exploration index
   const slotBuffer = Buffer.alloc(8);
   slotBuffer.writeBigUInt64LE(BigInt(targetSlot));
   const commitHash = crypto.createHash('sha256')
-    .update(Buffer.concat([secret, slotBuffer]))
+    .update(Buffer.concat([
+      Buffer.from('rig-it:commit:v2'),
+      programId.toBuffer(),
+      Buffer.from([config.blocks.sol.blockId, 0]),
+      indexBuffer,
+      slotBuffer,
+      secret,
+    ]))
     .digest();
 
   await program.methods
@@ -250,6 +275,7 @@ async function finalizeExploration() {
       protocolConfig: protocolConfigPDA,
       blockState: blockStatePDA,
       explorationState: explorationPDA,
+      operatorBond: null, // No bond required with default params
       operator: operator.publicKey,
       clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
     })
@@ -275,7 +301,9 @@ async function finalizeExploration() {
     .accounts({
       protocolConfig: protocolConfigPDA,
       blockState: blockStatePDA,
+      trackState: trackStatePDA,
       explorationState: explorationPDA,
+      operatorBond: null,
       operator: operator.publicKey,
       slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
       clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
@@ -1,4 +1,4 @@
-import { Keypair, LAMPORTS_PER_SOL } from '@solana/web3.js';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
 import BN from 'bn.js';
 
 // Test fixtures and constants
@@ -94,17 +94,33 @@ export function calculateWinnerPayout(
 }
 
 // Generate random commit-reveal pair
-export function generateCommitReveal(targetSlot: number): {
+export function generateCommitReveal(
+  targetSlot: number,
+  programId: PublicKey,
+  blockId: number,
+  trackId: number,
+  explorationIndex: number
+): {
   secret: Buffer;
   commitHash: Buffer;
 } {
   const crypto = require('crypto');
   const secret = crypto.randomBytes(32);
-  
+
+  const indexBuffer = Buffer.alloc(8);
+  indexBuffer.writeBigUInt64LE(BigInt(explorationIndex));
   const slotBuffer = Buffer.alloc(8);
   slotBuffer.writeBigUInt64LE(BigInt(targetSlot));
-  
-  const data = Buffer.concat([secret, slotBuffer]);
+
+  // v2 preimage, bound to the exploration identity
+  const data = Buffer.concat([
+    Buffer.from('rig-it:commit:v2'),
+    programId.toBuffer(),
+    Buffer.from([blockId, trackId]),
+    indexBuffer,
+    slotBuffer,
+    secret,
+  ]);
   const commitHash = crypto.createHash('sha256').update(data).digest();
 
   return { secret, commitHash };
@@ -9,6 +9,7 @@ import { RigIt } from '../target/types/rig_it';
 import {
   getProtocolConfigPDA,
   getBlockStatePDA,
+  getTrackStatePDA,
   getExplorationStatePDA,
   getRigStatePDA,
   getDepositReceiptPDA,
@@ -38,6 +39,7 @@ describe('Rig It Protocol', () => {
   // PDAs
   let protocolConfigPDA: PublicKey;
   let solBlockPDA: PublicKey;
+  let solTrackPDA: PublicKey;
   
   const SOL_BLOCK_ID = 0;
   const MIN_THRESHOLD = new BN(1_000_000_000); // 1 SOL
@@ -149,6 +151,8 @@ describe('Rig It Protocol', () => {
         .initBlock({
           blockId: SOL_BLOCK_ID,
           minThreshold: MIN_THRESHOLD,
+          rigCount: null,
+          prizeTierBps: null,
         })
         .accounts({
           protocolConfig: protocolConfigPDA,
@@ -168,7 +172,7 @@ describe('Rig It Protocol', () => {
       expect(block.blockId).to.equal(SOL_BLOCK_ID);
       expect(block.assetMint.toBase58()).to.equal(solTokenMint.toBase58());
       expect(block.minThreshold.toString()).to.equal(MIN_THRESHOLD.toString());
-      expect(block.currentExplorationIndex.toNumber()).to.equal(0);
+      expect(block.trackCount).to.equal(0);
       expect(block.paused).to.be.false;
     });
 
@@ -183,6 +187,8 @@ describe('Rig It Protocol', () => {
           .initBlock({
             blockId: invalidBlockId,
             minThreshold: MIN_THRESHOLD,
+            rigCount: null,
+            prizeTierBps: null,
           })
           .accounts({
             protocolConfig: protocolConfigPDA,
@@ -204,14 +210,75 @@ describe('Rig It Protocol', () => {
     });
   });
 
+  describe('Track Initialization', () => {
+    it('should initialize the standard track', async () => {
+      [solTrackPDA] = getTrackStatePDA(SOL_BLOCK_ID, 0, program.programId);
+
+      await program.methods
+        .initTrack({
+          trackId: 0,
+          scheduleAnchorTs: null,
+          activeDurationSecs: null,
+          cooldownDurationSecs: null,
+          antiSnipeWindowSecs: null,
+        })
+        .accounts({
+          protocolConfig: protocolConfigPDA,
+          blockState: solBlockPDA,
+          trackState: solTrackPDA,
+          admin: admin.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        })
+        .signers([admin])
+        .rpc();
+
+      const track = await program.account.trackState.fetch(solTrackPDA);
+      expect(track.trackId).to.equal(0);
+      expect(track.currentExplorationIndex.toNumber()).to.equal(0);
+
+      const block = await program.account.blockState.fetch(solBlockPDA);
+      expect(block.trackCount).to.equal(1);
+    });
+
+    it('should reject out-of-order track IDs', async () => {
+      const [skippedTrackPDA] = getTrackStatePDA(SOL_BLOCK_ID, 2, program.programId);
+
+      try {
+        await program.methods
+          .initTrack({
+            trackId: 2,
+            scheduleAnchorTs: null,
+            activeDurationSecs: 900,
+            cooldownDurationSecs: 300,
+            antiSnipeWindowSecs: 60,
+          })
+          .accounts({
+            protocolConfig: protocolConfigPDA,
+            blockState: solBlockPDA,
+            trackState: skippedTrackPDA,
+            admin: admin.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+          })
+          .signers([admin])
+          .rpc();
+
+        expect.fail('Should have thrown');
+      } catch (error: any) {
+        expect(error.message).to.include('InvalidTrackId');
+      }
+    });
+  });
+
   describe('Exploration Lifecycle', () => {
     let explorationPDA: PublicKey;
     let explorationIndex: BN;
 
     before(async () => {
-      // Get current exploration index from block
-      const block = await program.account.blockState.fetch(solBlockPDA);
-      explorationIndex = block.currentExplorationIndex;
+      // Get current exploration index from the track
+      const track = await program.account.trackState.fetch(solTrackPDA);
+      explorationIndex = track.currentExplorationIndex;
       [explorationPDA] = getExplorationStatePDA(
         SOL_BLOCK_ID,
         explorationIndex,
@@ -225,28 +292,32 @@ describe('Rig It Protocol', () => {
         .accounts({
           protocolConfig: protocolConfigPDA,
           blockState: solBlockPDA,
+          trackState: solTrackPDA,
           explorationState: explorationPDA,
           previousExploration: null,
-          operator: operator.publicKey,
+          caller: user1.publicKey, // Permissionless once the window opens
           systemProgram: anchor.web3.SystemProgram.programId,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
         })
-        .signers([operator])
+        .signers([user1])
         .rpc();
 
       const exploration = await program.account.explorationState.fetch(explorationPDA);
       
       expect(exploration.blockId).to.equal(SOL_BLOCK_ID);
+      expect(exploration.trackId).to.equal(0);
       expect(exploration.explorationIndex.toString()).to.equal(explorationIndex.toString());
-      expect(exploration.status.active).to.exist;
+      expect(exploration.version).to.equal(1);
+      expect(exploration.status).to.equal(1); // Active
+      expect(exploration.winningRig).to.equal(255); // Not settled
       expect(exploration.totalDeposits.toNumber()).to.equal(0);
       expect(exploration.rolloverAmount.toNumber()).to.equal(0);
     });
 
-    it('should fail to start exploration when not operator', async () => {
+    it('should fail to start exploration before its scheduled window', async () => {
       try {
-        const block = await program.account.blockState.fetch(solBlockPDA);
-        const nextIndex = block.currentExplorationIndex;
+        const track = await program.account.trackState.fetch(solTrackPDA);
+        const nextIndex = track.currentExplorationIndex;
         const [nextExplorationPDA] = getExplorationStatePDA(
           SOL_BLOCK_ID,
           nextIndex,
@@ -258,9 +329,10 @@ describe('Rig It Protocol', () => {
           .accounts({
             protocolConfig: protocolConfigPDA,
             blockState: solBlockPDA,
+            trackState: solTrackPDA,
             explorationState: nextExplorationPDA,
             previousExploration: explorationPDA,
-            operator: user1.publicKey, // Not operator
+            caller: user1.publicKey,
             systemProgram: anchor.web3.SystemProgram.programId,
             clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
           })
@@ -269,7 +341,7 @@ describe('Rig It Protocol', () => {
         
         expect.fail('Should have thrown');
       } catch (error: any) {
-        expect(error.message).to.include('Unauthorized');
+        expect(error.message).to.include('ExplorationNotScheduled');
       }
     });
   });
@@ -281,8 +353,8 @@ describe('Rig It Protocol', () => {
 
     before(async () => {
       // Get current exploration
-      const block = await program.account.blockState.fetch(solBlockPDA);
-      const currentIndex = block.currentExplorationIndex.subn(1);
+      const track = await program.account.trackState.fetch(solTrackPDA);
+      const currentIndex = track.currentExplorationIndex.subn(1);
       [explorationPDA] = getExplorationStatePDA(SOL_BLOCK_ID, currentIndex, program.programId);
 
       // Create token accounts and mint tokens to users
@@ -341,18 +413,24 @@ describe('Rig It Protocol', () => {
           rigIndex,
           amount,
           depositNonce,
+          entropyCommitment: Array.from(Keypair.generate().publicKey.toBytes()),
+          referrer: null,
         })
         .accounts({
           protocolConfig: protocolConfigPDA,
           blockState: solBlockPDA,
+          trackState: solTrackPDA,
           explorationState: explorationPDA,
           rigState: rigPDA,
           depositReceipt: depositReceiptPDA,
           user: user1.publicKey,
           userTokenAccount: user1TokenAccount,
           blockVault,
+          treasuryDepositFees: null,
           userRigTokenAccount: null,
           rigTokenMint: null,
+          referrer: null,
+          referrerStats: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: anchor.web3.SystemProgram.programId,
           clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
@@ -376,6 +454,7 @@ describe('Rig It Protocol', () => {
       // Check exploration state updated
       const exploration = await program.account.explorationState.fetch(explorationPDA);
       expect(exploration.totalDeposits.toString()).to.equal(amount.toString());
+      expect(exploration.entropyContributions).to.equal(1);
     });
 
     it('should reject deposits below minimum', async () => {
@@ -398,18 +477,24 @@ describe('Rig It Protocol', () => {
             rigIndex,
             amount,
             depositNonce,
+            entropyCommitment: null,
+            referrer: null,
           })
           .accounts({
             protocolConfig: protocolConfigPDA,
             blockState: solBlockPDA,
+            trackState: solTrackPDA,
             explorationState: explorationPDA,
             rigState: rigPDA,
             depositReceipt: depositReceiptPDA,
             user: user1.publicKey,
             userTokenAccount: user1TokenAccount,
             blockVault,
+            treasuryDepositFees: null,
             userRigTokenAccount: null,
             rigTokenMint: null,
+            referrer: null,
+            referrerStats: null,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: anchor.web3.SystemProgram.programId,
             clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
@@ -424,7 +509,7 @@ describe('Rig It Protocol', () => {
     });
 
     it('should reject invalid rig index', async () => {
-      const rigIndex = 50; // Invalid (max is 35)
+      const rigIndex = 50; // Invalid (default block has 36 rigs)
       const amount = new BN(1 * LAMPORTS_PER_SOL);
       const depositNonce = new BN(Date.now());
 
@@ -443,18 +528,24 @@ describe('Rig It Protocol', () => {
             rigIndex,
             amount,
             depositNonce,
+            entropyCommitment: null,
+            referrer: null,
           })
           .accounts({
             protocolConfig: protocolConfigPDA,
             blockState: solBlockPDA,
+            trackState: solTrackPDA,
             explorationState: explorationPDA,
             rigState: rigPDA,
             depositReceipt: depositReceiptPDA,
             user: user1.publicKey,
             userTokenAccount: user1TokenAccount,
             blockVault,
+            treasuryDepositFees: null,
             userRigTokenAccount: null,
             rigTokenMint: null,
+            referrer: null,
+            referrerStats: null,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: anchor.web3.SystemProgram.programId,
             clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
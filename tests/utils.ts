@@ -20,10 +20,22 @@ export function getBlockStatePDA(
   );
 }
 
+export function getTrackStatePDA(
+  blockId: number,
+  trackId: number,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('track'), Buffer.from([blockId]), Buffer.from([trackId])],
+    programId
+  );
+}
+
 export function getExplorationStatePDA(
   blockId: number,
   explorationIndex: BN | number,
-  programId: PublicKey
+  programId: PublicKey,
+  trackId: number = 0
 ): [PublicKey, number] {
   const indexBuffer = Buffer.alloc(8);
   const idx = typeof explorationIndex === 'number' 
@@ -32,7 +44,7 @@ export function getExplorationStatePDA(
   indexBuffer.writeBigUInt64LE(BigInt(idx.toString()));
 
   return PublicKey.findProgramAddressSync(
-    [Buffer.from('exploration'), Buffer.from([blockId]), indexBuffer],
+    [Buffer.from('exploration'), Buffer.from([blockId]), Buffer.from([trackId]), indexBuffer],
     programId
   );
 }
@@ -88,26 +100,96 @@ export function getBlockVaultAuthorityPDA(
 
 export function getTreasuryVaultPDA(
   treasuryType: string,
+  blockId: number,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from('treasury'), Buffer.from(treasuryType)],
+    [Buffer.from('treasury'), Buffer.from(treasuryType), Buffer.from([blockId])],
+    programId
+  );
+}
+
+export function getTreasuryAuthorityPDA(blockId: number, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('treasury_authority'), Buffer.from([blockId])],
     programId
   );
 }
 
+export function getRigBuybackVaultPDA(blockId: number, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('rig_buyback'), Buffer.from([blockId])],
+    programId
+  );
+}
+
+export function getBuybackSchedulePDA(explorationKey: PublicKey, programId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('buyback_schedule'), explorationKey.toBuffer()],
+    programId
+  );
+}
+
+// Mock constant-product AMM (programs/mock-amm)
+
+export function getMockAmmPoolPDA(
+  mintA: PublicKey,
+  mintB: PublicKey,
+  ammProgramId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('pool'), mintA.toBuffer(), mintB.toBuffer()],
+    ammProgramId
+  );
+}
+
+export function getMockAmmVaultPDA(
+  pool: PublicKey,
+  mint: PublicKey,
+  ammProgramId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('pool_vault'), pool.toBuffer(), mint.toBuffer()],
+    ammProgramId
+  );
+}
+
+export function getMockAmmLpMintPDA(pool: PublicKey, ammProgramId: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('lp_mint'), pool.toBuffer()],
+    ammProgramId
+  );
+}
+
 // Test helpers
 
 export async function sleep(ms: number): Promise<void> {
   return new Promise(resolve => setTimeout(resolve, ms));
 }
 
-export function generateCommitHash(secret: Buffer, targetSlot: number): Buffer {
+// v2 commit hash, bound to the exploration identity
+export function generateCommitHash(
+  secret: Buffer,
+  targetSlot: number,
+  programId: PublicKey,
+  blockId: number,
+  trackId: number,
+  explorationIndex: number
+): Buffer {
   const crypto = require('crypto');
+  const indexBuffer = Buffer.alloc(8);
+  indexBuffer.writeBigUInt64LE(BigInt(explorationIndex));
   const slotBuffer = Buffer.alloc(8);
   slotBuffer.writeBigUInt64LE(BigInt(targetSlot));
-  
-  const data = Buffer.concat([secret, slotBuffer]);
+
+  const data = Buffer.concat([
+    Buffer.from('rig-it:commit:v2'),
+    programId.toBuffer(),
+    Buffer.from([blockId, trackId]),
+    indexBuffer,
+    slotBuffer,
+    secret,
+  ]);
   return crypto.createHash('sha256').update(data).digest();
 }
 